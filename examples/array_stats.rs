@@ -0,0 +1,33 @@
+// Array statistics example for RISC Zero zkVM
+// This commits a structured result (count, min, max and mean of an array) in the tagged result
+// envelope, which the host decodes into its `result_value` JSON instead of reading a bare i64
+use risc0_zkvm::guest::env;
+
+// Result envelope header: magic, then the payload tag (1 = JSON), then the payload length
+const RESULT_ENVELOPE_MAGIC: &[u8; 4] = b"R0RE";
+const RESULT_TAG_JSON: u32 = 1;
+
+fn main() {
+    // Read input from the host - expects array of numbers [4, 8, 15, 16, 23, 42]
+    let inputs_json: String = env::read();
+    
+    let result = match serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        Ok(parsed) => match parsed.as_array().map(|arr| arr.iter().map(|v| v.as_f64()).collect::<Option<Vec<f64>>>()) {
+            Some(Some(values)) if !values.is_empty() => {
+                let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+                let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                let mean = values.iter().sum::<f64>() / values.len() as f64;
+                serde_json::json!({"count": values.len(), "min": min, "max": max, "mean": mean})
+            },
+            _ => serde_json::json!({"error": "expected a non-empty array of numbers"})
+        },
+        Err(_) => serde_json::json!({"error": "JSON parse error"})
+    };
+    
+    // Commit the envelope as raw bytes so it reads the same under either journal codec
+    let payload = serde_json::to_vec(&result).unwrap();
+    env::commit_slice(RESULT_ENVELOPE_MAGIC);
+    env::commit_slice(&RESULT_TAG_JSON.to_le_bytes());
+    env::commit_slice(&(payload.len() as u32).to_le_bytes());
+    env::commit_slice(&payload);
+}
@@ -0,0 +1,86 @@
+// Proof composition example for RISC Zero zkVM
+// This verifies a modexp receipt inside the guest with `env::verify`, then range-checks the result
+// that receipt attests to, so a single receipt covers the whole pipeline. The host supplies the
+// inner receipt with `--assumption`; proving fails unless an assumption matches the claimed image ID
+// and journal exactly.
+//
+// The inner image ID arrives as an input, so it is committed: whoever verifies this receipt must
+// compare it with the modexp guest's image ID, or a receipt from any other program could stand in.
+//
+// Journal layout (risc0-serde):
+//   status          i64, 1 = modexp result in range, 0 = out of range, negative on error
+//   inner_image_id  Digest, the image ID the inner receipt was verified against (zero on error)
+//   modexp          (u64, u64, u64, u64), base, exponent, modulus and result (zeros on error)
+//   bounds          (u64, u64), min and max (zeros on error)
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::Digest;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "inner_image_id": "<64 hex chars>",                        // image_id of the --assumption proof
+    //   "base": 4, "exponent": 13, "modulus": 497, "result": 445,  // that proof's journal
+    //   "min": 400,
+    //   "max": 500
+    // }
+    let inputs_json: String = env::read();
+
+    let (status, inner_image_id, modexp, bounds) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        let field = |name: &str| parsed.get(name).and_then(|v| v.as_u64());
+        if let (Some(image_id), Some(base), Some(exponent), Some(modulus), Some(result), Some(min), Some(max)) = (
+            parsed.get("inner_image_id").and_then(|v| v.as_str()),
+            field("base"),
+            field("exponent"),
+            field("modulus"),
+            field("result"),
+            field("min"),
+            field("max")
+        ) {
+            match decode_digest(image_id) {
+                Some(bytes) => {
+                    let image_id = Digest::from(bytes);
+                    let modexp = (base, exponent, modulus, result);
+                    // Adds the inner claim as an assumption of this receipt; execution stops here if
+                    // the host has no receipt proving it
+                    env::verify(image_id, &modexp_journal(modexp)).unwrap();
+                    ((min <= result && result <= max) as i64, image_id, modexp, (min, max))
+                }
+                None => (-3, Digest::ZERO, (0, 0, 0, 0), (0, 0)) // inner_image_id is not 64 hex characters
+            }
+        } else {
+            (-1, Digest::ZERO, (0, 0, 0, 0), (0, 0)) // Missing required fields
+        }
+    } else {
+        (-2, Digest::ZERO, (0, 0, 0, 0), (0, 0)) // JSON parse error
+    };
+
+    // Commit the outcome and the full statement it is about
+    env::commit(&status);
+    env::commit(&inner_image_id);
+    env::commit(&modexp);
+    env::commit(&bounds);
+}
+
+// The journal exactly as the modexp guest commits it: four u64s as risc0 serde words
+fn modexp_journal(modexp: (u64, u64, u64, u64)) -> Vec<u8> {
+    risc0_zkvm::serde::to_vec(&modexp)
+        .unwrap()
+        .iter()
+        .flat_map(|word| word.to_le_bytes())
+        .collect()
+}
+
+// Parse a 32-byte digest from hex (either case), as the host prints image IDs
+fn decode_digest(hex: &str) -> Option<[u8; 32]> {
+    let hex = hex.as_bytes();
+    if hex.len() != 64 {
+        return None;
+    }
+    let mut digest = [0u8; 32];
+    for (byte, pair) in digest.iter_mut().zip(hex.chunks(2)) {
+        let high = (pair[0] as char).to_digit(16)?;
+        let low = (pair[1] as char).to_digit(16)?;
+        *byte = (high * 16 + low) as u8;
+    }
+    Some(digest)
+}
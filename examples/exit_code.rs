@@ -0,0 +1,14 @@
+use risc0_zkvm::guest::env;
+
+// Exit code example: commits the input and then halts with it as the exit code.
+// Any non-zero code is an abnormal halt, so the host reports it and exits with an error.
+fn main() {
+    let inputs_json: String = env::read();
+    let code = serde_json::from_str::<serde_json::Value>(&inputs_json)
+        .ok()
+        .and_then(|v| v.as_u64())
+        .unwrap_or(1) as u32;
+    
+    env::commit(&(code as i64));
+    env::exit(code as u8);
+}
@@ -1,5 +1,6 @@
 // Hash verification example for RISC Zero zkVM
 // This verifies that a secret input produces a known hash without revealing the input
+// The hash is a toy djb2 variant; see sha256_verify.rs for a real SHA-256 preimage proof
 use risc0_zkvm::guest::env;
 
 fn main() {
@@ -0,0 +1,111 @@
+// K-nearest-neighbors classification with zero-knowledge proof
+// This classifies a query point by majority vote of its k nearest labeled training points
+// without revealing the training data
+use risc0_zkvm::guest::env;
+use std::collections::BTreeMap;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "training_data": [
+    //     {"point": [1.0, 2.0], "label": 0},
+    //     {"point": [2.0, 1.0], "label": 0},
+    //     {"point": [8.0, 9.0], "label": 1},
+    //     {"point": [9.0, 8.0], "label": 1}
+    //   ],
+    //   "query_point": [1.5, 1.8],
+    //   "k": 3
+    // }
+    let inputs_json: String = env::read();
+
+    let result = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(training_data),
+            Some(query_point),
+            Some(k)
+        ) = (
+            parsed.get("training_data").and_then(|v| v.as_array()),
+            parsed.get("query_point").and_then(|v| v.as_array()),
+            parsed.get("k").and_then(|v| v.as_i64())
+        ) {
+            // Convert training data to Vec<(Vec<f64>, i64)>; labels are non-negative so they
+            // cannot be confused with error codes
+            let samples: Result<Vec<(Vec<f64>, i64)>, _> = training_data
+                .iter()
+                .map(|sample| {
+                    let coords = sample.get("point").and_then(|p| p.as_array()).ok_or("Invalid point format")?;
+                    let point: Result<Vec<f64>, _> = coords.iter()
+                        .map(|coord| coord.as_f64().ok_or("Invalid coordinate"))
+                        .collect();
+                    let label = sample.get("label").and_then(|l| l.as_i64()).filter(|l| *l >= 0).ok_or("Invalid label")?;
+                    Ok((point?, label))
+                })
+                .collect();
+
+            // Convert query point
+            let query: Result<Vec<f64>, &str> = query_point
+                .iter()
+                .map(|v| v.as_f64().ok_or("Invalid query coordinate"))
+                .collect();
+
+            match (samples, query) {
+                (Ok(data), Ok(q_point)) => {
+                    if data.is_empty() || q_point.is_empty() || k <= 0 || k > data.len() as i64 {
+                        -1 // Invalid parameters: k must be between 1 and the number of points
+                    } else if data.iter().any(|(point, _)| point.len() != q_point.len()) {
+                        -5 // Dimension mismatch between a training point and the query point
+                    } else {
+                        knn_classify(&data, &q_point, k as usize)
+                    }
+                },
+                _ => -2 // Invalid data format
+            }
+        } else {
+            -3 // Missing required fields
+        }
+    } else {
+        -4 // JSON parse error
+    };
+
+    // Commit only the predicted label (or error code)
+    // Note: The training points, their labels and the query point remain private!
+    env::commit(&result);
+}
+
+// Majority label among the k nearest training points. Points at equal distance keep their input
+// order, and a tied vote goes to the lowest label, so the result is deterministic.
+fn knn_classify(data: &[(Vec<f64>, i64)], query_point: &[f64], k: usize) -> i64 {
+    let mut neighbors: Vec<(f64, i64)> = data
+        .iter()
+        .map(|(point, label)| (euclidean_distance(point, query_point), *label))
+        .collect();
+    neighbors.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // Count votes; BTreeMap iterates labels in ascending order
+    let mut votes: BTreeMap<i64, usize> = BTreeMap::new();
+    for (_, label) in &neighbors[..k] {
+        *votes.entry(*label).or_insert(0) += 1;
+    }
+
+    let mut best_label = -1;
+    let mut best_votes = 0;
+    for (label, count) in votes {
+        if count > best_votes {
+            best_votes = count;
+            best_label = label;
+        }
+    }
+
+    best_label
+}
+
+// Calculate Euclidean distance between two points
+fn euclidean_distance(point1: &[f64], point2: &[f64]) -> f64 {
+    let mut sum_squares = 0.0;
+    for i in 0..point1.len() {
+        let diff = point1[i] - point2[i];
+        sum_squares += diff * diff;
+    }
+
+    sum_squares.sqrt()
+}
@@ -0,0 +1,65 @@
+// SHA-256 preimage proof for RISC Zero zkVM
+// This proves knowledge of a secret string whose SHA-256 digest is a given hash, without revealing
+// the string. The digest is computed with the zkVM's accelerated SHA-256 (`risc0_zkvm::sha`), which
+// costs far fewer cycles than hashing in pure Rust.
+//
+// The expected digest arrives as an input, so it is committed: whoever verifies this receipt must
+// compare it with the hash they care about, or a proof about the hash of any other string would pass.
+//
+// Journal layout (risc0-serde):
+//   status    i64, 1 = the secret hashes to expected_sha256, 0 = it does not, negative on error
+//   expected  Digest, the expected_sha256 the secret was checked against (zero on error)
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "secret": "hello",
+    //   "expected_sha256": "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    // }
+    let inputs_json: String = env::read();
+
+    let (status, expected): (i64, Digest) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (Some(secret), Some(expected_hex)) = (
+            parsed.get("secret").and_then(|s| s.as_str()),
+            parsed.get("expected_sha256").and_then(|h| h.as_str())
+        ) {
+            match decode_digest(expected_hex) {
+                Some(expected) => {
+                    let digest = Impl::hash_bytes(secret.as_bytes());
+                    let status = if digest.as_bytes() == expected.as_slice() {
+                        1 // Digest matches - the prover knows a valid preimage
+                    } else {
+                        0 // Digest doesn't match
+                    };
+                    (status, Digest::from(expected))
+                }
+                None => (-3, Digest::ZERO) // expected_sha256 is not 64 hex characters
+            }
+        } else {
+            (-1, Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-2, Digest::ZERO) // JSON parse error
+    };
+
+    // Commit the outcome and the digest it is about; the secret itself is never revealed
+    env::commit(&status);
+    env::commit(&expected);
+}
+
+// Parse a 32-byte digest from hex (either case)
+fn decode_digest(hex: &str) -> Option<[u8; 32]> {
+    let hex = hex.as_bytes();
+    if hex.len() != 64 {
+        return None;
+    }
+    let mut digest = [0u8; 32];
+    for (byte, pair) in digest.iter_mut().zip(hex.chunks(2)) {
+        let high = (pair[0] as char).to_digit(16)?;
+        let low = (pair[1] as char).to_digit(16)?;
+        *byte = (high * 16 + low) as u8;
+    }
+    Some(digest)
+}
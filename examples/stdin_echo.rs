@@ -0,0 +1,15 @@
+use risc0_zkvm::guest::env;
+use std::io::{Read, Write};
+
+// Raw stdin/stdout example for the host's `--stdio` mode:
+// reads every byte from stdin, writes the upper-cased text to stdout
+// and commits the number of bytes processed.
+fn main() {
+    let mut input = Vec::new();
+    env::stdin().read_to_end(&mut input).expect("failed to read stdin");
+    
+    let transformed = String::from_utf8_lossy(&input).to_uppercase();
+    env::stdout().write_all(transformed.as_bytes()).expect("failed to write stdout");
+    
+    env::commit(&(input.len() as i64));
+}
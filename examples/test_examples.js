@@ -37,6 +37,12 @@ const examples = [
     inputs: [{"data_points": [[1.0, 2.0], [2.0, 1.0], [8.0, 9.0], [9.0, 8.0]], "k": 2, "max_iterations": 10, "query_point": [1.5, 1.8]}],
     description: "ML: Classify query point using k-means clustering (should be 0 or 1)"
   },
+  {
+    name: "K-Nearest Neighbors",
+    file: "knn_classification.rs",
+    inputs: [{"training_data": [{"point": [1.0, 2.0], "label": 0}, {"point": [2.0, 1.0], "label": 0}, {"point": [8.0, 9.0], "label": 1}, {"point": [9.0, 8.0], "label": 1}], "query_point": [1.5, 1.8], "k": 3}],
+    description: "ML: Classify query point by its 3 nearest neighbors (should be 0)"
+  },
   {
     name: "Array Sum",
     file: "sum_array.rs",
@@ -49,6 +55,12 @@ const examples = [
     inputs: [{"secret": "hello", "expected_hash": 210714}],
     description: "Verify secret 'hello' matches hash 210714"
   },
+  {
+    name: "SHA-256 Verify",
+    file: "sha256_verify.rs",
+    inputs: [{"secret": "hello", "expected_sha256": "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"}],
+    description: "Prove knowledge of a SHA-256 preimage of the digest (should be 1)"
+  },
   {
     name: "Merkle Proof",
     file: "merkle_proof.rs",
@@ -0,0 +1,22 @@
+// Typed inputs example for RISC Zero zkVM
+// Run with inputs {"input_mode": "typed", "values": [...]} and the host writes each value with its
+// own `env::write`, so the guest reads them directly instead of parsing a JSON string
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor (5 decimal places, matching the host)
+const SCALE: i64 = 100000;
+
+fn main() {
+    // Read the values in the order they were listed - expects:
+    // {"input_mode": "typed", "values": [{"i64": -3}, {"u64": 4}, {"fixed": 2.5}, {"string": "zkvm"}]}
+    let offset: i64 = env::read();
+    let count: u64 = env::read();
+    let price: i64 = env::read(); // fixed point: 2.5 arrives as 250000
+    let label: String = env::read();
+    
+    // offset + count * price + label length, as an integer
+    let result = offset + count as i64 * price / SCALE + label.len() as i64;
+    
+    // Commit the result to the proof
+    env::commit(&result);
+}
@@ -0,0 +1,26 @@
+// Re-derive keys/public/<key_id>.pub from the private key in keys/<key_id>.key ("default" unless
+// a key_id argument is given). Both files use the format documented in `host::keys`, the same one
+// `host regenerate_key` writes.
+use host::keys::{load_private_key, public_key_path, write_public_key, KEY_DIR};
+use std::fs;
+use std::path::Path;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let key_id = std::env::args().nth(1).unwrap_or_else(|| "default".to_string());
+    let dir = Path::new(KEY_DIR);
+    let keypair = load_private_key(dir, &key_id)?;
+    let public_key_hex = hex::encode(keypair.pk.as_ref());
+    println!("Derived public key: {}", public_key_hex);
+
+    // Report whether the stored public key already matched before overwriting it
+    let path = public_key_path(dir, &key_id);
+    match fs::read_to_string(&path) {
+        Ok(stored) if stored.trim().eq_ignore_ascii_case(&public_key_hex) => println!("Public key file already matches"),
+        Ok(_) => println!("Public key file did not match the private key; replacing it"),
+        Err(_) => println!("No public key file yet; creating it"),
+    }
+
+    write_public_key(dir, &key_id, &keypair)?;
+    println!("Public key file updated: {}", path.display());
+    Ok(())
+}
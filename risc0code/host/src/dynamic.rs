@@ -0,0 +1,405 @@
+// Dynamic guest programs: Rust source compiled on the host with `cargo risczero build` against a
+// generated manifest, and cached by source hash so an unchanged guest is only built once.
+use risc0_zkvm::sha::{Impl, Sha256};
+use std::fs;
+use std::process::Command;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// Cargo.toml every dynamic guest is built with. It ends in [dependencies] so allowlisted crates
+// can be appended, and the generated manifest is part of the compiled-guest cache key.
+const DYNAMIC_GUEST_CARGO_TOML: &str = r#"[package]
+name = "guest-dynamic"
+version = "0.1.0"
+edition = "2021"
+
+[[bin]]
+name = "guest-dynamic"
+path = "src/main.rs"
+
+[dependencies]
+risc0-zkvm = { version = "^2.3.1", default-features = false, features = ["std"] }
+serde = { version = "1.0", default-features = false, features = ["derive", "alloc"] }
+serde_json = { version = "1.0", default-features = false, features = ["alloc"] }
+"#;
+
+// Crates a dynamic guest may add through `"dependencies": [...]` in its inputs JSON. The host pins
+// each one's version and features, so callers cannot pull arbitrary crates, sources or build scripts.
+const ALLOWED_GUEST_DEPENDENCIES: &[(&str, &str)] = &[
+    ("ed25519-compact", r#"{ version = "2.1", default-features = false }"#),
+    ("hex", r#"{ version = "0.4", default-features = false, features = ["alloc"] }"#),
+    ("sha2", r#"{ version = "0.10", default-features = false }"#),
+];
+
+// Allowlisted crates named in the inputs' optional "dependencies" array, sorted and deduplicated so
+// the same set always generates the same manifest. Inputs that are not a JSON object request none.
+fn requested_guest_dependencies(inputs_json: &str) -> Result<Vec<&'static str>, String> {
+    let Ok(serde_json::Value::Object(inputs)) = serde_json::from_str::<serde_json::Value>(inputs_json) else {
+        return Ok(Vec::new());
+    };
+    let Some(requested) = inputs.get("dependencies") else {
+        return Ok(Vec::new());
+    };
+    
+    let not_names = || "\"dependencies\" must be an array of crate names".to_string();
+    let mut dependencies = Vec::new();
+    for name in requested.as_array().ok_or_else(not_names)? {
+        let name = name.as_str().ok_or_else(not_names)?;
+        match ALLOWED_GUEST_DEPENDENCIES.iter().find(|(allowed, _)| *allowed == name) {
+            Some((allowed, _)) => dependencies.push(*allowed),
+            None => {
+                let allowed: Vec<&str> = ALLOWED_GUEST_DEPENDENCIES.iter().map(|(allowed, _)| *allowed).collect();
+                return Err(format!("dependency '{}' is not allowed for dynamic guests (allowed: {})", name, allowed.join(", ")));
+            }
+        }
+    }
+    dependencies.sort_unstable();
+    dependencies.dedup();
+    Ok(dependencies)
+}
+
+// The dynamic guest's Cargo.toml with allowlisted `dependencies` appended
+fn dynamic_guest_manifest(dependencies: &[&str]) -> String {
+    let mut manifest = DYNAMIC_GUEST_CARGO_TOML.to_string();
+    for (name, spec) in ALLOWED_GUEST_DEPENDENCIES.iter().filter(|(name, _)| dependencies.contains(name)) {
+        manifest.push_str(&format!("{} = {}\n", name, spec));
+    }
+    manifest
+}
+
+// Environment variable overriding where compiled dynamic guests are cached
+const GUEST_CACHE_ENV: &str = "RISC0_GUEST_CACHE_DIR";
+
+fn guest_cache_dir() -> std::path::PathBuf {
+    guest_cache_dir_from(std::env::var_os(GUEST_CACHE_ENV), std::env::var_os("XDG_CACHE_HOME"), std::env::var_os("HOME"))
+}
+
+// Cached ELFs are loaded and proven without being rebuilt, so they live in the user's own cache
+// directory rather than a shared temp directory another user could write into
+fn guest_cache_dir_from(overridden: Option<std::ffi::OsString>, xdg_cache_home: Option<std::ffi::OsString>, home: Option<std::ffi::OsString>) -> std::path::PathBuf {
+    let non_empty = |dir: Option<std::ffi::OsString>| dir.filter(|dir| !dir.is_empty()).map(std::path::PathBuf::from);
+    if let Some(dir) = non_empty(overridden) {
+        return dir;
+    }
+    non_empty(xdg_cache_home)
+        .or_else(|| non_empty(home).map(|home| home.join(".cache")))
+        .unwrap_or_else(std::env::temp_dir)
+        .join("risc0_guest_cache")
+}
+
+// SHA-256 of the generated Cargo.toml and the guest source, so changing either misses the cache
+fn guest_cache_key(manifest: &str, guest_code: &str) -> String {
+    let mut bytes = Vec::with_capacity(manifest.len() + 1 + guest_code.len());
+    bytes.extend_from_slice(manifest.as_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(guest_code.as_bytes());
+    Impl::hash_bytes(&bytes).to_string()
+}
+
+// Write through a per-process temporary file and rename, so a concurrent run never reads a partial ELF
+fn store_cached_guest(path: &std::path::Path, elf_data: &[u8]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension(format!("bin.{}.tmp", std::process::id()));
+    fs::write(&partial, elf_data)?;
+    fs::rename(&partial, path)
+}
+
+// Default for `--build-timeout <secs>`: a cold `cargo risczero build` pulls its Docker image first
+pub const DEFAULT_BUILD_TIMEOUT_SECS: u64 = 300;
+
+// Bytes of stdout and of stderr kept from a failed build; the tail is kept, where compiler errors are
+const BUILD_OUTPUT_LIMIT: usize = 16 * 1024;
+
+#[derive(Debug)]
+pub enum DynamicBuildError {
+    // The build ran past the timeout and was killed; `output` is whatever arrived before the kill
+    Timeout { secs: u64, output: String },
+    // `cargo risczero build` exited unsuccessfully
+    Failed { status: std::process::ExitStatus, output: String },
+    // The build succeeded but the ELF is not where `cargo risczero build` puts it
+    ElfMissing(std::path::PathBuf),
+    // The inputs asked for a dependency outside `ALLOWED_GUEST_DEPENDENCIES`, or named them wrongly
+    Dependencies(String),
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for DynamicBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynamicBuildError::Timeout { secs, output } => write!(f, "Dynamic guest build timed out after {}s and was killed; its output may be incomplete\n{}", secs, output),
+            DynamicBuildError::Failed { status, output } => write!(f, "Dynamic guest program compilation failed ({})\n{}", status, output),
+            DynamicBuildError::ElfMissing(path) => write!(f, "Built ELF file not found at {}", path.display()),
+            DynamicBuildError::Dependencies(message) => write!(f, "{}", message),
+            DynamicBuildError::Io(e) => write!(f, "Dynamic guest build failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DynamicBuildError {}
+
+impl From<std::io::Error> for DynamicBuildError {
+    fn from(e: std::io::Error) -> Self {
+        DynamicBuildError::Io(e)
+    }
+}
+
+// Last `BUILD_OUTPUT_LIMIT` bytes of a build's output stream, and how many earlier bytes were dropped
+#[derive(Debug, Default)]
+struct OutputTail {
+    bytes: Vec<u8>,
+    dropped: usize,
+}
+
+impl OutputTail {
+    fn push(&mut self, chunk: &[u8], limit: usize) {
+        self.bytes.extend_from_slice(chunk);
+        if self.bytes.len() > limit {
+            let excess = self.bytes.len() - limit;
+            self.bytes.drain(..excess);
+            self.dropped += excess;
+        }
+    }
+    
+    fn describe(&self, name: &str) -> String {
+        if self.dropped > 0 {
+            format!("{} (first {} bytes truncated): ...{}", name, self.dropped, String::from_utf8_lossy(&self.bytes))
+        } else {
+            format!("{}: {}", name, String::from_utf8_lossy(&self.bytes))
+        }
+    }
+}
+
+// Drain a child's output stream on a background thread into a shared tail
+fn drain_output(mut reader: impl std::io::Read + Send + 'static, tail: Arc<std::sync::Mutex<OutputTail>>) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        let mut chunk = [0u8; 8192];
+        while let Ok(n) = reader.read(&mut chunk) {
+            if n == 0 {
+                break;
+            }
+            tail.lock().unwrap().push(&chunk[..n], BUILD_OUTPUT_LIMIT);
+        }
+    })
+}
+
+// Kill a build and every process it spawned. The build leads its own process group (see
+// `run_build`), so signalling the group also reaches rustc and linker children that hold its pipes.
+fn kill_build(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    // SAFETY: kill(2) has no memory-safety preconditions; a negative pid names the child's group
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+// Run a build command to completion or until `timeout`, killing its whole process group on expiry.
+// stdin is closed so a prompt fails instead of waiting forever, and output is drained on threads so
+// a chatty build cannot block on a full pipe. The readers are joined in both cases; once the group
+// is dead nothing holds the pipes open.
+fn run_build(mut command: Command, timeout: Duration) -> Result<(), DynamicBuildError> {
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut command, 0);
+    let mut child = command
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()?;
+    let stdout = Arc::new(std::sync::Mutex::new(OutputTail::default()));
+    let stderr = Arc::new(std::sync::Mutex::new(OutputTail::default()));
+    let readers = [
+        drain_output(child.stdout.take().expect("stdout is piped"), Arc::clone(&stdout)),
+        drain_output(child.stderr.take().expect("stderr is piped"), Arc::clone(&stderr)),
+    ];
+    
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if Instant::now() >= deadline {
+            kill_build(&mut child);
+            break None;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    };
+    for reader in readers {
+        let _ = reader.join();
+    }
+    
+    let output = format!("{}\n{}", stdout.lock().unwrap().describe("stdout"), stderr.lock().unwrap().describe("stderr"));
+    match status {
+        Some(status) if status.success() => Ok(()),
+        Some(status) => Err(DynamicBuildError::Failed { status, output }),
+        None => Err(DynamicBuildError::Timeout { secs: timeout.as_secs(), output }),
+    }
+}
+
+// Build a dynamic guest from source with `cargo risczero build` and return its ELF
+fn compile_dynamic_guest(manifest: &str, guest_code: &str, timeout: Duration) -> Result<Vec<u8>, DynamicBuildError> {
+    // Create a uniquely named temporary directory for the dynamic guest program, so concurrent
+    // builds never share one; it is removed when `build_dir` is dropped
+    let build_dir = tempfile::Builder::new().prefix("risc0_dynamic_").tempdir()?;
+    let temp_dir = build_dir.path();
+    
+    let cargo_toml_path = temp_dir.join("Cargo.toml");
+    fs::write(&cargo_toml_path, manifest)?;
+    
+    // Create src directory and copy the guest program
+    let src_dir = temp_dir.join("src");
+    fs::create_dir_all(&src_dir)?;
+    
+    let main_rs_path = src_dir.join("main.rs");
+    fs::write(&main_rs_path, guest_code)?;
+    
+    // Build the guest program using RISC Zero toolchain
+    eprintln!("🔨 Building dynamic guest program (timeout {}s)...", timeout.as_secs());
+    let mut build = Command::new("cargo");
+    build.args(["risczero", "build"]).current_dir(temp_dir);
+    if let Err(e) = run_build(build, timeout) {
+        eprintln!("❌ Failed to build dynamic guest program");
+        return Err(e);
+    }
+    
+    // Find the built ELF file (cargo risczero build places it in docker subdirectory)
+    let elf_path = temp_dir.join("target/riscv32im-risc0-zkvm-elf/docker/guest-dynamic.bin");
+    if !elf_path.exists() {
+        eprintln!("❌ ELF file not found at: {}", elf_path.display());
+        eprintln!("🔍 Checking directory contents...");
+        if let Ok(entries) = std::fs::read_dir(temp_dir.join("target")) {
+            for entry in entries.flatten() {
+                eprintln!("  Found: {}", entry.path().display());
+            }
+        }
+        return Err(DynamicBuildError::ElfMissing(elf_path));
+    }
+    
+    let elf_data = fs::read(&elf_path)?;
+    eprintln!("✅ Dynamic guest program compiled successfully ({} bytes)", elf_data.len());
+    
+    Ok(elf_data)
+}
+
+// ELF of the dynamic guest program at `guest_program_path`, with the dependencies its inputs request.
+// Compiled guests are cached by source hash unless `use_cache` is false; a hit skips `cargo risczero build` entirely.
+pub fn dynamic_guest_elf(guest_program_path: &str, inputs_json: &str, use_cache: bool, timeout: Duration) -> Result<Vec<u8>, DynamicBuildError> {
+    let guest_code = fs::read_to_string(guest_program_path)?;
+    let manifest = dynamic_guest_manifest(&requested_guest_dependencies(inputs_json).map_err(DynamicBuildError::Dependencies)?);
+    
+    let cached_path = use_cache.then(|| guest_cache_dir().join(format!("{}.bin", guest_cache_key(&manifest, &guest_code))));
+    if let Some(path) = cached_path.as_ref().filter(|path| path.exists()) {
+        let elf_data = fs::read(path)?;
+        eprintln!("♻️  Using cached dynamic guest program: {} ({} bytes)", path.display(), elf_data.len());
+        return Ok(elf_data);
+    }
+    
+    eprintln!("🔧 Compiling dynamic guest program: {}", guest_program_path);
+    let elf_data = compile_dynamic_guest(&manifest, &guest_code, timeout)?;
+    if let Some(path) = &cached_path {
+        match store_cached_guest(path, &elf_data) {
+            Ok(()) => eprintln!("💾 Cached compiled guest at {}", path.display()),
+            Err(e) => eprintln!("⚠️  Could not cache compiled guest: {}", e),
+        }
+    }
+    Ok(elf_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_tail_keeps_last_bytes() {
+        let output: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let mut tail = OutputTail::default();
+        for chunk in output.chunks(8192) {
+            tail.push(chunk, 1000);
+        }
+        assert_eq!(tail.bytes, &output[99_000..]);
+        assert_eq!(tail.dropped, 99_000);
+        assert!(tail.describe("stderr").starts_with("stderr (first 99000 bytes truncated): ..."));
+        
+        let mut short = OutputTail::default();
+        short.push(b"short", 1000);
+        assert_eq!(short.describe("stdout"), "stdout: short");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_build_kills_on_timeout() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo started; sleep 30"]);
+        let start = Instant::now();
+        match run_build(command, Duration::from_millis(300)) {
+            Err(DynamicBuildError::Timeout { output, .. }) => assert!(output.contains("started")),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+        assert!(start.elapsed() < Duration::from_secs(10));
+        
+        // A spawned process holding the pipes is killed with the build, so joining the readers returns
+        let mut spawning = Command::new("sh");
+        spawning.args(["-c", "sleep 30 & echo spawned; wait"]);
+        let start = Instant::now();
+        match run_build(spawning, Duration::from_millis(300)) {
+            Err(e @ DynamicBuildError::Timeout { .. }) => assert!(e.to_string().contains("spawned") && e.to_string().contains("may be incomplete")),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+        assert!(start.elapsed() < Duration::from_secs(10));
+        
+        let mut failing = Command::new("sh");
+        failing.args(["-c", "echo boom >&2; exit 3"]);
+        match run_build(failing, Duration::from_secs(10)) {
+            Err(DynamicBuildError::Failed { status, output }) => {
+                assert_eq!(status.code(), Some(3));
+                assert!(output.contains("stderr: boom"));
+            },
+            other => panic!("expected a failed build, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_guest_dependencies_are_allowlisted() {
+        assert_eq!(requested_guest_dependencies(r#"{"dependencies": ["sha2", "hex", "sha2"]}"#), Ok(vec!["hex", "sha2"]));
+        assert_eq!(requested_guest_dependencies(r#"{"x": 1}"#), Ok(vec![]));
+        assert_eq!(requested_guest_dependencies("[1, 2, 3]"), Ok(vec![]));
+        assert!(requested_guest_dependencies(r#"{"dependencies": ["openssl-sys"]}"#).unwrap_err().contains("'openssl-sys' is not allowed"));
+        assert!(requested_guest_dependencies(r#"{"dependencies": "sha2"}"#).is_err());
+        
+        let manifest = dynamic_guest_manifest(&["sha2"]);
+        assert!(manifest.ends_with("sha2 = { version = \"0.10\", default-features = false }\n"));
+        assert!(!manifest.contains("hex ="));
+    }
+
+    #[test]
+    fn test_guest_cache_key_tracks_source() {
+        let source = "fn main() {}";
+        let manifest = dynamic_guest_manifest(&[]);
+        assert_eq!(guest_cache_key(&manifest, source), guest_cache_key(&manifest, source));
+        assert_ne!(guest_cache_key(&manifest, source), guest_cache_key(&manifest, "fn main() { }"));
+        assert_ne!(guest_cache_key(&manifest, source), guest_cache_key(&dynamic_guest_manifest(&["hex"]), source));
+        assert_eq!(guest_cache_key(&manifest, source).len(), 64);
+    }
+
+    #[test]
+    fn test_guest_cache_dir_is_per_user() {
+        let dir = |overridden: Option<&str>, xdg: Option<&str>, home: Option<&str>| guest_cache_dir_from(overridden.map(Into::into), xdg.map(Into::into), home.map(Into::into));
+        assert_eq!(dir(Some("/srv/cache"), Some("/xdg"), Some("/home/alice")), std::path::PathBuf::from("/srv/cache"));
+        assert_eq!(dir(None, Some("/xdg"), Some("/home/alice")), std::path::PathBuf::from("/xdg/risc0_guest_cache"));
+        assert_eq!(dir(None, Some(""), Some("/home/alice")), std::path::PathBuf::from("/home/alice/.cache/risc0_guest_cache"));
+        assert_eq!(dir(None, None, None), std::env::temp_dir().join("risc0_guest_cache"));
+    }
+    
+    #[test]
+    fn test_store_cached_guest_round_trips() {
+        let dir = std::env::temp_dir().join(format!("risc0_guest_cache_test_{}", std::process::id()));
+        let path = dir.join(format!("{}.bin", guest_cache_key(DYNAMIC_GUEST_CARGO_TOML, "fn main() {}")));
+        store_cached_guest(&path, b"\x7fELF").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"\x7fELF");
+        // Only the final file is left behind
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,137 @@
+// Ed25519 key files for authenticated operations. `host regenerate_key` and the `regenerate_pubkey`
+// binary both read and write this one format:
+//   <dir>/<key_id>.key         hex of the 32-byte Ed25519 seed (the RFC 8032 private key)
+//   <dir>/public/<key_id>.pub  hex of the 32-byte public key derived from that seed
+// Private keys written by older hosts as 64-byte `seed || public_key` still load, provided the
+// public half matches the seed.
+use ed25519_compact::{KeyPair, Seed};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Key directory, relative to the working directory
+pub const KEY_DIR: &str = "keys";
+
+// Key IDs name files, so they are limited to letters, digits, `-` and `_`
+pub fn check_key_id(key_id: &str) -> Result<(), String> {
+    if key_id.is_empty() || !key_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(format!("invalid key id '{}': use letters, digits, '-' and '_'", key_id));
+    }
+    Ok(())
+}
+
+pub fn private_key_path(dir: &Path, key_id: &str) -> PathBuf {
+    dir.join(format!("{}.key", key_id))
+}
+
+pub fn public_key_path(dir: &Path, key_id: &str) -> PathBuf {
+    dir.join("public").join(format!("{}.pub", key_id))
+}
+
+// Parse a private key file's contents into the key pair it derives
+pub fn parse_private_key(contents: &str) -> Result<KeyPair, Box<dyn std::error::Error>> {
+    let bytes = hex::decode(contents.trim()).map_err(|e| format!("private key is not hex: {}", e))?;
+    match bytes.len() {
+        32 => Ok(KeyPair::from_seed(Seed::from_slice(&bytes)?)),
+        64 => {
+            let keypair = KeyPair::from_seed(Seed::from_slice(&bytes[..32])?);
+            if keypair.pk.as_ref() != &bytes[32..] {
+                return Err("64-byte private key: public half does not match the seed".into());
+            }
+            Ok(keypair)
+        },
+        n => Err(format!("private key must be a 32-byte seed, got {} bytes", n).into()),
+    }
+}
+
+// Write both key files, creating the directories as needed
+pub fn write_keypair(dir: &Path, key_id: &str, keypair: &KeyPair) -> Result<(), Box<dyn std::error::Error>> {
+    check_key_id(key_id)?;
+    fs::create_dir_all(dir)?;
+    fs::write(private_key_path(dir, key_id), hex::encode(keypair.sk.seed().as_ref()))?;
+    write_public_key(dir, key_id, keypair)
+}
+
+pub fn write_public_key(dir: &Path, key_id: &str, keypair: &KeyPair) -> Result<(), Box<dyn std::error::Error>> {
+    check_key_id(key_id)?;
+    fs::create_dir_all(dir.join("public"))?;
+    fs::write(public_key_path(dir, key_id), hex::encode(keypair.pk.as_ref()))?;
+    Ok(())
+}
+
+// Load the private key only, deriving its public key
+pub fn load_private_key(dir: &Path, key_id: &str) -> Result<KeyPair, Box<dyn std::error::Error>> {
+    check_key_id(key_id)?;
+    let path = private_key_path(dir, key_id);
+    let contents = fs::read_to_string(&path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    parse_private_key(&contents).map_err(|e| format!("{}: {}", path.display(), e).into())
+}
+
+// Load both key files and check that the stored public key belongs to the private key
+pub fn load_keypair(dir: &Path, key_id: &str) -> Result<KeyPair, Box<dyn std::error::Error>> {
+    let keypair = load_private_key(dir, key_id)?;
+    let path = public_key_path(dir, key_id);
+    let stored = fs::read_to_string(&path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    if !stored.trim().eq_ignore_ascii_case(&hex::encode(keypair.pk.as_ref())) {
+        return Err(format!("{} does not match the public key derived from {}", path.display(), private_key_path(dir, key_id).display()).into());
+    }
+    Ok(keypair)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_key_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("risc0_keys_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_keypair_round_trip() {
+        let dir = temp_key_dir("round_trip");
+        let keypair = KeyPair::from_seed(Seed::new([7u8; 32]));
+        write_keypair(&dir, "default", &keypair).unwrap();
+
+        let private = fs::read_to_string(private_key_path(&dir, "default")).unwrap();
+        let loaded = load_keypair(&dir, "default");
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(private.len(), 64);
+        assert_eq!(loaded.unwrap().pk, keypair.pk);
+    }
+
+    #[test]
+    fn test_legacy_64_byte_key_loads() {
+        let keypair = KeyPair::from_seed(Seed::new([7u8; 32]));
+        let legacy = format!("{}{}", hex::encode([7u8; 32]), hex::encode(keypair.pk.as_ref()));
+        assert_eq!(parse_private_key(&legacy).unwrap().pk, keypair.pk);
+
+        let other = KeyPair::from_seed(Seed::new([8u8; 32]));
+        let mismatched = format!("{}{}", hex::encode([7u8; 32]), hex::encode(other.pk.as_ref()));
+        assert!(parse_private_key(&mismatched).is_err());
+        assert!(parse_private_key(&"ab".repeat(16)).is_err());
+    }
+
+    #[test]
+    fn test_named_keys_are_separate() {
+        let dir = temp_key_dir("named");
+        write_keypair(&dir, "alice", &KeyPair::from_seed(Seed::new([1u8; 32]))).unwrap();
+        write_keypair(&dir, "bob", &KeyPair::from_seed(Seed::new([2u8; 32]))).unwrap();
+        let alice = load_keypair(&dir, "alice").unwrap();
+        let bob = load_keypair(&dir, "bob").unwrap();
+        let missing = load_keypair(&dir, "carol");
+        let _ = fs::remove_dir_all(&dir);
+        assert_ne!(alice.pk, bob.pk);
+        assert!(missing.unwrap_err().to_string().contains("carol.key"));
+        assert!(check_key_id("../default").is_err());
+        assert!(check_key_id("").is_err());
+    }
+
+    #[test]
+    fn test_mismatched_public_key_file_is_rejected() {
+        let dir = temp_key_dir("mismatch");
+        write_keypair(&dir, "default", &KeyPair::from_seed(Seed::new([7u8; 32]))).unwrap();
+        write_public_key(&dir, "default", &KeyPair::from_seed(Seed::new([8u8; 32]))).unwrap();
+        let loaded = load_keypair(&dir, "default");
+        let _ = fs::remove_dir_all(&dir);
+        assert!(loaded.is_err());
+    }
+}
@@ -0,0 +1,748 @@
+// Library API for proving without going through the `host` binary. `prove` runs a `ProveRequest`
+// (a registered operation, or a dynamic or precompiled guest program), verifies the receipt and
+// decodes the journal; `prove_operation` is the shortcut for one registered operation. The binary
+// parses its flags into a request, prints what comes back and saves the proof files.
+use methods::journal::{decode_dynamic_result, trace_fields, AuthenticatedAddJournal, Field, GcdJournal, JournalCodec, RangeJournal, RESULT_ENVELOPE_MAGIC};
+use methods::operation::{write_input, DecodedJournal, Operation, Registry, ResultType};
+use risc0_zkvm::sha::Digest;
+use risc0_zkvm::{compute_image_id, default_executor, default_prover, BonsaiProver, ExecutorEnv, ExecutorEnvBuilder, ExitCode, Journal, Prover, ProverOpts, Receipt, VerifierContext};
+use std::fmt;
+use std::fs;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub mod dynamic;
+pub mod keys;
+pub mod proofs;
+
+// Fixed-point arithmetic scale factor and conversions, shared with the guests through the methods crate
+pub use methods::scale::{decimal_to_fixed_point, fixed_point_to_decimal, SCALE};
+
+#[derive(Debug)]
+pub enum ProveError {
+    // The name is not registered in `Registry::builtin`
+    UnknownOperation(String),
+    // Wrong number of inputs, unparsable values, or inputs of the wrong kind for the operation
+    InvalidInputs(String),
+    // Building the executor environment or proving failed
+    Prover(String),
+    // The receipt did not verify against the operation's image ID
+    Verification(String),
+    // The journal does not match the layout the operation's guest commits
+    Journal(String),
+    // Resident memory went over `ProveRequest::max_memory_mb`, and the prover was stopped
+    MemoryLimit(String),
+}
+
+impl fmt::Display for ProveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProveError::UnknownOperation(name) => write!(f, "Unknown operation '{}'", name),
+            ProveError::InvalidInputs(message) => write!(f, "Invalid inputs: {}", message),
+            ProveError::Prover(message) => write!(f, "Proving failed: {}", message),
+            ProveError::Verification(message) => write!(f, "Receipt verification failed: {}", message),
+            ProveError::Journal(message) => write!(f, "Invalid journal: {}", message),
+            ProveError::MemoryLimit(message) => write!(f, "Proving aborted: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ProveError {}
+
+// One value of a dynamic guest's typed inputs. Each is written with its own `env::write`, so the
+// guest reads them in order with `env::read` instead of parsing a JSON string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedInput {
+    I64(i64),
+    U64(u64),
+    // A decimal, written as a fixed-point i64 scaled by SCALE
+    Fixed(f64),
+    String(String),
+}
+
+impl TypedInput {
+    // One `{"<type>": value}` object, where type is i64, u64, fixed or string
+    fn from_json(value: &serde_json::Value) -> Result<Self, ProveError> {
+        let invalid = || ProveError::InvalidInputs(format!("typed input must be one of {{\"i64\": n}}, {{\"u64\": n}}, {{\"fixed\": x}} or {{\"string\": s}}, got {}", value));
+        let (kind, inner) = match value.as_object() {
+            Some(object) if object.len() == 1 => object.iter().next().unwrap(),
+            _ => return Err(invalid()),
+        };
+        match kind.as_str() {
+            "i64" => inner.as_i64().map(TypedInput::I64),
+            "u64" => inner.as_u64().map(TypedInput::U64),
+            // Reject decimals whose fixed-point value would not fit in an i64
+            "fixed" => inner.as_f64().filter(|x| (x * SCALE as f64).abs() < i64::MAX as f64).map(TypedInput::Fixed),
+            "string" => inner.as_str().map(|s| TypedInput::String(s.to_string())),
+            _ => None,
+        }.ok_or_else(invalid)
+    }
+}
+
+// Typed inputs requested by dynamic inputs of the form
+// `{"input_mode": "typed", "values": [{"i64": -5}, {"u64": 7}, {"fixed": 1.5}, {"string": "hi"}]}`;
+// None for any other inputs, which are written as a single JSON string as before
+pub fn typed_inputs(inputs: &serde_json::Value) -> Option<Result<Vec<TypedInput>, ProveError>> {
+    if inputs.get("input_mode")?.as_str() != Some("typed") {
+        return None;
+    }
+    Some(match inputs.get("values").and_then(|v| v.as_array()) {
+        Some(values) => values.iter().map(TypedInput::from_json).collect(),
+        None => Err(ProveError::InvalidInputs("typed inputs need a \"values\" array".to_string())),
+    })
+}
+
+// Write typed inputs in order, one `env::write` each
+pub fn write_typed_inputs(builder: &mut ExecutorEnvBuilder<'_>, values: &[TypedInput], reads: &mut Vec<String>) -> Result<(), ProveError> {
+    for (i, value) in values.iter().enumerate() {
+        let name = format!("values[{}]", i);
+        match value {
+            TypedInput::I64(n) => write_input(builder, reads, &name, n),
+            TypedInput::U64(n) => write_input(builder, reads, &name, n),
+            TypedInput::Fixed(x) => write_input(builder, reads, &name, &decimal_to_fixed_point(*x)),
+            TypedInput::String(s) => write_input(builder, reads, &name, s),
+        }.map_err(|e| ProveError::Prover(e.to_string()))?;
+    }
+    Ok(())
+}
+
+// Where proofs are generated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProverKind {
+    Local,
+    // Remote proving through Bonsai, configured by BONSAI_API_URL and BONSAI_API_KEY
+    Bonsai,
+}
+
+impl ProverKind {
+    // Bonsai when both Bonsai variables are set and non-empty, local proving otherwise. Dev mode
+    // always proves locally, as `default_prover` does: Bonsai would generate a real proof
+    pub fn from_env(dev_mode: bool) -> Self {
+        Self::select(std::env::var("BONSAI_API_URL").ok().as_deref(), std::env::var("BONSAI_API_KEY").ok().as_deref(), dev_mode)
+    }
+
+    pub fn select(api_url: Option<&str>, api_key: Option<&str>, dev_mode: bool) -> Self {
+        match (api_url, api_key) {
+            (Some(url), Some(key)) if !dev_mode && !url.is_empty() && !key.is_empty() => ProverKind::Bonsai,
+            _ => ProverKind::Local,
+        }
+    }
+
+    // Reported as `prover` in the binary's JSON output
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProverKind::Local => "local",
+            ProverKind::Bonsai => "bonsai",
+        }
+    }
+
+    pub fn prover(&self) -> Rc<dyn Prover> {
+        match self {
+            ProverKind::Local => default_prover(),
+            // BonsaiProver reads the URL and key from the environment itself
+            ProverKind::Bonsai => Rc::new(BonsaiProver::new("bonsai")),
+        }
+    }
+}
+
+// What a `ProveRequest` runs
+pub enum Program {
+    // A registered operation and its arguments, as `Operation::write_env` takes them
+    Registered(&'static dyn Operation, Vec<String>),
+    // A dynamic or precompiled guest ELF and its inputs: JSON, or raw stdin bytes with `stdio`
+    Guest { operation: &'static str, elf: Vec<u8>, inputs: String },
+}
+
+impl Program {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Program::Registered(op, _) => op.name(),
+            Program::Guest { operation, .. } => operation,
+        }
+    }
+
+    pub fn registered(&self) -> Option<&'static dyn Operation> {
+        match self {
+            Program::Registered(op, _) => Some(*op),
+            Program::Guest { .. } => None,
+        }
+    }
+
+    pub fn elf(&self) -> &[u8] {
+        match self {
+            Program::Registered(op, _) => op.elf(),
+            Program::Guest { elf, .. } => elf,
+        }
+    }
+
+    // Guest programs are not compiled into the host, so their image ID is computed from the ELF
+    pub fn image_id(&self) -> Result<[u32; 8], ProveError> {
+        match self {
+            Program::Registered(op, _) => Ok(op.image_id()),
+            Program::Guest { elf, .. } => {
+                let digest = compute_image_id(elf).map_err(|e| ProveError::InvalidInputs(format!("Invalid guest ELF: {}", e)))?;
+                Ok(<[u32; 8]>::try_from(digest.as_words()).expect("a digest is eight words"))
+            }
+        }
+    }
+
+    // One line saying what is being proven, for progress output
+    pub fn describe(&self) -> String {
+        match self {
+            Program::Registered(op, args) => op.describe_inputs(args),
+            Program::Guest { operation, inputs, .. } => format!("{} execution with inputs: {}", operation, inputs),
+        }
+    }
+
+    // The inputs as reported in the binary's `inputs` output; raw stdin is echoed as a string
+    pub fn echo_inputs(&self, stdio: bool) -> serde_json::Value {
+        match self {
+            Program::Registered(op, args) => op.echo_inputs(args),
+            Program::Guest { inputs, .. } if stdio => serde_json::Value::String(inputs.clone()),
+            Program::Guest { inputs, .. } => echo_json_input(inputs),
+        }
+    }
+}
+
+// Which receipt the prover produces
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReceiptKind {
+    // One seal per segment
+    #[default]
+    Composite,
+    // A single seal, needed for minimal envelopes and bare seals
+    Succinct,
+    // Groth16 wraps the succinct receipt for on-chain verification; local proving needs x86_64 and Docker
+    Groth16,
+}
+
+impl ReceiptKind {
+    fn opts(&self) -> ProverOpts {
+        match self {
+            ReceiptKind::Composite => ProverOpts::composite(),
+            ReceiptKind::Succinct => ProverOpts::succinct(),
+            ReceiptKind::Groth16 => ProverOpts::groth16(),
+        }
+    }
+}
+
+// One proof to generate, and how
+pub struct ProveRequest {
+    pub program: Program,
+    // Earlier receipts an outer guest resolves with `env::verify`
+    pub assumptions: Vec<Receipt>,
+    // Guest programs only: feed `inputs` as raw bytes on the guest's stdin and capture its stdout
+    pub stdio: bool,
+    // state_transition only: the prior state hash it reads first; None starts from the empty genesis state
+    pub prev_state: Option<Digest>,
+    // How a guest program's journal is decoded; registered operations keep their own codec
+    pub journal_codec: JournalCodec,
+    pub receipt_kind: ReceiptKind,
+    // Execute without the STARK and accept the resulting fake receipt. Set here rather than through
+    // RISC0_DEV_MODE, so callers never have to change the process environment.
+    pub dev_mode: bool,
+    // Stop proving with `ProveError::MemoryLimit` once resident memory exceeds this many MB, see
+    // `spawn_memory_monitor`
+    pub max_memory_mb: Option<u64>,
+}
+
+impl ProveRequest {
+    // A composite receipt with no assumptions, in dev mode only when RISC0_DEV_MODE asks for it
+    pub fn new(program: Program) -> Self {
+        let journal_codec = program.registered().map_or(JournalCodec::default(), |op| op.journal_codec());
+        ProveRequest {
+            program,
+            assumptions: Vec::new(),
+            stdio: false,
+            prev_state: None,
+            journal_codec,
+            receipt_kind: ReceiptKind::default(),
+            dev_mode: ProverOpts::default().dev_mode(),
+            max_memory_mb: None,
+        }
+    }
+
+    // The executor environment with the assumptions added and the inputs written; `reads` lists the
+    // inputs in the order the guest reads them
+    fn env<'a>(&self, guest_stdout: &'a mut Vec<u8>, reads: &mut Vec<String>) -> Result<ExecutorEnv<'a>, ProveError> {
+        let mut builder = ExecutorEnv::builder();
+        for assumption in &self.assumptions {
+            builder.add_assumption(assumption.clone());
+        }
+        if self.program.name() == "state_transition" {
+            write_input(&mut builder, reads, "prev_state", &self.prev_state.unwrap_or(Digest::ZERO)).map_err(|e| ProveError::Prover(e.to_string()))?;
+        } else if self.prev_state.is_some() {
+            return Err(ProveError::InvalidInputs("a prior state only applies to state_transition".to_string()));
+        }
+        
+        match &self.program {
+            Program::Registered(op, args) => op.write_env(&mut builder, args, reads).map_err(|e| ProveError::InvalidInputs(e.to_string()))?,
+            // Raw stdin/stdout for guests that use `env::stdin().read_to_end(..)` instead of typed reads
+            Program::Guest { inputs, .. } if self.stdio => {
+                reads.push(format!("stdin = {} raw bytes", inputs.len()));
+                builder.stdin(std::io::Cursor::new(inputs.as_bytes().to_vec())).stdout(guest_stdout);
+            },
+            Program::Guest { inputs, .. } => {
+                let parsed: serde_json::Value = serde_json::from_str(inputs)
+                    .map_err(|e| ProveError::InvalidInputs(format!("Invalid JSON inputs: {}", e)))?;
+                // `"input_mode": "typed"` writes each value separately; otherwise the guest gets the JSON string
+                match typed_inputs(&parsed) {
+                    Some(values) => write_typed_inputs(&mut builder, &values?, reads)?,
+                    None => write_input(&mut builder, reads, "inputs_json", inputs).map_err(|e| ProveError::Prover(e.to_string()))?,
+                }
+            }
+        }
+        builder.build().map_err(|e| ProveError::Prover(e.to_string()))
+    }
+}
+
+// A decoded journal, and the JSON result only guest programs commit
+pub struct ProofResult {
+    pub decoded: DecodedJournal,
+    // The headline value's natural JSON type; None when there is no single value
+    pub result_type: Option<ResultType>,
+    // Only for guest programs: the committed result as JSON (a number for a bare i64)
+    pub value: Option<serde_json::Value>,
+}
+
+// Decode a journal: registered operations by their own layout, guest programs as a result envelope
+// or a leading i64 in `codec`. Side-effect-only guests may commit nothing; the proof is still verifiable.
+pub fn decode_result(program: &Program, codec: JournalCodec, journal: &Journal) -> Result<ProofResult, ProveError> {
+    if journal.bytes.is_empty() {
+        return Ok(ProofResult { decoded: DecodedJournal::empty(), result_type: None, value: None });
+    }
+    if let Some(op) = program.registered() {
+        let decoded = op.decode_journal(journal).map_err(|e| ProveError::Journal(e.to_string()))?;
+        return Ok(ProofResult { decoded, result_type: Some(op.result_type()), value: None });
+    }
+    
+    let value = decode_dynamic_result(codec, &journal.bytes)
+        .map_err(|e| ProveError::Journal(format!("{} journal for {}: {}", codec, program.name(), e)))?;
+    let (decoded, result_type) = match value.as_f64() {
+        Some(number) => (
+            DecodedJournal::new(number, value.as_i64().unwrap_or(number as i64)),
+            Some(if value.is_f64() { ResultType::Decimal } else { ResultType::Integer }),
+        ),
+        None => (DecodedJournal::error("structured result in result_value"), None),
+    };
+    Ok(ProofResult { decoded: decoded.line(format!("🔢 Dynamic computation result: {}", value)), result_type, value: Some(value) })
+}
+
+// Everything `prove` produced. A receipt that failed verification is still returned, with the
+// reason in `verification`.
+pub struct Proof {
+    pub receipt: Receipt,
+    pub image_id: [u32; 8],
+    pub verification: Result<(), String>,
+    pub result: ProofResult,
+    // Only with `stdio`: what the guest wrote to stdout
+    pub guest_stdout: Vec<u8>,
+    pub prover: ProverKind,
+    // How the guest halted, see `proofs::halt_status`
+    pub halt_reason: &'static str,
+    pub exit_code: Option<u32>,
+    // From the prover's session stats
+    pub total_cycles: u64,
+    pub user_cycles: u64,
+    pub segments: usize,
+    // Highest resident memory sampled while proving, including the prover subprocess; None off Linux
+    pub peak_memory_mb: Option<u64>,
+    pub prove_time: Duration,
+    pub verify_time: Duration,
+}
+
+// Prove a request (on Bonsai when it is configured, see `ProverKind::from_env`), verify the receipt
+// against the program's image ID and decode the journal
+pub fn prove(request: &ProveRequest) -> Result<Proof, ProveError> {
+    let image_id = request.program.image_id()?;
+    let mut guest_stdout = Vec::new();
+    let env = request.env(&mut guest_stdout, &mut Vec::new())?;
+    let prover = ProverKind::from_env(request.dev_mode);
+    let opts = request.receipt_kind.opts().with_dev_mode(request.dev_mode);
+    
+    let prove_start = Instant::now();
+    let proving_done = Arc::new(AtomicBool::new(false));
+    let memory_monitor = spawn_memory_monitor(request.max_memory_mb, proving_done.clone());
+    let prove_info = prover.prover().prove_with_opts(env, request.program.elf(), &opts);
+    proving_done.store(true, Ordering::Relaxed);
+    let memory = memory_monitor.join().unwrap_or_default();
+    // Checked before the prover's own result, which is only the error from the killed subprocess
+    if let (Some(max_mb), Some(used_mb)) = (request.max_memory_mb, memory.exceeded_mb) {
+        return Err(ProveError::MemoryLimit(format!("resident memory {} MB exceeds the {} MB limit", used_mb, max_mb)));
+    }
+    let prove_info = prove_info.map_err(|e| ProveError::Prover(e.to_string()))?;
+    let prove_time = prove_start.elapsed();
+    let receipt = prove_info.receipt;
+    
+    let verify_start = Instant::now();
+    let verification = receipt.verify_with_context(&VerifierContext::default().with_dev_mode(request.dev_mode), image_id).map_err(|e| e.to_string());
+    let verify_time = verify_start.elapsed();
+    
+    // A guest that calls `env::exit` with a non-zero code still yields a receipt
+    let (halt_reason, exit_code) = proofs::halt_status(&receipt).map_err(|e| ProveError::Prover(e.to_string()))?;
+    let result = decode_result(&request.program, request.journal_codec, &receipt.journal)?;
+    
+    Ok(Proof {
+        image_id,
+        verification,
+        result,
+        guest_stdout,
+        prover,
+        halt_reason,
+        exit_code,
+        total_cycles: prove_info.stats.total_cycles,
+        user_cycles: prove_info.stats.user_cycles,
+        segments: prove_info.stats.segments,
+        peak_memory_mb: memory.peak_mb,
+        prove_time,
+        verify_time,
+        receipt,
+    })
+}
+
+// What `--trace` prints: the inputs in the order the guest read them, the values it committed and how it exited
+pub struct Trace {
+    pub reads: Vec<String>,
+    pub commits: Vec<String>,
+    pub exit_code: ExitCode,
+}
+
+// Execute a request's guest without proving
+pub fn trace(request: &ProveRequest) -> Result<Trace, ProveError> {
+    let mut guest_stdout = Vec::new();
+    let mut reads = Vec::new();
+    let env = request.env(&mut guest_stdout, &mut reads)?;
+    let session = default_executor().execute(env, request.program.elf()).map_err(|e| ProveError::Prover(e.to_string()))?;
+    Ok(Trace {
+        commits: trace_commits(request.program.registered(), request.program.name(), request.journal_codec, &session.journal.bytes),
+        exit_code: session.exit_code,
+        reads,
+    })
+}
+
+// Split a journal into its committed values for `--trace`: registered operations list their own
+// layout, and a dynamic guest's result is read as its envelope or a leading i64
+fn trace_commits(registered: Option<&dyn Operation>, operation: &str, codec: JournalCodec, bytes: &[u8]) -> Vec<String> {
+    if let Some(op) = registered {
+        return op.trace_journal(codec, bytes);
+    }
+    
+    // An enveloped dynamic result is one value, listed as the JSON it decodes to
+    if bytes.starts_with(&RESULT_ENVELOPE_MAGIC) {
+        return vec![match decode_dynamic_result(codec, bytes) {
+            Ok(value) => format!("result = {}", value),
+            Err(e) => format!("<{}>", e),
+        }];
+    }
+    trace_fields(operation, codec, bytes, |journal, commits| journal.read_fields(&[("result", Field::I64)], commits))
+}
+
+// JSON inputs are echoed as parsed JSON; anything unparseable is kept as a string so the output stays valid
+fn echo_json_input(input: &str) -> serde_json::Value {
+    serde_json::from_str(input).unwrap_or_else(|_| serde_json::Value::String(input.to_string()))
+}
+
+// The host's independent computation of a registered operation next to the guest's committed value
+#[derive(Debug)]
+pub struct HostComparison {
+    pub host_value: i64,
+    pub guest_value: i64,
+    // guest - host; None when the difference does not fit in an i64
+    pub difference: Option<i64>,
+    // Equal up to the operation's rounding tolerance
+    pub matches: bool,
+    fixed_point: bool,
+}
+
+impl HostComparison {
+    // `tolerance` absorbs expected rounding differences; a difference too large for an i64 is a divergence
+    pub fn new(op: &dyn Operation, host_value: i64, guest_value: i64, tolerance: i64) -> Self {
+        let difference = guest_value.checked_sub(host_value);
+        let matches = difference.and_then(i64::checked_abs).is_some_and(|distance| distance <= tolerance);
+        HostComparison { host_value, guest_value, difference, matches, fixed_point: op.result_type() == ResultType::Decimal }
+    }
+
+    // Fixed-point values are shown as decimals
+    pub fn format_value(&self, value: i64) -> String {
+        if self.fixed_point {
+            format!("{} (fixed-point {})", fixed_point_to_decimal(value), value)
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+// Cross-check a guest's committed value against the host's own computation from the same arguments;
+// None for operations the host cannot compute
+pub fn compare_host_compute(op: &dyn Operation, args: &[String], guest_value: i64) -> Option<HostComparison> {
+    let (host_value, tolerance) = op.host_expected(args)?;
+    Some(HostComparison::new(op, host_value, guest_value, tolerance))
+}
+
+// Value in kB of a field such as "VmRSS:\t  123456 kB" from /proc/<pid>/status
+fn parse_status_kb(status: &str, field: &str) -> Option<u64> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix(field)?.strip_prefix(':'))?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
+}
+
+// Value in kB of a /proc/<pid>/status field, for "self" or a numeric pid; None where /proc is unavailable
+fn process_status_kb(pid: &str, field: &str) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    parse_status_kb(&status, field)
+}
+
+// Pids of this process's direct children, from the parent pid in each /proc/<pid>/stat
+fn child_pids() -> Vec<u32> {
+    let parent = std::process::id();
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u32>().ok())
+        .filter(|pid| {
+            // The parent pid follows the state, after the parenthesised command name (which may hold spaces)
+            let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok();
+            stat.and_then(|stat| stat.rsplit_once(')')?.1.split_whitespace().nth(1)?.parse::<u32>().ok()) == Some(parent)
+        })
+        .collect()
+}
+
+// Resident memory while proving, as sampled by `spawn_memory_monitor`
+#[derive(Debug, Default)]
+struct MemoryUsage {
+    // Highest sample in MB; None where /proc is unavailable
+    peak_mb: Option<u64>,
+    // The sample that went over `max_memory_mb`, after which the prover subprocesses were killed
+    exceeded_mb: Option<u64>,
+}
+
+// Sample resident memory every 100 ms until `done` is set, or until a sample exceeds `max_memory_mb`.
+// risc0-zkvm is built without its `prove` feature, so `default_prover()` proves in an `r0vm`
+// subprocess; each sample is this process plus the children started since proving began, and those
+// children are killed once the limit is exceeded, which makes `prove_with_opts` fail. This is a soft
+// guard: allocation spikes between samples can still overshoot.
+fn spawn_memory_monitor(max_memory_mb: Option<u64>, done: Arc<AtomicBool>) -> std::thread::JoinHandle<MemoryUsage> {
+    let existing = child_pids();
+    std::thread::spawn(move || {
+        let mut usage = MemoryUsage::default();
+        loop {
+            let provers: Vec<u32> = child_pids().into_iter().filter(|pid| !existing.contains(pid)).collect();
+            let sample_kb = provers.iter().fold(process_status_kb("self", "VmRSS"), |total, pid| {
+                Some(total? + process_status_kb(&pid.to_string(), "VmRSS").unwrap_or(0))
+            });
+            if let Some(mb) = sample_kb.map(|kb| kb / 1024) {
+                usage.peak_mb = usage.peak_mb.max(Some(mb));
+                if max_memory_mb.is_some_and(|max_mb| mb > max_mb) {
+                    usage.exceeded_mb = Some(mb);
+                    stop_provers(&provers);
+                    return usage;
+                }
+            }
+            if done.load(Ordering::Relaxed) {
+                return usage;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    })
+}
+
+// Kill prover subprocesses that went over the memory limit
+#[cfg_attr(not(unix), allow(unused_variables))]
+fn stop_provers(pids: &[u32]) {
+    #[cfg(unix)]
+    for pid in pids {
+        // SAFETY: kill(2) has no memory-safety preconditions
+        unsafe {
+            libc::kill(*pid as libc::pid_t, libc::SIGKILL);
+        }
+    }
+}
+
+// Everything a successful `prove_operation` produced
+pub struct ProofOutput {
+    pub operation: &'static str,
+    // None when the guest committed nothing or reported an error; `result_note` says which
+    pub result: Option<f64>,
+    pub result_fixed: i64,
+    // Only for integer operations: the exact committed value, see `DecodedJournal::integer`
+    pub result_integer: Option<u64>,
+    pub result_note: Option<&'static str>,
+    // Whether add, subtract, multiply, divide, power or factorial overflowed (for batch, any pair); None for operations without an overflow flag
+    pub overflow: Option<bool>,
+    // Only for batch: one result per input pair, in input order; None where that pair overflowed
+    pub results: Option<Vec<Option<f64>>>,
+    // Only for modinverse: whether an inverse exists
+    pub invertible: Option<bool>,
+    // Only for gcd: the inputs, gcd and Bézout coefficients
+    pub bezout: Option<GcdJournal>,
+    // Only for range: every committed value, including the empty-range and exclusivity flags
+    pub range: Option<RangeJournal>,
+    // Only for authenticated_add: the signed computation, signature already checked
+    pub authenticated: Option<AuthenticatedAddJournal>,
+    pub journal: Vec<u8>,
+    pub image_id: [u32; 8],
+    pub prover: ProverKind,
+    // bincode-serialized `Receipt`, the same bytes the binary saves as proof_<operation>_<timestamp>.bin
+    pub receipt: Vec<u8>,
+}
+
+// Prove one registered operation (on Bonsai when it is configured, see `ProverKind::from_env`) from
+// its CLI arguments, verify the receipt against the operation's image ID and decode its result
+pub fn prove_operation(operation: &str, args: &[String]) -> Result<ProofOutput, ProveError> {
+    let op = Registry::builtin().get(operation).ok_or_else(|| ProveError::UnknownOperation(operation.to_string()))?;
+    prove_operation_with(&ProveRequest::new(Program::Registered(op, args.to_vec())))
+}
+
+// `prove_operation` for a request built by the caller, e.g. one that sets `dev_mode` itself
+// instead of inheriting RISC0_DEV_MODE
+pub fn prove_operation_with(request: &ProveRequest) -> Result<ProofOutput, ProveError> {
+    let Program::Registered(op, _) = &request.program else {
+        return Err(ProveError::UnknownOperation(request.program.name().to_string()));
+    };
+    let Proof { receipt, image_id, verification, result, prover, .. } = prove(request)?;
+    verification.map_err(ProveError::Verification)?;
+    
+    let decoded = result.decoded;
+    let receipt_bytes = bincode::serialize(&receipt).map_err(|e| ProveError::Prover(format!("Failed to serialize receipt: {}", e)))?;
+    Ok(ProofOutput {
+        operation: op.name(),
+        result: decoded.note.is_none().then_some(decoded.value),
+        result_fixed: decoded.fixed,
+        result_integer: decoded.integer,
+        result_note: decoded.note,
+        overflow: decoded.overflow,
+        results: decoded.results,
+        invertible: decoded.invertible,
+        bezout: decoded.bezout,
+        range: decoded.range,
+        authenticated: decoded.authenticated,
+        journal: receipt.journal.bytes,
+        image_id,
+        prover,
+        receipt: receipt_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_prove_operation_rejects_bad_requests_before_proving() {
+        assert!(matches!(prove_operation("dynamic", &to_args(&["guest.rs", "{}"])), Err(ProveError::UnknownOperation(_))));
+        assert!(matches!(prove_operation("add", &to_args(&["1"])), Err(ProveError::InvalidInputs(_))));
+        assert!(matches!(prove_operation("modexp", &to_args(&["2", "-3", "5"])), Err(ProveError::InvalidInputs(_))));
+        assert!(matches!(prove_operation("range", &to_args(&["5", "10", "3"])), Err(ProveError::InvalidInputs(_))));
+        assert!(matches!(prove_operation("batch", &to_args(&["divide", "[[1, 2]]"])), Err(ProveError::InvalidInputs(_))));
+    }
+
+    #[test]
+    fn test_typed_inputs() {
+        let inputs = serde_json::json!({"input_mode": "typed", "values": [{"i64": -5}, {"u64": 7}, {"fixed": 1.5}, {"string": "hi"}]});
+        assert_eq!(typed_inputs(&inputs).unwrap().unwrap(), vec![
+            TypedInput::I64(-5), TypedInput::U64(7), TypedInput::Fixed(1.5), TypedInput::String("hi".to_string()),
+        ]);
+        
+        // Anything else keeps the single JSON string mode
+        assert!(typed_inputs(&serde_json::json!({"values": [{"i64": 1}]})).is_none());
+        assert!(typed_inputs(&serde_json::json!([1, 2])).is_none());
+        
+        for bad in [
+            serde_json::json!({"input_mode": "typed"}),
+            serde_json::json!({"input_mode": "typed", "values": [{"u64": -1}]}),
+            serde_json::json!({"input_mode": "typed", "values": [{"f32": 1.0}]}),
+            serde_json::json!({"input_mode": "typed", "values": [{"i64": 1, "u64": 2}]}),
+            serde_json::json!({"input_mode": "typed", "values": [{"fixed": 1e300}]}),
+        ] {
+            assert!(matches!(typed_inputs(&bad), Some(Err(ProveError::InvalidInputs(_)))), "accepted {}", bad);
+        }
+    }
+
+    #[test]
+    fn test_prover_kind_needs_both_bonsai_variables() {
+        assert_eq!(ProverKind::select(Some("https://api.bonsai.xyz"), Some("key"), false), ProverKind::Bonsai);
+        assert_eq!(ProverKind::select(Some("https://api.bonsai.xyz"), None, false), ProverKind::Local);
+        assert_eq!(ProverKind::select(None, Some("key"), false), ProverKind::Local);
+        assert_eq!(ProverKind::select(Some(""), Some("key"), false), ProverKind::Local);
+        // Dev mode proves locally even with Bonsai configured
+        assert_eq!(ProverKind::select(Some("https://api.bonsai.xyz"), Some("key"), true), ProverKind::Local);
+        assert_eq!(ProverKind::Bonsai.name(), "bonsai");
+    }
+
+    #[test]
+    fn test_compare_host_compute_detects_divergence() {
+        // 2.5 * 4.0 computed by the host vs. a guest that divided by the wrong scale
+        let registry = Registry::builtin();
+        let op = |name: &str| registry.get(name).unwrap();
+        let host_value = decimal_to_fixed_point(10.0);
+        assert!(HostComparison::new(op("multiply"), host_value, host_value, 0).matches);
+        assert!(!HostComparison::new(op("multiply"), host_value, host_value * 10, 0).matches);
+        assert_eq!(HostComparison::new(op("subtract"), i64::MIN, i64::MAX, 0).difference, None);
+        assert_eq!(HostComparison::new(op("multiply"), host_value, host_value, 0).format_value(host_value), "10 (fixed-point 1000000)");
+        
+        // Rounding tolerance only absorbs off-by-one differences
+        assert!(HostComparison::new(op("sqrt"), 141421, 141420, 1).matches);
+        assert!(!HostComparison::new(op("sqrt"), 141421, 141419, 1).matches);
+        
+        // The host computes from the same arguments the guest was given
+        let comparison = compare_host_compute(op("modexp"), &to_args(&["4", "13", "497"]), 446).unwrap();
+        assert_eq!(comparison.host_value, 445);
+        assert!(!comparison.matches);
+        assert_eq!(comparison.format_value(445), "445");
+        assert!(compare_host_compute(op("k_means"), &to_args(&["{}"]), 0).is_none());
+    }
+
+    #[test]
+    fn test_trace_commits_decodes_result_envelope() {
+        use methods::journal::{encode_result_envelope, RESULT_TAG_JSON};
+        
+        let envelope = encode_result_envelope(RESULT_TAG_JSON, b"[1, 2, 3]");
+        assert_eq!(trace_commits(None, "dynamic", JournalCodec::Risc0Serde, &envelope), vec!["result = [1,2,3]"]);
+        
+        // Without the envelope a dynamic journal still reads as a leading i64
+        assert_eq!(trace_commits(None, "dynamic", JournalCodec::RawLe, &7i64.to_le_bytes()), vec!["result = 7"]);
+    }
+
+    #[test]
+    fn test_parse_status_kb() {
+        let status = "Name:\thost\nVmPeak:\t 2048000 kB\nVmHWM:\t  524288 kB\nVmRSS:\t  262144 kB\n";
+        assert_eq!(parse_status_kb(status, "VmHWM"), Some(524288));
+        assert_eq!(parse_status_kb(status, "VmRSS"), Some(262144));
+        assert_eq!(parse_status_kb(status, "VmSwap"), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_memory_monitor_counts_children_and_stops_over_the_limit() {
+        let mut child = std::process::Command::new("sleep").arg("5").spawn().unwrap();
+        assert!(child_pids().contains(&child.id()));
+        let _ = child.kill();
+        let _ = child.wait();
+        
+        // This process alone holds more than 1 MB, so the first sample is over the limit
+        let usage = spawn_memory_monitor(Some(1), Arc::new(AtomicBool::new(false))).join().unwrap();
+        assert!(usage.exceeded_mb.is_some_and(|mb| mb > 1));
+        assert_eq!(usage.peak_mb, usage.exceeded_mb);
+        
+        // Without a limit the monitor samples until proving is done
+        let usage = spawn_memory_monitor(None, Arc::new(AtomicBool::new(true))).join().unwrap();
+        assert!(usage.peak_mb.is_some() && usage.exceeded_mb.is_none());
+    }
+
+    #[test]
+    fn test_echo_json_input_keeps_output_valid() {
+        assert_eq!(echo_json_input(r#"{"points": [1, 2]}"#), serde_json::json!({ "points": [1, 2] }));
+        assert_eq!(echo_json_input("not json"), serde_json::Value::String("not json".to_string()));
+    }
+}
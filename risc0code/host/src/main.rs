@@ -1,57 +1,15 @@
-use methods::{ADDITION_ELF, ADDITION_ID, MULTIPLY_GUEST_ELF, MULTIPLY_GUEST_ID, SQRT_GUEST_ELF, SQRT_GUEST_ID, MODEXP_GUEST_ELF, MODEXP_GUEST_ID, GUEST_RANGE_ELF, GUEST_RANGE_ID, GUEST_AUTHENTICATED_ADD_ELF, GUEST_AUTHENTICATED_ADD_ID, GUEST_K_MEANS_ELF, GUEST_K_MEANS_ID, GUEST_LINEAR_REGRESSION_ELF, GUEST_LINEAR_REGRESSION_ID, GUEST_NEURAL_NETWORK_ELF, GUEST_NEURAL_NETWORK_ID, GUEST_LOGISTIC_REGRESSION_ELF, GUEST_LOGISTIC_REGRESSION_ID};
-use risc0_zkvm::{default_prover, ExecutorEnv, compute_image_id};
-use std::mem;
+use host::proofs::{self, Envelope};
+use host::{dynamic, keys, Program, ProveError, ProveRequest, ProverKind, ReceiptKind};
+use methods::journal::{GcdJournal, JournalCodec};
+use methods::operation::Registry;
+use methods::proof_file::{self, ProofMeta};
+use risc0_zkvm::compute_image_id;
+use risc0_zkvm::sha::{Digest, Digestible};
+use serde::Serialize;
+use std::str::FromStr;
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use std::fs;
 use std::process::Command;
-use hex;
-
-// Fixed-point arithmetic scale factor (5 decimal places for better precision)
-const SCALE: i64 = 100000;
-
-// Convert decimal number to fixed-point representation
-fn decimal_to_fixed_point(decimal: f64) -> i64 {
-    (decimal * SCALE as f64).round() as i64
-}
-
-// Convert fixed-point representation back to decimal
-fn fixed_point_to_decimal(fixed: i64) -> f64 {
-    fixed as f64 / SCALE as f64
-}
-
-// Host-side modular exponentiation for verification
-fn modular_exponentiation_host(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
-    if modulus == 0 {
-        return 0;
-    }
-    
-    if modulus == 1 {
-        return 0;
-    }
-    
-    if exponent == 0 {
-        return 1;
-    }
-    
-    base = base % modulus;
-    
-    if base == 0 {
-        return 0;
-    }
-    
-    let mut result = 1u64;
-    
-    while exponent > 0 {
-        if exponent & 1 == 1 {
-            result = ((result as u128 * base as u128) % modulus as u128) as u64;
-        }
-        
-        base = ((base as u128 * base as u128) % modulus as u128) as u64;
-        exponent >>= 1;
-    }
-    
-    result
-}
 
 // Integer square root using binary search
 // Returns the largest integer x such that x² ≤ n
@@ -94,57 +52,357 @@ fn integer_sqrt(n: u32) -> u32 {
     result
 }
 
-fn regenerate_public_key() -> Result<(), Box<dyn std::error::Error>> {
-    use ed25519_compact::Seed;
-    
-    println!("Generating a new valid Ed25519 key pair...");
-    
-    // Generate a seed and let the library handle key derivation properly
-    let seed = Seed::generate();
-    println!("Generated seed (32 bytes): {}", hex::encode(seed.as_ref()));
+fn regenerate_public_key(key_dir: &std::path::Path, key_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+    use ed25519_compact::{KeyPair, Seed};
     
-    // Create keys using the library's KeyPair mechanism for consistency
-    let keypair = ed25519_compact::KeyPair::from_seed(seed);
-    let secret_key = keypair.sk;
-    let public_key = keypair.pk;
+    println!("Generating a new valid Ed25519 key pair '{}'...", key_id);
     
-    // Get the key bytes
-    let secret_key_bytes = secret_key.as_ref();
-    let public_key_bytes = public_key.as_ref();
-    
-    println!("Private key length: {} bytes", secret_key_bytes.len());
-    println!("Private key: {}", hex::encode(secret_key_bytes));
-    println!("Public key (32 bytes): {}", hex::encode(public_key_bytes));
+    let keypair = KeyPair::from_seed(Seed::generate());
+    println!("Private key seed (32 bytes): {}", hex::encode(keypair.sk.seed().as_ref()));
+    println!("Public key (32 bytes): {}", hex::encode(keypair.pk.as_ref()));
     
     // Test signing and verification
     let test_message = b"test message";
-    let signature = secret_key.sign(test_message, None);
-    let is_valid = public_key.verify(test_message, &signature).is_ok();
+    let signature = keypair.sk.sign(test_message, None);
+    let is_valid = keypair.pk.verify(test_message, &signature).is_ok();
     println!("Key validation test: {}", if is_valid { "PASSED" } else { "FAILED" });
     
     if !is_valid {
         return Err("Generated key pair failed validation test".into());
     }
     
-    // Store the keys in the format expected by our zkVM code (64 bytes)
-    let key_to_store = if secret_key_bytes.len() == 32 {
-        // Extend to 64-byte format: seed + public_key
-        let mut extended_key = [0u8; 64];
-        extended_key[..32].copy_from_slice(seed.as_ref()); // Use original seed
-        extended_key[32..].copy_from_slice(public_key_bytes);
-        extended_key
+    // Stored in the format `host::keys` documents, which `regenerate_pubkey` reads back
+    keys::write_keypair(key_dir, key_id, &keypair)?;
+    
+    println!("Key files updated successfully!");
+    println!("Private key: {}", keys::private_key_path(key_dir, key_id).display());
+    println!("Public key: {}", keys::public_key_path(key_dir, key_id).display());
+    Ok(())
+}
+
+// Remove every `<flag> <value>` pair from the argument list and return the values
+fn take_flag_values(args: &mut Vec<String>, flag: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut values = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == flag {
+            if i + 1 >= args.len() {
+                return Err(format!("{} requires a value", flag).into());
+            }
+            values.push(args.remove(i + 1));
+            args.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(values)
+}
+
+// Remove a boolean `<flag>` from the argument list, returning whether it was present
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let before = args.len();
+    args.retain(|a| a != flag);
+    args.len() != before
+}
+
+// Path of a generated proof file: under --output-dir when given, otherwise the bare filename in the CWD
+fn output_file(output_dir: &std::path::Path, filename: &str) -> String {
+    output_dir.join(filename).to_string_lossy().into_owned()
+}
+
+// `--list-proofs <dir>`: one row per saved proof, oldest first
+fn list_proofs(dir: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let inventory = proofs::proof_inventory(dir)?;
+    for (path, e) in &inventory.skipped {
+        eprintln!("⚠️  Skipping {}: {}", path.display(), e);
+    }
+    
+    println!("{:<48} {:<24} {:<64} {:>8}  RESULT", "FILENAME", "OPERATION", "IMAGE ID", "JOURNAL");
+    for summary in &inventory.summaries {
+        println!("{:<48} {:<24} {:<64} {:>8}  {}", summary.filename, summary.operation, summary.image_id, summary.journal_len, summary.result);
+    }
+    eprintln!("📋 {} proof file(s) in {}", inventory.summaries.len(), dir);
+    Ok(())
+}
+
+// Guests `main` loads itself rather than through the registry, as (name, positional arguments,
+// description). Registered operations describe themselves through the `Operation` trait.
+const GUEST_PROGRAM_OPERATIONS: &[(&str, &str, &str)] = &[
+    ("dynamic", "<guest_program_path> <inputs_json|raw_stdin>", "Compile and prove a Rust guest program"),
+    ("precompiled", "<guest_binary_path> <inputs_json|raw_stdin>", "Prove a prebuilt guest ELF"),
+];
+
+// One `list-operations` entry
+#[derive(Debug, Serialize)]
+struct OperationInfo {
+    name: &'static str,
+    arg_count: usize,
+    usage: &'static str,
+    description: &'static str,
+}
+
+// Every operation the host accepts: the registry's in registration order, then the guest programs
+fn operation_catalog(registry: &Registry) -> Vec<OperationInfo> {
+    let registered = registry.iter().map(|op| OperationInfo {
+        name: op.name(),
+        arg_count: op.arg_count(),
+        usage: op.usage(),
+        description: op.description(),
+    });
+    let guest_programs = GUEST_PROGRAM_OPERATIONS.iter().map(|&(name, usage, description)| OperationInfo {
+        name,
+        arg_count: usage.split_whitespace().count(),
+        usage,
+        description,
+    });
+    registered.chain(guest_programs).collect()
+}
+
+// Print every operation with its arguments, as a table or (with --json) as `{"operations": [...]}`
+fn list_operations(json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let catalog = operation_catalog(Registry::builtin());
+    if json {
+        println!("{}", serde_json::to_string_pretty(&serde_json::json!({ "operations": catalog }))?);
+        return Ok(());
+    }
+    
+    println!("{:<28} {:>4}  {:<48} DESCRIPTION", "OPERATION", "ARGS", "USAGE");
+    for op in &catalog {
+        println!("{:<28} {:>4}  {:<48} {}", op.name, op.arg_count, op.usage, op.description);
+    }
+    eprintln!("📋 {} operation(s)", catalog.len());
+    Ok(())
+}
+
+// Print the image ID of a compiled guest (ELF or `cargo risczero build` output) without proving it
+fn print_image_id(path: &str, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let elf_data = fs::read(path).map_err(|e| format!("Failed to read guest binary {}: {}", path, e))?;
+    let image_id = hex::encode(compute_image_id(&elf_data)?.as_bytes());
+    if json {
+        println!("{}", serde_json::to_string_pretty(&serde_json::json!({
+            "guest_binary_path": path,
+            "image_id": image_id,
+            "elf_size_bytes": elf_data.len(),
+        }))?);
     } else {
-        // Use as-is if already 64 bytes
-        let mut key_array = [0u8; 64];
-        key_array[..secret_key_bytes.len()].copy_from_slice(secret_key_bytes);
-        key_array
+        println!("{}", image_id);
+    }
+    Ok(())
+}
+
+// Parse a 32-byte state hash given as 64 hex characters (as printed in a state transition journal)
+fn parse_state_hash(hash: &str) -> Result<Digest, Box<dyn std::error::Error>> {
+    let bytes = hex::decode(hash.trim()).map_err(|e| format!("'{}' is not valid hex: {}", hash, e))?;
+    Digest::try_from(bytes.as_slice()).map_err(|_| format!("'{}' must be 32 bytes, got {}", hash, bytes.len()).into())
+}
+
+// Version of the JSON document printed on stdout. Keys are emitted in `HostOutput` field order and
+// every key is always present (null when it does not apply); adding, removing, renaming or retyping
+// a key means bumping this and updating the schema in the README.
+const OUTPUT_SCHEMA_VERSION: u32 = 12;
+
+// The host's stdout contract, schema version 12
+#[derive(Serialize)]
+struct HostOutput {
+    schema_version: u32,
+    timestamp: u64,
+    inputs: serde_json::Value,
+    // Decoded headline value in its natural JSON type; null when the guest committed nothing
+    result: Option<serde_json::Value>,
+    // "decimal", "integer" or "boolean"; null when the operation has no typed headline value
+    result_type: Option<&'static str>,
+    result_note: Option<&'static str>,
+    // Only for dynamic and precompiled: the committed result as JSON (a number for a bare i64)
+    result_value: Option<serde_json::Value>,
+    // Only for operations whose guest flags overflow (add, multiply, divide, power, factorial, batch)
+    overflow: Option<bool>,
+    // Only for batch: one result per input pair, in input order; null where that pair overflowed
+    results: Option<Vec<Option<f64>>>,
+    // Only for modinverse: false when gcd(a, n) != 1 and there is no inverse
+    invertible: Option<bool>,
+    // Only for gcd: a, b, gcd and the Bézout coefficients x and y
+    bezout: Option<GcdJournal>,
+    // Only for range: whether no value satisfies the bounds, and the guest's one-sided checks against
+    // min and max (strict for a bound proven exclusive)
+    invalid_range: Option<bool>,
+    above_min: Option<bool>,
+    below_max: Option<bool>,
+    // Only with --compare-host-compute on a supported operation
+    host_compute_match: Option<bool>,
+    // Only with --stdio
+    guest_stdout: Option<String>,
+    receipt_journal: Vec<u8>,
+    journal_codec: String,
+    image_id: String,
+    verification_status: &'static str,
+    exit_code: Option<u32>,
+    halt_reason: &'static str,
+    peak_memory_mb: Option<u64>,
+    // From the prover's session stats: all cycles including paging and padding, the guest's own
+    // cycles, and how many segments the execution was split into
+    total_cycles: u64,
+    user_cycles: u64,
+    segments: usize,
+    proof_generation_time_ms: u128,
+    verification_time_ms: u128,
+    total_time_ms: u128,
+    proof_seal_hex: String,
+    proof_size_bytes: usize,
+    // "composite", "succinct", "groth16", or "fake" in dev mode
+    proof_system: &'static str,
+    // Only with --snark: the Groth16 seal and the SHA-256 journal digest an on-chain verifier checks
+    snark_seal_hex: Option<String>,
+    journal_digest: Option<String>,
+    // Empty when the proof was not saved (--fail-on-unverified)
+    proof_file_path: String,
+    // Only with --journal-out
+    journal_file_path: Option<String>,
+    // Only with --seal-only: the succinct seal written beside the full receipt, and its size
+    seal_file_path: Option<String>,
+    seal_size_bytes: Option<usize>,
+    dev_mode: bool,
+    // "local", or "bonsai" when BONSAI_API_URL and BONSAI_API_KEY are set
+    prover: &'static str,
+    // Only for authenticated_add
+    public_key: Option<String>,
+    signature: Option<String>,
+    task_id: Option<String>,
+    auth_timestamp: Option<u64>,
+}
+
+// Environment variable opting into v2 host behavior ahead of it becoming the default
+const V2_ENV: &str = "RISC0_HOST_V2";
+
+// v2 (`--fail-on-unverified` or RISC0_HOST_V2=1) refuses to save an unverified proof and exits nonzero;
+// legacy behavior reports `verification_status: failed`, saves the proof and exits 0
+fn fail_on_unverified_enabled(flag: bool, v2_env: Option<&str>) -> bool {
+    flag || matches!(v2_env, Some("1") | Some("true"))
+}
+
+// `bundle <output> <proof>...`: write the bundle and print its manifest
+fn write_bundle(output: &str, paths: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    if paths.is_empty() {
+        return Err("bundle needs at least one proof file".into());
+    }
+    let bundle = proofs::build_bundle(paths)?;
+    let bytes = bundle.encode()?;
+    fs::write(output, &bytes).map_err(|e| format!("Failed to write {}: {}", output, e))?;
+    
+    for entry in &bundle.manifest {
+        println!("{:<48} {:<24} {}", entry.filename, entry.operation, entry.image_id);
+    }
+    eprintln!("📦 Bundled {} proof(s) into {} ({} bytes)", bundle.manifest.len(), output, bytes.len());
+    Ok(())
+}
+
+// SplitMix64: tiny and dependency-free, so a seed reproduces the same inputs on every platform and release
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+    
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+    
+    // Signed decimal with two places in (-max, max), exact in the guests' fixed-point scale
+    fn decimal(&mut self, max: u64) -> String {
+        let hundredths = self.below(max * 100) as i64;
+        let value = if self.next_u64() & 1 == 1 { -hundredths } else { hundredths };
+        format!("{}", value as f64 / 100.0)
+    }
+}
+
+// Operations `--random-inputs` can generate arguments for
+const RANDOM_INPUT_OPERATIONS: &[&str] = &["add", "subtract", "multiply", "divide", "sqrt", "modexp", "range"];
+
+// Command-line arguments for one generated run of `operation`
+fn random_args(rng: &mut SplitMix64, operation: &str) -> Option<Vec<String>> {
+    let args = match operation {
+        "add" | "subtract" => vec![rng.decimal(1_000_000), rng.decimal(1_000_000)],
+        "multiply" => vec![rng.decimal(10_000), rng.decimal(10_000)],
+        "divide" => vec![rng.decimal(1_000_000), rng.decimal(1_000)],
+        "sqrt" => vec![rng.decimal(10_000).trim_start_matches('-').to_string()],
+        // Full-width operands: the modulus often exceeds 32 bits, where truncation bugs hide
+        "modexp" => vec![rng.next_u64().to_string(), rng.below(1 << 32).to_string(), (rng.next_u64() | 2).to_string()],
+        "range" => {
+            let (a, b) = (rng.below(1 << 48), rng.below(1 << 48));
+            vec![rng.below(1 << 48).to_string(), a.min(b).to_string(), a.max(b).to_string()]
+        },
+        _ => return None,
     };
+    Some(args)
+}
+
+// `--random-inputs <seed> <count> <operation>`: prove and verify `count` generated inputs by re-running
+// this binary for each, so every run goes through the full pipeline; exits nonzero if any run fails
+fn run_random_inputs(operation: &str, seed: u64, count: u64) -> Result<(), Box<dyn std::error::Error>> {
+    if !RANDOM_INPUT_OPERATIONS.contains(&operation) {
+        return Err(format!("--random-inputs supports {}, not '{}'", RANDOM_INPUT_OPERATIONS.join(", "), operation).into());
+    }
+    let host = std::env::current_exe()?;
+    let compare_host = operation != "range";
+    let mut rng = SplitMix64(seed);
+    let mut runs = Vec::new();
+    let mut failed = 0;
+    
+    for index in 0..count {
+        let op_args = random_args(&mut rng, operation).ok_or("no generator for operation")?;
+        eprintln!("🎲 [{}/{}] {} {}", index + 1, count, operation, op_args.join(" "));
+        
+        let mut command = Command::new(&host);
+        if compare_host {
+            command.arg("--compare-host-compute");
+        }
+        let output = command.arg(operation).args(&op_args).output()?;
+        let parsed: Option<serde_json::Value> = serde_json::from_slice(&output.stdout).ok();
+        
+        let error = match &parsed {
+            _ if !output.status.success() => Some(format!("host exited with {}: {}", output.status,
+                String::from_utf8_lossy(&output.stderr).lines().last().unwrap_or_default())),
+            None => Some("host did not print valid JSON".to_string()),
+            Some(result) if result["verification_status"] != "verified" => Some("receipt failed verification".to_string()),
+            Some(result) if result["host_compute_match"] == false => Some("guest result diverged from host computation".to_string()),
+            Some(_) => None,
+        };
+        // The generated proofs are only needed to check the run, not kept
+        if let Some(path) = parsed.as_ref().and_then(|result| result["proof_file_path"].as_str()) {
+            let _ = fs::remove_file(path);
+        }
+        
+        match &error {
+            Some(e) => {
+                failed += 1;
+                eprintln!("❌ {}", e);
+            },
+            None => eprintln!("✅ verified"),
+        }
+        runs.push(serde_json::json!({
+            "inputs": op_args,
+            "status": if error.is_some() { "failed" } else { "verified" },
+            "error": error,
+        }));
+    }
     
-    fs::write("keys/default.key", hex::encode(&key_to_store))?;
-    fs::write("keys/public/default.pub", hex::encode(public_key_bytes))?;
+    println!("{}", serde_json::to_string_pretty(&serde_json::json!({
+        "operation": operation,
+        "seed": seed,
+        "count": count,
+        "passed": count - failed,
+        "failed": failed,
+        "runs": runs,
+    }))?);
     
-    println!("Key files updated successfully!");
-    println!("Stored private key format: {} bytes", key_to_store.len());
+    if failed > 0 {
+        eprintln!("Error: {} of {} random {} run(s) failed (reproduce with --random-inputs {} {} {})", failed, count, operation, seed, count, operation);
+        std::process::exit(1);
+    }
     Ok(())
 }
 
@@ -155,617 +413,401 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .init();
 
     // Read command line arguments
-    let args: Vec<String> = std::env::args().collect();
+    let mut args: Vec<String> = std::env::args().collect();
     
-    // Special case for key regeneration
-    if args.len() >= 2 && args[1] == "regenerate_key" {
-        return regenerate_public_key();
-    }
+    // Prior proofs passed via `--assumption <proof-file>` (repeatable) are added to the
+    // executor environment so an outer guest can resolve them with `env::verify`
+    let assumption_paths = take_flag_values(&mut args, "--assumption")?;
     
-    // New format: program <operation> <...computation_args>
-    if args.len() < 2 {
-        eprintln!("Usage: {} <operation> <...args>", args[0]);
+    // `--compare-host-compute` audits the guest's committed value against the host's own computation
+    let compare_host = take_flag(&mut args, "--compare-host-compute");
+    
+    // `--stdio` feeds dynamic/precompiled inputs as raw bytes on the guest's stdin and captures its stdout
+    let stdio_mode = take_flag(&mut args, "--stdio");
+    
+    // `--no-guest-cache` always recompiles a dynamic guest instead of reusing a cached build
+    let no_guest_cache = take_flag(&mut args, "--no-guest-cache");
+    
+    // `--build-timeout <secs>` kills a dynamic guest build that runs longer
+    let build_timeout = match take_flag_values(&mut args, "--build-timeout")?.pop() {
+        Some(value) => match value.parse::<u64>() {
+            Ok(secs) if secs > 0 => std::time::Duration::from_secs(secs),
+            _ => {
+                eprintln!("Error: --build-timeout must be a positive number of seconds, got '{}'", value);
+                std::process::exit(1);
+            }
+        },
+        None => std::time::Duration::from_secs(dynamic::DEFAULT_BUILD_TIMEOUT_SECS),
+    };
+    
+    // `--envelope minimal` stores only seal + journal + image ID instead of the full composite receipt
+    let envelope = match take_flag_values(&mut args, "--envelope")?.pop() {
+        Some(name) => match Envelope::from_str(&name) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                eprintln!("Error: --{}", e);
+                std::process::exit(1);
+            }
+        },
+        None => Envelope::default(),
+    };
+    
+    // `--seal-only` also writes the bare succinct seal to a `.seal` file; the full receipt is still saved
+    let seal_only = take_flag(&mut args, "--seal-only");
+    
+    // `--snark` compresses the receipt to a Groth16 SNARK that can be verified on-chain
+    let snark = take_flag(&mut args, "--snark");
+    if snark && seal_only {
+        eprintln!("Error: --snark and --seal-only cannot be combined; a Groth16 receipt has no succinct seal");
         std::process::exit(1);
     }
     
-    let operation = &args[1];
-    
-    match operation.as_str() {
-        "sqrt" => {
-            if args.len() != 3 {
-                eprintln!("Usage: {} sqrt <n>", args[0]);
+    // `--journal-codec risc0-serde|raw-le` says how a dynamic/precompiled guest encoded its journal
+    let journal_codec_flag = match take_flag_values(&mut args, "--journal-codec")?.pop() {
+        Some(name) => match name.parse::<JournalCodec>() {
+            Ok(codec) => Some(codec),
+            Err(e) => {
+                eprintln!("Error: --{}", e);
                 std::process::exit(1);
             }
-        }
-        "modexp" => {
-            if args.len() != 5 {
-                eprintln!("Usage: {} modexp <base> <exponent> <modulus>", args[0]);
+        },
+        None => None,
+    };
+    
+    // `--prev-state <hash>` chains `state_transition` proofs: the prior proof's new state hash (hex)
+    let prev_state = match take_flag_values(&mut args, "--prev-state")?.pop() {
+        Some(hash) => match parse_state_hash(&hash) {
+            Ok(digest) => Some(digest),
+            Err(e) => {
+                eprintln!("Error: --prev-state {}", e);
                 std::process::exit(1);
             }
-        }
-        "range" => {
-            if args.len() != 5 {
-                eprintln!("Usage: {} range <secret_number> <min> <max>", args[0]);
+        },
+        None => None,
+    };
+    
+    // `--trace` executes the guest without proving and prints each input read and value committed
+    let trace = take_flag(&mut args, "--trace");
+    
+    // `--min-exclusive` / `--max-exclusive` make range check secret > min / secret < max instead of
+    // the inclusive default; the guest commits which semantics it proved
+    let min_exclusive = take_flag(&mut args, "--min-exclusive");
+    let max_exclusive = take_flag(&mut args, "--max-exclusive");
+    
+    // `--max-memory-mb <n>` stops proving once the host and its prover subprocess hold more than n MB
+    let max_memory_mb = match take_flag_values(&mut args, "--max-memory-mb")?.pop() {
+        Some(value) => match value.parse::<u64>() {
+            Ok(mb) if mb > 0 => Some(mb),
+            _ => {
+                eprintln!("Error: --max-memory-mb must be a positive integer, got '{}'", value);
                 std::process::exit(1);
             }
+        },
+        None => None,
+    };
+    
+    // `--random-inputs <seed> <count>` proves and verifies generated inputs for the chosen operation
+    let random_inputs = match args.iter().position(|a| a == "--random-inputs") {
+        Some(i) if i + 2 < args.len() => {
+            let values: Vec<String> = args.drain(i..i + 3).skip(1).collect();
+            match (values[0].parse::<u64>(), values[1].parse::<u64>()) {
+                (Ok(seed), Ok(count)) if count > 0 => Some((seed, count)),
+                _ => {
+                    eprintln!("Error: --random-inputs takes a seed and a positive count, got '{} {}'", values[0], values[1]);
+                    std::process::exit(1);
+                }
+            }
+        },
+        Some(_) => return Err("--random-inputs requires a seed and a count".into()),
+        None => None,
+    };
+    
+    // `--fail-on-unverified` makes a failed verification fatal: nothing is saved and the host exits nonzero
+    let fail_on_unverified = fail_on_unverified_enabled(take_flag(&mut args, "--fail-on-unverified"), std::env::var(V2_ENV).ok().as_deref());
+    
+    // `--output-dir <dir>` saves proof, sidecar and seal files there instead of the CWD, creating it if needed
+    let output_dir = take_flag_values(&mut args, "--output-dir")?.pop().map(std::path::PathBuf::from).unwrap_or_default();
+    if !output_dir.as_os_str().is_empty() {
+        if let Err(e) = fs::create_dir_all(&output_dir) {
+            eprintln!("Error: cannot create --output-dir {}: {}", output_dir.display(), e);
+            std::process::exit(1);
         }
+    }
+    
+    // `--journal-out <path>` also writes the raw journal bytes, for consumers that take the journal without the receipt
+    let journal_out = take_flag_values(&mut args, "--journal-out")?.pop();
+    
+    // `--key-dir <dir>` holds the named signing keys `<key_id>.key` and `public/<key_id>.pub`
+    let key_dir = take_flag_values(&mut args, "--key-dir")?.pop().map(std::path::PathBuf::from)
+        .unwrap_or_else(|| std::path::PathBuf::from(keys::KEY_DIR));
+    
+    // `list-operations [--json]` prints every operation and its arguments without proving anything
+    if args.len() >= 2 && args[1] == "list-operations" {
+        return list_operations(take_flag(&mut args, "--json"));
+    }
+    
+    // `image-id <guest_binary_path> [--json]` prints the image ID a precompiled proof of that guest carries
+    if args.len() >= 2 && args[1] == "image-id" {
+        let json = take_flag(&mut args, "--json");
+        if args.len() != 3 {
+            eprintln!("Usage: {} image-id <guest_binary_path> [--json]", args[0]);
+            std::process::exit(1);
+        }
+        return print_image_id(&args[2], json);
+    }
+    
+    // Special case for key regeneration: `regenerate_key [key_id]`, "default" unless named
+    if args.len() >= 2 && args[1] == "regenerate_key" {
+        return regenerate_public_key(&key_dir, args.get(2).map_or("default", String::as_str));
+    }
+    
+    // `bundle <output> <proof>...` packs saved proofs into one file for `verify --bundle`
+    if args.len() >= 2 && args[1] == "bundle" {
+        if args.len() < 4 {
+            eprintln!("Usage: {} bundle <output> <proof-file>...", args[0]);
+            std::process::exit(1);
+        }
+        return write_bundle(&args[2], &args[3..]);
+    }
+    
+    // `--list-proofs <dir>` prints an inventory of saved proofs without proving or verifying anything
+    if let Some(dir) = take_flag_values(&mut args, "--list-proofs")?.pop() {
+        return list_proofs(&dir);
+    }
+    
+    if let Some((seed, count)) = random_inputs {
+        if args.len() != 2 {
+            eprintln!("Usage: {} --random-inputs <seed> <count> <{}>", args[0], RANDOM_INPUT_OPERATIONS.join("|"));
+            std::process::exit(1);
+        }
+        return run_random_inputs(&args[1], seed, count);
+    }
+    
+    // New format: program <operation> <...computation_args>
+    if args.len() < 2 {
+        eprintln!("Usage: {} [--assumption <proof-file>]... [--compare-host-compute] [--envelope full|minimal] [--seal-only] [--snark] [--journal-codec risc0-serde|raw-le] [--prev-state <hash>] [--trace] [--min-exclusive] [--max-exclusive] [--max-memory-mb <n>] [--fail-on-unverified] [--journal-out <path>] [--output-dir <dir>] [--key-dir <dir>] <operation> <...args>", args[0]);
+        eprintln!("       {} list-operations [--json]", args[0]);
+        eprintln!("       {} image-id <guest_binary_path> [--json]", args[0]);
+        eprintln!("       {} --list-proofs <dir>", args[0]);
+        eprintln!("       {} [--key-dir <dir>] regenerate_key [key_id]", args[0]);
+        eprintln!("       {} bundle <output> <proof-file>...", args[0]);
+        eprintln!("       {} --random-inputs <seed> <count> <operation>", args[0]);
+        std::process::exit(1);
+    }
+    
+    let operation = &args[1];
+    
+    // Every operation but the dynamic and precompiled guest programs is implemented through the `Operation` trait
+    let registry = Registry::builtin();
+    let registered = registry.get(operation);
+    
+    if (min_exclusive || max_exclusive) && operation != "range" {
+        eprintln!("Error: --min-exclusive and --max-exclusive only apply to range, not {}", operation);
+        std::process::exit(1);
+    }
+    if prev_state.is_some() && operation != "state_transition" {
+        eprintln!("Error: --prev-state only applies to state_transition");
+        std::process::exit(1);
+    }
+    
+    match operation.as_str() {
         "dynamic" => {
             if args.len() != 4 {
-                eprintln!("Usage: {} dynamic <guest_program_path> <inputs_json>", args[0]);
+                eprintln!("Usage: {} [--stdio] [--no-guest-cache] [--build-timeout <secs>] dynamic <guest_program_path> <inputs_json|raw_stdin>", args[0]);
                 std::process::exit(1);
             }
         }
         "precompiled" => {
             if args.len() != 4 {
-                eprintln!("Usage: {} precompiled <guest_binary_path> <inputs_json>", args[0]);
+                eprintln!("Usage: {} [--stdio] precompiled <guest_binary_path> <inputs_json|raw_stdin>", args[0]);
                 std::process::exit(1);
             }
         }
-        "authenticated_add" => {
-            if args.len() != 5 {
-                eprintln!("Usage: {} authenticated_add <a> <b> <key_id>", args[0]);
-                std::process::exit(1);
+        _ => match registered {
+            Some(op) => {
+                if args.len() != 2 + op.arg_count() {
+                    eprintln!("Usage: {} {} {}", args[0], operation, op.usage());
+                    std::process::exit(1);
+                }
             }
-        }
-        "k_means" | "linear_regression" | "neural_network" | "logistic_regression" => {
-            if args.len() != 3 {
-                eprintln!("Usage: {} {} <json_inputs>", args[0], operation);
+            None => {
+                let names: Vec<&str> = operation_catalog(registry).iter().map(|op| op.name).collect();
+                eprintln!("Usage: {} <operation> <...args>", args[0]);
+                eprintln!("Operations: {}", names.join(", "));
+                eprintln!("Run `{} list-operations` for each operation's arguments", args[0]);
                 std::process::exit(1);
             }
         }
-        _ => {
-            if args.len() != 4 {
-                eprintln!("Usage: {} <operation> <a> <b>", args[0]);
-                eprintln!("Operations: add, multiply, sqrt, modexp, range, dynamic, precompiled, authenticated_add, k_means, linear_regression, neural_network, logistic_regression");
-                std::process::exit(1);
-            }
+    }
+    
+    // Built-in guests all commit with `env::commit`; only dynamic guests may use another journal codec
+    if let (Some(op), Some(codec)) = (registered, journal_codec_flag) {
+        if codec != op.journal_codec() {
+            eprintln!("Error: {} journals are always {}; --journal-codec only applies to dynamic and precompiled", operation, op.journal_codec());
+            std::process::exit(1);
         }
     }
     
     let total_start = Instant::now();
     
-    // Handle dynamic elf data separately to manage lifetimes
-    let dynamic_elf_data: Option<Vec<u8>> = if operation == "dynamic" || operation == "precompiled" {
-        let guest_program_path = &args[2];
-        let _inputs_json = &args[3];
-        
-        if operation == "precompiled" {
-            eprintln!("🔧 Loading precompiled guest program: {}", guest_program_path);
-            
-            // Read the precompiled binary directly
-            let elf_data = fs::read(guest_program_path)?;
-            Some(elf_data)
-        } else {
-            eprintln!("🔧 Compiling dynamic guest program: {}", guest_program_path);
-            
-            // Create a temporary directory for the dynamic guest program
-            let temp_dir = std::env::temp_dir().join(format!("risc0_dynamic_{}", SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()));
-            fs::create_dir_all(&temp_dir)?;
-        
-        // Copy the Cargo.toml template for a guest program
-        let cargo_toml_content = r#"[package]
-name = "guest-dynamic"
-version = "0.1.0"
-edition = "2021"
-
-[dependencies]
-risc0-zkvm = { version = "^2.3.1", default-features = false, features = ["std"] }
-serde = { version = "1.0", default-features = false, features = ["derive", "alloc"] }
-serde_json = { version = "1.0", default-features = false, features = ["alloc"] }
-
-[[bin]]
-name = "guest-dynamic"
-path = "src/main.rs"
-"#;
-        
-        let cargo_toml_path = temp_dir.join("Cargo.toml");
-        fs::write(&cargo_toml_path, cargo_toml_content)?;
-        
-        // Create src directory and copy the guest program
-        let src_dir = temp_dir.join("src");
-        fs::create_dir_all(&src_dir)?;
-        
-        let guest_code = fs::read_to_string(guest_program_path)?;
-        let main_rs_path = src_dir.join("main.rs");
-        fs::write(&main_rs_path, guest_code)?;
-        
-        // Build the guest program using RISC Zero toolchain
-        eprintln!("🔨 Building dynamic guest program...");
-        let build_output = Command::new("cargo")
-            .args(["risczero", "build"])
-            .current_dir(&temp_dir)
-            .output()?;
-        
-        if !build_output.status.success() {
-            eprintln!("❌ Failed to build dynamic guest program:");
-            eprintln!("stdout: {}", String::from_utf8_lossy(&build_output.stdout));
-            eprintln!("stderr: {}", String::from_utf8_lossy(&build_output.stderr));
-            return Err("Dynamic guest program compilation failed".into());
-        }
-        
-        // Find the built ELF file (cargo risczero build places it in docker subdirectory)
-        let elf_path = temp_dir.join("target/riscv32im-risc0-zkvm-elf/docker/guest-dynamic.bin");
-        if !elf_path.exists() {
-            eprintln!("❌ ELF file not found at: {}", elf_path.display());
-            eprintln!("🔍 Checking directory contents...");
-            if let Ok(entries) = std::fs::read_dir(temp_dir.join("target")) {
-                for entry in entries {
-                    if let Ok(entry) = entry {
-                        eprintln!("  Found: {}", entry.path().display());
+    let program = match (operation.as_str(), registered) {
+        ("precompiled", _) => {
+            eprintln!("🔧 Loading precompiled guest program: {}", args[2]);
+            Program::Guest { operation: "precompiled", elf: fs::read(&args[2])?, inputs: args[3].clone() }
+        },
+        ("dynamic", _) => match dynamic::dynamic_guest_elf(&args[2], &args[3], !no_guest_cache, build_timeout) {
+            Ok(elf) => Program::Guest { operation: "dynamic", elf, inputs: args[3].clone() },
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        },
+        (_, Some(op)) => {
+            // Registered operations get their CLI arguments, plus what the host supplies itself
+            let mut op_args = args[2..].to_vec();
+            // range also takes the bound flags, as the 0/1 inputs its guest reads after the bounds
+            if operation == "range" {
+                op_args.extend([min_exclusive, max_exclusive].map(|exclusive| (exclusive as u8).to_string()));
+            }
+            // authenticated_add's guest signs with the key named by key_id; a missing key fails before proving
+            if operation == "authenticated_add" {
+                match keys::load_keypair(&key_dir, &args[4]) {
+                    Ok(keypair) => op_args.push(hex::encode(*keypair.sk.seed())),
+                    Err(e) => {
+                        eprintln!("Error: signing key '{}' is not available: {}", args[4], e);
+                        eprintln!("Create it with: {} --key-dir {} regenerate_key {}", args[0], key_dir.display(), args[4]);
+                        std::process::exit(1);
                     }
                 }
             }
-            return Err("Built ELF file not found".into());
-        }
-        
-        let elf_data = fs::read(&elf_path)?;
-        eprintln!("✅ Dynamic guest program compiled successfully ({} bytes)", elf_data.len());
-        
-        // Clean up temporary directory
-        let _ = fs::remove_dir_all(&temp_dir);
-        
-        Some(elf_data)
-        }
+            Program::Registered(op, op_args)
+        },
+        (_, None) => return Err("Unknown operation".into()),
+    };
+    
+    let mut request = ProveRequest::new(program);
+    request.stdio = stdio_mode;
+    request.prev_state = prev_state;
+    request.max_memory_mb = max_memory_mb;
+    if let Some(codec) = journal_codec_flag {
+        request.journal_codec = codec;
+    }
+    request.receipt_kind = if snark {
+        ReceiptKind::Groth16
+    } else if envelope == Envelope::Minimal || seal_only {
+        // Minimal envelopes and bare seals need a single succinct seal rather than per-segment composite seals
+        ReceiptKind::Succinct
     } else {
-        None
+        ReceiptKind::Composite
     };
+    for path in &assumption_paths {
+        let assumption = proofs::load_assumption(path)?;
+        eprintln!("🔗 Loaded assumption receipt: {} ({} journal bytes)", path, assumption.journal.bytes.len());
+        request.assumptions.push(assumption);
+    }
+    eprintln!("🚀 Starting RISC Zero zkVM computation: {}", request.program.describe());
     
-    let (elf_data, image_id, op_symbol, inputs_desc, _expected_result_fixed, _operation_type) = match operation.as_str() {
-        "add" => {
-            let a_decimal: f64 = args[2].parse().expect("Second argument must be a number");
-            let b_decimal: f64 = args[3].parse().expect("Third argument must be a number");
-            let a_fixed = decimal_to_fixed_point(a_decimal);
-            let b_fixed = decimal_to_fixed_point(b_decimal);
-            let expected_fixed = a_fixed + b_fixed;
-            (ADDITION_ELF, ADDITION_ID, "+", format!("{} + {}", a_decimal, b_decimal), expected_fixed, "decimal")
-        },
-        "multiply" => {
-            let a_decimal: f64 = args[2].parse().expect("Second argument must be a number");
-            let b_decimal: f64 = args[3].parse().expect("Third argument must be a number");
-            let a_fixed = decimal_to_fixed_point(a_decimal);
-            let b_fixed = decimal_to_fixed_point(b_decimal);
-            let expected_fixed = (a_fixed * b_fixed) / SCALE;
-            (MULTIPLY_GUEST_ELF, MULTIPLY_GUEST_ID, "*", format!("{} * {}", a_decimal, b_decimal), expected_fixed, "decimal")
-        },
-        "sqrt" => {
-            let n_decimal: f64 = args[2].parse().expect("Second argument must be a positive number");
-            let _n_fixed = decimal_to_fixed_point(n_decimal);
-            let expected_fixed = if n_decimal >= 0.0 {
-                decimal_to_fixed_point(n_decimal.sqrt())
-            } else {
-                0
-            };
-            (SQRT_GUEST_ELF, SQRT_GUEST_ID, "sqrt", format!("sqrt({})", n_decimal), expected_fixed, "decimal")
-        },
-        "modexp" => {
-            let base: u64 = args[2].parse().expect("Second argument must be a positive integer");
-            let exponent: u64 = args[3].parse().expect("Third argument must be a positive integer");
-            let modulus: u64 = args[4].parse().expect("Fourth argument must be a positive integer");
-            let expected_result = if modulus == 0 {
-                0
-            } else if modulus == 1 {
-                0
-            } else {
-                // Use modular exponentiation to avoid overflow
-                modular_exponentiation_host(base, exponent, modulus)
-            };
-            (MODEXP_GUEST_ELF, MODEXP_GUEST_ID, "^", format!("{}^{} mod {}", base, exponent, modulus), expected_result as i64, "integer")
-        },
-        "range" => {
-            let secret_number: u64 = args[2].parse().expect("Secret number must be a positive integer");
-            let min_value: u64 = args[3].parse().expect("Min value must be a positive integer");
-            let max_value: u64 = args[4].parse().expect("Fourth argument must be a positive integer");
-            let expected_result = if secret_number >= min_value && secret_number <= max_value { 1 } else { 0 };
-            (GUEST_RANGE_ELF, GUEST_RANGE_ID, "∈", format!("secret ∈ [{}, {}]", min_value, max_value), expected_result as i64, "range")
-        },
-        "authenticated_add" => {
-            let a: i64 = args[2].parse().expect("Second argument must be a number");
-            let b: i64 = args[3].parse().expect("Third argument must be a number");
-            let key_id = &args[4];
-            
-            // Generate a unique task ID based on timestamp and inputs
-            let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
-            let task_id = format!("auth_{}_{}_{}_{}", timestamp, a, b, key_id);
-            
-            let expected_result = a + b;
-            (GUEST_AUTHENTICATED_ADD_ELF, GUEST_AUTHENTICATED_ADD_ID, "auth+", 
-             format!("authenticated {} + {} (key: {}, task: {})", a, b, key_id, task_id), expected_result, "authenticated")
-        },
-        "k_means" => {
-            let inputs_json = &args[2];
-            (GUEST_K_MEANS_ELF, GUEST_K_MEANS_ID, "k-means", 
-             format!("K-means clustering with inputs: {}", inputs_json), 0i64, "ml")
-        },
-        "linear_regression" => {
-            let inputs_json = &args[2];
-            (GUEST_LINEAR_REGRESSION_ELF, GUEST_LINEAR_REGRESSION_ID, "linear_reg", 
-             format!("Linear regression with inputs: {}", inputs_json), 0i64, "ml")
-        },
-        "neural_network" => {
-            let inputs_json = &args[2];
-            (GUEST_NEURAL_NETWORK_ELF, GUEST_NEURAL_NETWORK_ID, "neural_net", 
-             format!("Neural network with inputs: {}", inputs_json), 0i64, "ml")
-        },
-        "logistic_regression" => {
-            let inputs_json = &args[2];
-            (GUEST_LOGISTIC_REGRESSION_ELF, GUEST_LOGISTIC_REGRESSION_ID, "logistic_reg", 
-             format!("Logistic regression with inputs: {}", inputs_json), 0i64, "ml")
-        },
-        "dynamic" | "precompiled" => {
-            let inputs_json = &args[3];
-            let elf_data = dynamic_elf_data.as_ref().expect("Dynamic/Precompiled ELF data should be loaded");
-            
-            // We'll compute the real image ID after proof generation from the receipt
-            // For now, use a placeholder that will be replaced later
-            let placeholder_image_id = [0u32; 8];
-            
-            let op_name = if operation == "dynamic" { "dynamic" } else { "precompiled" };
-            (elf_data.as_slice(), placeholder_image_id, op_name, format!("{} execution with inputs: {}", op_name, inputs_json), 0i64, op_name)
-        },
-        _ => {
-            eprintln!("Error: Unknown operation '{}'. Use 'add', 'multiply', 'sqrt', 'modexp', 'range', 'dynamic', 'precompiled', or 'authenticated_add'.", operation);
-            std::process::exit(1);
+    // RISC0_DEV_MODE=1 executes the guest and produces a fake receipt without the STARK; the request
+    // reads it the same way the prover does, so the reported mode is the one actually used
+    let dev_mode = request.dev_mode;
+    if dev_mode {
+        eprintln!("⚠️  Running in DEV mode (RISC0_DEV_MODE) - the receipt is NOT a real proof");
+    } else {
+        eprintln!("🔐 Running in PRODUCTION mode - generating real ZK-STARK proof");
+        eprintln!("💡 This may take several minutes and use significant CPU/memory");
+    }
+    if let (true, Program::Guest { inputs, .. }) = (stdio_mode, &request.program) {
+        eprintln!("📥 Feeding {} raw bytes on guest stdin", inputs.len());
+    }
+    
+    // `--trace` executes without proving and lists what the guest read and committed
+    if trace {
+        eprintln!("🔎 Executing guest without proving (--trace)...");
+        let trace = match host::trace(&request) {
+            Ok(trace) => trace,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        };
+        for read in &trace.reads {
+            println!("read   {}", read);
         }
-    };
-    eprintln!("🚀 Starting RISC Zero zkVM computation: {}", inputs_desc);
-    
-    let dev_mode = false;
-    eprintln!("🔐 Running in PRODUCTION mode - generating real ZK-STARK proof");
-    eprintln!("💡 This may take several minutes and use significant CPU/memory");
-    
-    // Initialize the executor environment
-    eprintln!("📝 Setting up executor environment...");
-    let env_start = Instant::now();
-    let env = match operation.as_str() {
-        "sqrt" => {
-            let n_decimal: f64 = args[2].parse().expect("Second argument must be a positive number");
-            let n_fixed = decimal_to_fixed_point(n_decimal);
-            ExecutorEnv::builder()
-                .write(&n_fixed)?         // Computation inputs only
-                .build()?
-        },
-        "add" | "multiply" => {
-            let a_decimal: f64 = args[2].parse().expect("Second argument must be a number");
-            let b_decimal: f64 = args[3].parse().expect("Third argument must be a number");
-            let a_fixed = decimal_to_fixed_point(a_decimal);
-            let b_fixed = decimal_to_fixed_point(b_decimal);
-            ExecutorEnv::builder()
-                .write(&a_fixed)?         // Computation inputs only
-                .write(&b_fixed)?
-                .build()?
-        },
-        "modexp" => {
-            let base: u64 = args[2].parse().expect("Second argument must be a positive integer");
-            let exponent: u64 = args[3].parse().expect("Third argument must be a positive integer");
-            let modulus: u64 = args[4].parse().expect("Fourth argument must be a positive integer");
-            ExecutorEnv::builder()
-                .write(&base)?            // Computation inputs only
-                .write(&exponent)?
-                .write(&modulus)?
-                .build()?
-        },
-        "range" => {
-            let secret_number: u64 = args[2].parse().expect("Second argument must be a positive integer");
-            let min_value: u64 = args[3].parse().expect("Third argument must be a positive integer");
-            let max_value: u64 = args[4].parse().expect("Fourth argument must be a positive integer");
-            ExecutorEnv::builder()
-                .write(&secret_number)?   // Computation inputs only
-                .write(&min_value)?
-                .write(&max_value)?
-                .build()?
-        },
-        "authenticated_add" => {
-            let a: i64 = args[2].parse().expect("Second argument must be a number");
-            let b: i64 = args[3].parse().expect("Third argument must be a number");
-            let key_id = &args[4];
-            
-            // Generate a unique task ID based on timestamp and inputs
-            let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
-            let task_id = format!("auth_{}_{}_{}_{}", timestamp, a, b, key_id);
-            
-            ExecutorEnv::builder()
-                .write(&a)?                          // Computation inputs
-                .write(&b)?
-                .write(&timestamp)?                  // Timestamp
-                .write(&task_id.to_string())?        // Task ID
-                .build()?
-        },
-        "k_means" | "linear_regression" | "neural_network" | "logistic_regression" => {
-            let inputs_json = &args[2];
-            
-            // Parse inputs JSON to validate it's valid JSON
-            let _inputs: serde_json::Value = serde_json::from_str(inputs_json)
-                .map_err(|e| format!("Invalid JSON inputs: {}", e))?;
-            
-            ExecutorEnv::builder()
-                .write(&inputs_json)?     // Write JSON string directly
-                .build()?
-        },
-        "dynamic" | "precompiled" => {
-            let inputs_json = &args[3];
-            
-            // Parse inputs JSON and write to environment
-            let _inputs: serde_json::Value = serde_json::from_str(inputs_json)
-                .map_err(|e| format!("Invalid JSON inputs: {}", e))?;
-            
-            ExecutorEnv::builder()
-                .write(&inputs_json)?     // Write JSON string directly
-                .build()?
-        },
-        _ => {
-            eprintln!("Error: Unknown operation");
-            std::process::exit(1);
+        for commit in &trace.commits {
+            println!("commit {}", commit);
         }
-    };
-    eprintln!("✅ Executor environment ready ({:.2?})", env_start.elapsed());
-
-    // Generate the receipt by running the prover
-    eprintln!("🏃 Starting zkVM execution and proof generation...");
-    let prove_start = Instant::now();
-    let prover = default_prover();
-    
-    eprintln!("🔄 Executing guest program in zkVM...");
+        println!("exit   {:?}", trace.exit_code);
+        return Ok(());
+    }
     
-    let prove_info = prover.prove(env, elf_data)?;
-    let receipt = prove_info.receipt;
-    let prove_duration = prove_start.elapsed();
+    eprintln!("🏃 Starting zkVM execution and proof generation...");
+    eprintln!("🛰️  Prover: {}", ProverKind::from_env(request.dev_mode).name());
+    let proof = match host::prove(&request) {
+        Ok(proof) => proof,
+        Err(e @ (ProveError::InvalidInputs(_) | ProveError::UnknownOperation(_) | ProveError::MemoryLimit(_))) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        },
+        Err(e) => return Err(e.into()),
+    };
+    let receipt = &proof.receipt;
+    let decoded = &proof.result.decoded;
+    if stdio_mode {
+        eprintln!("📤 Guest wrote {} bytes to stdout", proof.guest_stdout.len());
+    }
     
-    eprintln!("🎉 ZK-STARK proof generation completed! ({:.2?})", prove_duration);
+    let halted_normally = proof.halt_reason == "halted" && proof.exit_code == Some(0);
+    if !halted_normally {
+        eprintln!("⚠️  Guest halted abnormally: {} (exit code {:?})", proof.halt_reason, proof.exit_code);
+    }
+    if dev_mode {
+        eprintln!("🎉 Dev mode execution completed, fake receipt generated ({:.2?})", proof.prove_time);
+    } else {
+        eprintln!("🎉 ZK-STARK proof generation completed! ({:.2?})", proof.prove_time);
+    }
+    if let Some(mb) = proof.peak_memory_mb {
+        eprintln!("🧠 Peak memory: {} MB", mb);
+    }
+    eprintln!("🔁 Cycles: {} total, {} user, {} segment(s)", proof.total_cycles, proof.user_cycles, proof.segments);
     if let Ok(succinct) = receipt.inner.succinct() {
         eprintln!("📊 Proof size: {} bytes", succinct.seal.len());
     }
     
-    // Extract the result from the receipt's journal
     eprintln!("📖 Extracting result from receipt journal...");
-    let mut auth_details: Option<(String, String, String, u64)> = None; // (public_key, signature, task_id, timestamp)
-    let (decimal_result, _result_for_json) = match operation.as_str() {
-        "sqrt" => {
-            // For sqrt, manually decode the bytes for fixed-point values (i64)
-            let bytes = &receipt.journal.bytes;
-            if bytes.len() < 16 { // sqrt data (16 bytes)
-                return Err("Journal too short for sqrt operation".into());
-            }
-            
-            // No session context to skip
-            let computation_bytes = bytes;
-            
-            // First 8 bytes: input (little-endian i64 fixed-point)
-            let input_fixed = i64::from_le_bytes([
-                computation_bytes[0], computation_bytes[1], computation_bytes[2], computation_bytes[3], 
-                computation_bytes[4], computation_bytes[5], computation_bytes[6], computation_bytes[7]
-            ]);
-            // Next 8 bytes: sqrt result (little-endian i64 fixed-point)
-            let sqrt_result_fixed = i64::from_le_bytes([
-                computation_bytes[8], computation_bytes[9], computation_bytes[10], computation_bytes[11], 
-                computation_bytes[12], computation_bytes[13], computation_bytes[14], computation_bytes[15]
-            ]);
-            
-            let input_decimal = fixed_point_to_decimal(input_fixed);
-            let sqrt_result_decimal = fixed_point_to_decimal(sqrt_result_fixed);
-            
-            eprintln!("🔢 Computation result: sqrt({}) = {}", input_decimal, sqrt_result_decimal);
-            (sqrt_result_decimal, sqrt_result_fixed)
-        },
-        "add" | "multiply" => {
-            // For decimal operations, manually decode the journal bytes to avoid stateful decoder issues
-            let bytes = &receipt.journal.bytes;
-            if bytes.len() < 24 { // computation data (24 bytes)
-                return Err("Journal too short for decimal operation".into());
-            }
-            
-            // No session context to skip
-            let computation_bytes = bytes;
-            
-            // Decode three i64 values (little-endian): a, b, result
-            let a_fixed = i64::from_le_bytes([
-                computation_bytes[0], computation_bytes[1], computation_bytes[2], computation_bytes[3], 
-                computation_bytes[4], computation_bytes[5], computation_bytes[6], computation_bytes[7]
-            ]);
-            let b_fixed = i64::from_le_bytes([
-                computation_bytes[8], computation_bytes[9], computation_bytes[10], computation_bytes[11], 
-                computation_bytes[12], computation_bytes[13], computation_bytes[14], computation_bytes[15]
-            ]);
-            let result_fixed = i64::from_le_bytes([
-                computation_bytes[16], computation_bytes[17], computation_bytes[18], computation_bytes[19], 
-                computation_bytes[20], computation_bytes[21], computation_bytes[22], computation_bytes[23]
-            ]);
-            
-            let a_decimal = fixed_point_to_decimal(a_fixed);
-            let b_decimal = fixed_point_to_decimal(b_fixed);
-            let result_decimal = fixed_point_to_decimal(result_fixed);
-            
-            eprintln!("🔢 Computation result: {} {} {} = {}", a_decimal, op_symbol, b_decimal, result_decimal);
-            (result_decimal, result_fixed)
-        },
-        "modexp" => {
-            // For modexp, manually decode the bytes for u64 values
-            let bytes = &receipt.journal.bytes;
-            if bytes.len() < 32 { // modexp data (32 bytes)
-                return Err("Journal too short for modexp operation".into());
-            }
-            
-            // No session context to skip
-            let computation_bytes = bytes;
-            
-            // Decode four u64 values (little-endian): base, exponent, modulus, result
-            let base = u64::from_le_bytes([
-                computation_bytes[0], computation_bytes[1], computation_bytes[2], computation_bytes[3], 
-                computation_bytes[4], computation_bytes[5], computation_bytes[6], computation_bytes[7]
-            ]);
-            let exponent = u64::from_le_bytes([
-                computation_bytes[8], computation_bytes[9], computation_bytes[10], computation_bytes[11], 
-                computation_bytes[12], computation_bytes[13], computation_bytes[14], computation_bytes[15]
-            ]);
-            let modulus = u64::from_le_bytes([
-                computation_bytes[16], computation_bytes[17], computation_bytes[18], computation_bytes[19], 
-                computation_bytes[20], computation_bytes[21], computation_bytes[22], computation_bytes[23]
-            ]);
-            let result = u64::from_le_bytes([
-                computation_bytes[24], computation_bytes[25], computation_bytes[26], computation_bytes[27], 
-                computation_bytes[28], computation_bytes[29], computation_bytes[30], computation_bytes[31]
-            ]);
-            
-            eprintln!("🔢 Computation result: {}^{} mod {} = {}", base, exponent, modulus, result);
-            (result as f64, result as i64)
-        },
-        "range" => {
-            // For range proof, manually decode the bytes for boolean and u64 values
-            let bytes = &receipt.journal.bytes;
-            if bytes.len() < 28 { // range data (28 bytes)
-                return Err("Journal too short for range operation".into());
-            }
-            
-            // No session context to skip
-            let computation_bytes = bytes;
-            
-            // First 4 bytes: in_range boolean (stored as u32)
-            let in_range = u32::from_le_bytes([computation_bytes[0], computation_bytes[1], computation_bytes[2], computation_bytes[3]]) != 0;
-            // Next 4 bytes: above_min boolean (stored as u32)
-            let above_min = u32::from_le_bytes([computation_bytes[4], computation_bytes[5], computation_bytes[6], computation_bytes[7]]) != 0;
-            // Next 4 bytes: below_max boolean (stored as u32)
-            let below_max = u32::from_le_bytes([computation_bytes[8], computation_bytes[9], computation_bytes[10], computation_bytes[11]]) != 0;
-            // Next 8 bytes: min_value (little-endian u64)
-            let min_value = u64::from_le_bytes([
-                computation_bytes[12], computation_bytes[13], computation_bytes[14], computation_bytes[15], 
-                computation_bytes[16], computation_bytes[17], computation_bytes[18], computation_bytes[19]
-            ]);
-            // Next 8 bytes: max_value (little-endian u64)
-            let max_value = u64::from_le_bytes([
-                computation_bytes[20], computation_bytes[21], computation_bytes[22], computation_bytes[23], 
-                computation_bytes[24], computation_bytes[25], computation_bytes[26], computation_bytes[27]
-            ]);
-            
-            eprintln!("🔢 Range proof result: secret ∈ [{}, {}] = {}", min_value, max_value, in_range);
-            eprintln!("🔍 Details: above_min={}, below_max={}", above_min, below_max);
-            (if in_range { 1.0 } else { 0.0 }, if in_range { 1 } else { 0 })
-        },
-        "authenticated_add" => {
-            // For authenticated operations, extract the ComputationResult
-            let bytes = &receipt.journal.bytes;
-            
-            eprintln!("🔍 Debug: Journal has {} bytes", bytes.len());
-            eprintln!("🔍 Debug: First 32 bytes: {:?}", &bytes[..32.min(bytes.len())]);
-            
-            // Deserialize the ComputationResult directly from the journal
-            use serde::{Deserialize, Serialize};
-            
-            #[derive(Serialize, Deserialize)]
-            struct ComputationResult {
-                a: i64,
-                b: i64,
-                result: i64,
-                timestamp: u64,
-                task_id: String,
-            }
-            
-            // Try using RISC Zero's journal reader instead of bincode
-            let computation_result: ComputationResult = receipt.journal.decode()
-                .map_err(|e| format!("Failed to decode computation result from journal: {} (journal size: {})", e, bytes.len()))?;
-            
-            eprintln!("🔢 Authenticated computation result: {} + {} = {}", 
-                     computation_result.a, computation_result.b, computation_result.result);
-            eprintln!("🏷️  Task ID: {}", computation_result.task_id);
-            eprintln!("⏰ Timestamp: {}", computation_result.timestamp);
-            
-            // Store task details for JSON output (no auth details since they're handled on server side)
-            eprintln!("🔄 Storing task details for JSON output...");
-            auth_details = Some((
-                "server-side".to_string(),  // Public key handled on server
-                "server-side".to_string(),  // Signature handled on server
-                computation_result.task_id.clone(),
-                computation_result.timestamp
-            ));
-            
-            (computation_result.result as f64, computation_result.result)
-        },
-        "k_means" | "linear_regression" | "neural_network" | "logistic_regression" => {
-            // For ML operations, extract the result from the journal
-            let bytes = &receipt.journal.bytes;
-            if bytes.is_empty() {
-                return Err("Journal is empty for ML operation".into());
-            }
-            
-            // For now, assume ML operations commit a JSON result string
-            // Try to decode as string first, or fall back to simple numeric result
-            let result = if bytes.len() >= 8 {
-                // Try to extract a simple numeric result (f64)
-                if bytes.len() >= 8 {
-                    let result_bytes = &bytes[0..8];
-                    let result_f64 = f64::from_le_bytes([
-                        result_bytes[0], result_bytes[1], result_bytes[2], result_bytes[3],
-                        result_bytes[4], result_bytes[5], result_bytes[6], result_bytes[7]
-                    ]);
-                    eprintln!("🔢 ML computation result: {}", result_f64);
-                    (result_f64, result_f64 as i64)
-                } else {
-                    eprintln!("🔢 ML computation completed (no specific result extracted)");
-                    (0.0, 0)
-                }
-            } else {
-                eprintln!("🔢 ML computation completed (journal too short)");
-                (0.0, 0)
-            };
-            
-            result
-        },
-        "dynamic" | "precompiled" => {
-            // For dynamic/precompiled operations, try to extract the result from the journal
-            let bytes = &receipt.journal.bytes;
-            if bytes.len() < 8 { // At minimum need result data
-                return Err("Journal too short for dynamic operation".into());
+    for line in &decoded.lines {
+        eprintln!("{}", line);
+    }
+    
+    // Cross-check the guest's committed value against the host's independent computation
+    let host_compute_match = match (compare_host && decoded.note.is_none(), &request.program) {
+        (false, _) => None,
+        (true, Program::Registered(op, op_args)) if op.host_expected(op_args).is_some() => {
+            let comparison = host::compare_host_compute(*op, op_args, decoded.fixed).expect("host_expected is supported");
+            eprintln!("🧮 Host vs guest computation for {}:", operation);
+            eprintln!("   • Host computed:   {}", comparison.format_value(comparison.host_value));
+            eprintln!("   • Guest committed: {}", comparison.format_value(comparison.guest_value));
+            match comparison.difference {
+                _ if comparison.matches => eprintln!("✅ Host and guest computations agree"),
+                Some(difference) => eprintln!("❌ DIVERGENCE: guest result differs from host computation by {}", difference),
+                None => eprintln!("❌ DIVERGENCE: guest result differs from host computation by more than i64 can hold"),
             }
-            
-            // No session context to skip
-            let computation_bytes = bytes;
-            
-            // Try to parse the result - this depends on what the dynamic guest program committed
-            // For now, let's assume it committed a simple i64 result
-            let result = if computation_bytes.len() >= 8 {
-                let result_i64 = i64::from_le_bytes([
-                    computation_bytes[0], computation_bytes[1], computation_bytes[2], computation_bytes[3],
-                    computation_bytes[4], computation_bytes[5], computation_bytes[6], computation_bytes[7]
-                ]);
-                eprintln!("🔢 Dynamic computation result: {}", result_i64);
-                (result_i64 as f64, result_i64)
-            } else {
-                eprintln!("🔢 Dynamic computation completed (no specific result extracted)");
-                (0.0, 0)
-            };
-            
-            result
+            Some(comparison.matches)
         },
-        _ => {
-            return Err("Unknown operation".into());
+        (true, _) => {
+            eprintln!("⚠️  --compare-host-compute is not supported for '{}' (no host-side computation)", operation);
+            None
         }
     };
     
-    // For dynamic/precompiled operations, compute the real image ID from the ELF data
-    let actual_image_id = if operation == "dynamic" || operation == "precompiled" {
-        // Compute the real image ID from the ELF data
-        eprintln!("🔧 Computing real image ID from ELF data for dynamic program");
-        let digest = compute_image_id(elf_data)?;
-        // Convert Digest to [u32; 8] format to match expected type
-        let words = digest.as_words();
-        [words[0], words[1], words[2], words[3], words[4], words[5], words[6], words[7]]
-    } else {
-        image_id
-    };
-    
-    // Verify the receipt
-    eprintln!("🔍 Verifying receipt authenticity...");
-    let verify_start = Instant::now();
-    let verification_result = receipt.verify(actual_image_id);
-    let verify_duration = verify_start.elapsed();
-    let is_verified = verification_result.is_ok();
-    
-    match verification_result {
-        Ok(_) => {
-            eprintln!("✅ Receipt verification PASSED ({:.2?})", verify_duration);
+    let is_verified = proof.verification.is_ok();
+    match &proof.verification {
+        Ok(()) => {
+            eprintln!("✅ Receipt verification PASSED ({:.2?})", proof.verify_time);
             if !dev_mode {
                 eprintln!("🎯 ZK-STARK proof is cryptographically valid!");
             }
         },
-        Err(ref e) => {
+        Err(e) => {
             eprintln!("❌ Receipt verification FAILED: {}", e);
         }
     }
@@ -773,10 +815,12 @@ path = "src/main.rs"
     let total_duration = total_start.elapsed();
     eprintln!("⏱️  Total execution time: {:.2?}", total_duration);
     
-    eprintln!("🏆 Real zero-knowledge proof successfully generated and verified!");
+    if !dev_mode {
+        eprintln!("🏆 Real zero-knowledge proof successfully generated and verified!");
+    }
     eprintln!("📈 Performance stats:");
-    eprintln!("   • Proof generation: {:.2?}", prove_duration);
-    eprintln!("   • Verification: {:.2?}", verify_duration);
+    eprintln!("   • Proof generation: {:.2?}", proof.prove_time);
+    eprintln!("   • Verification: {:.2?}", proof.verify_time);
     eprintln!("   • Total time: {:.2?}", total_duration);
     
     eprintln!("🔄 Outputting JSON result...");
@@ -787,91 +831,244 @@ path = "src/main.rs"
         .unwrap()
         .as_secs();
     
-    // Print results in JSON format for easy parsing
-    let id_bytes: &[u8] = unsafe { 
-        std::slice::from_raw_parts(actual_image_id.as_ptr() as *const u8, mem::size_of_val(&actual_image_id))
-    };
+    let image_id_hex = hex::encode(Digest::from(proof.image_id).as_bytes());
+    let receipt_bytes = proofs::encode_receipt(receipt, proof.image_id, envelope)?;
     
-    // Get proof/seal data
-    // Try to get the full receipt bytes for the proof
-    let receipt_bytes = bincode::serialize(&receipt)?;
-    let receipt_hex = hex::encode(&receipt_bytes);
-    let size = receipt_bytes.len();
+    // Dev mode returns a fake receipt even with --snark, so there is no seal to report
+    let (snark_seal_hex, journal_digest) = if snark {
+        let seal = match receipt.inner.groth16() {
+            Ok(groth16) => {
+                eprintln!("🔏 Groth16 seal: {} bytes", groth16.seal.len());
+                Some(hex::encode(&groth16.seal))
+            },
+            Err(e) => {
+                eprintln!("⚠️  No Groth16 seal (--snark): {}", e);
+                None
+            }
+        };
+        (seal, Some(hex::encode(receipt.journal.digest().as_bytes())))
+    } else {
+        (None, None)
+    };
     
-    // Save proof to binary file
-    let proof_filename = format!("proof_{}_{}.bin", operation, timestamp);
-    match std::fs::write(&proof_filename, &receipt_bytes) {
-        Ok(_) => eprintln!("📁 Full receipt proof saved to: {}", proof_filename),
-        Err(e) => eprintln!("⚠️  Failed to save proof file: {}", e),
-    }
+    // Save proof to binary file, unless it failed verification and --fail-on-unverified forbids keeping it
+    let proof_filename = if !is_verified && fail_on_unverified {
+        eprintln!("🗑️  Unverified proof not saved (--fail-on-unverified)");
+        String::new()
+    } else {
+        let proof_filename = output_file(&output_dir, &format!("proof_{}_{}.bin", operation, timestamp));
+        match std::fs::write(&proof_filename, &receipt_bytes) {
+            Ok(_) => eprintln!("📁 Full receipt proof saved to: {}", proof_filename),
+            Err(e) => eprintln!("⚠️  Failed to save proof file: {}", e),
+        }
+        if let Program::Guest { elf, .. } = &request.program {
+            let meta = ProofMeta { image_id: image_id_hex.clone(), operation: operation.clone(), elf_len: elf.len() };
+            let meta_filename = output_file(&output_dir, &format!("proof_{}_{}.meta.json", operation, timestamp));
+            match std::fs::write(&meta_filename, serde_json::to_string_pretty(&meta)?) {
+                Ok(_) => eprintln!("📁 Image ID sidecar saved to: {}", meta_filename),
+                Err(e) => eprintln!("⚠️  Failed to save proof sidecar: {}", e),
+            }
+        }
+        proof_filename
+    };
     
-    let (proof_hex, proof_size, proof_file_path) = (Some(receipt_hex), Some(size), Some(proof_filename));
+    // The journal goes out alongside the proof and is withheld with it
+    let journal_file_path = match &journal_out {
+        Some(path) if !proof_filename.is_empty() => match fs::write(path, &receipt.journal.bytes) {
+            Ok(_) => {
+                eprintln!("📁 Journal ({} bytes) saved to: {}", receipt.journal.bytes.len(), path);
+                Some(path.clone())
+            },
+            Err(e) => {
+                eprintln!("⚠️  Failed to save journal file: {}", e);
+                None
+            }
+        },
+        _ => None,
+    };
     
-    println!("{{");
-    println!("  \"timestamp\": {},", timestamp);
+    // The seal is extra output for seal-only verifiers; the full receipt above stays the proof of record
+    let (seal_file_path, seal_size_bytes) = if seal_only && !proof_filename.is_empty() {
+        match proof_file::succinct_seal_bytes(receipt) {
+            Ok(seal) => {
+                let seal_filename = output_file(&output_dir, &format!("proof_{}_{}.seal", operation, timestamp));
+                match fs::write(&seal_filename, &seal) {
+                    Ok(_) => {
+                        eprintln!("📁 Succinct seal ({} bytes) saved to: {}", seal.len(), seal_filename);
+                        (Some(seal_filename), Some(seal.len()))
+                    },
+                    Err(e) => {
+                        eprintln!("⚠️  Failed to save seal file: {}", e);
+                        (None, None)
+                    }
+                }
+            },
+            Err(e) => {
+                eprintln!("⚠️  No succinct seal to save (--seal-only): {}", e);
+                (None, None)
+            }
+        }
+    } else {
+        (None, None)
+    };
     
-    match operation.as_str() {
-        "sqrt" => {
-            let n_decimal: f64 = args[2].parse().expect("Second argument must be a positive number");
-            println!("  \"inputs\": {{ \"n\": {} }},", n_decimal);
-        },
-        "add" | "multiply" => {
-            let a_decimal: f64 = args[2].parse().expect("Second argument must be a number");
-            let b_decimal: f64 = args[3].parse().expect("Third argument must be a number");
-            println!("  \"inputs\": {{ \"a\": {}, \"b\": {} }},", a_decimal, b_decimal);
-        },
-        "modexp" => {
-            let base: u64 = args[2].parse().expect("Second argument must be a positive integer");
-            let exponent: u64 = args[3].parse().expect("Third argument must be a positive integer");
-            let modulus: u64 = args[4].parse().expect("Fourth argument must be a positive integer");
-            println!("  \"inputs\": {{ \"base\": {}, \"exponent\": {}, \"modulus\": {} }},", base, exponent, modulus);
-        },
-        "range" => {
-            let min_value: u64 = args[3].parse().expect("Third argument must be a positive integer");
-            let max_value: u64 = args[4].parse().expect("Fourth argument must be a positive integer");
-            println!("  \"inputs\": {{ \"min\": {}, \"max\": {} }},", min_value, max_value);
-        },
-        "k_means" | "linear_regression" | "neural_network" | "logistic_regression" => {
-            let inputs_json = &args[2];
-            println!("  \"inputs\": {},", inputs_json);
+    // authenticated_add's decoder has already checked the signature made inside the guest
+    let authenticated = decoded.authenticated.as_ref();
+    let result_type = proof.result.result_type;
+    let output = HostOutput {
+        schema_version: OUTPUT_SCHEMA_VERSION,
+        timestamp,
+        inputs: request.program.echo_inputs(stdio_mode),
+        result: match (decoded.note, &proof.result.value) {
+            (Some(_), _) => None,
+            // Dynamic results are already JSON; u64 values past i64::MAX keep their exact value
+            (None, Some(value)) => Some(value.clone()),
+            (None, None) => result_type.map(|kind| kind.to_json(decoded.value, decoded.fixed, decoded.integer)),
         },
-        "dynamic" | "precompiled" => {
-            let inputs_json = &args[3];
-            println!("  \"inputs\": {},", inputs_json);
-        },
-        _ => {
-            println!("  \"inputs\": {{ \"unknown\": true }},");
+        result_type: result_type.map(|kind| kind.name()),
+        result_note: decoded.note,
+        result_value: proof.result.value.clone(),
+        overflow: decoded.overflow,
+        results: decoded.results.clone(),
+        invertible: decoded.invertible,
+        bezout: decoded.bezout.clone(),
+        invalid_range: decoded.range.as_ref().map(|range| range.invalid_range != 0),
+        above_min: decoded.range.as_ref().map(|range| range.above_min),
+        below_max: decoded.range.as_ref().map(|range| range.below_max),
+        host_compute_match,
+        guest_stdout: stdio_mode.then(|| String::from_utf8_lossy(&proof.guest_stdout).into_owned()),
+        receipt_journal: receipt.journal.bytes.clone(),
+        journal_codec: request.journal_codec.to_string(),
+        image_id: image_id_hex,
+        verification_status: if is_verified { "verified" } else { "failed" },
+        exit_code: proof.exit_code,
+        halt_reason: proof.halt_reason,
+        peak_memory_mb: proof.peak_memory_mb,
+        total_cycles: proof.total_cycles,
+        user_cycles: proof.user_cycles,
+        segments: proof.segments,
+        proof_generation_time_ms: proof.prove_time.as_millis(),
+        verification_time_ms: proof.verify_time.as_millis(),
+        total_time_ms: total_duration.as_millis(),
+        proof_seal_hex: hex::encode(&receipt_bytes),
+        proof_size_bytes: receipt_bytes.len(),
+        proof_system: proofs::proof_system(receipt),
+        snark_seal_hex,
+        journal_digest,
+        proof_file_path: proof_filename,
+        journal_file_path,
+        seal_file_path,
+        seal_size_bytes,
+        dev_mode,
+        prover: proof.prover.name(),
+        public_key: authenticated.map(|computation| hex::encode(computation.public_key)),
+        signature: authenticated.map(|computation| hex::encode(&computation.signature)),
+        task_id: authenticated.map(|computation| computation.task_id.clone()),
+        auth_timestamp: authenticated.map(|computation| computation.timestamp),
+    };
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    
+    if !is_verified && fail_on_unverified {
+        eprintln!("Error: receipt failed verification (--fail-on-unverified)");
+        std::process::exit(1);
+    }
+    
+    // An overflowed computation still proves and verifies, but there is no result to report
+    if decoded.overflow == Some(true) {
+        match &output.results {
+            Some(results) => eprintln!("Error: batch overflowed ({} of {} pairs overflow i64)", results.iter().filter(|r| r.is_none()).count(), results.len()),
+            None => eprintln!("Error: {} overflowed ({})", operation, decoded.note.unwrap_or("result overflows i64")),
         }
+        std::process::exit(1);
     }
-    println!("  \"result\": {},", decimal_result);
-    println!("  \"receipt_journal\": {:?},", receipt.journal.bytes);
-    println!("  \"image_id\": \"{}\",", hex::encode(id_bytes));
-    println!("  \"verification_status\": \"{}\",", 
-        if is_verified { "verified" } else { "failed" }
-    );
-    println!("  \"proof_generation_time_ms\": {},", prove_duration.as_millis());
-    println!("  \"verification_time_ms\": {},", verify_duration.as_millis());
-    println!("  \"total_time_ms\": {},", total_duration.as_millis());
-    
-    // Include proof data
-    println!("  \"proof_seal_hex\": \"{}\",", proof_hex.unwrap_or_default());
-    println!("  \"proof_size_bytes\": {},", proof_size.unwrap_or(0));
-    println!("  \"proof_file_path\": \"{}\",", proof_file_path.unwrap_or_default());
-    println!("  \"dev_mode\": false,");
-    
-    // Add authentication details if available
-    if let Some((public_key, signature, task_id, auth_timestamp)) = auth_details {
-        println!("  \"public_key\": \"{}\",", public_key);
-        println!("  \"signature\": \"{}\",", signature);
-        println!("  \"task_id\": \"{}\",", task_id);
-        println!("  \"auth_timestamp\": {}", auth_timestamp);
-    } else {
-        println!("  \"public_key\": null,");
-        println!("  \"signature\": null,");
-        println!("  \"task_id\": null,");
-        println!("  \"auth_timestamp\": null");
+    
+    if !halted_normally {
+        eprintln!("Error: guest did not halt cleanly ({}, exit code {:?})", proof.halt_reason, proof.exit_code);
+        std::process::exit(1);
     }
-    println!("}}");
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_take_flag_values() {
+        let mut args = to_args(&["host", "--assumption", "inner.bin", "precompiled", "--assumption", "other.hex", "outer.bin", "{}"]);
+        let values = take_flag_values(&mut args, "--assumption").unwrap();
+        assert_eq!(values, vec!["inner.bin", "other.hex"]);
+        assert_eq!(args, to_args(&["host", "precompiled", "outer.bin", "{}"]));
+        
+        let mut dangling = to_args(&["host", "add", "1", "2", "--assumption"]);
+        assert!(take_flag_values(&mut dangling, "--assumption").is_err());
+    }
+
+    #[test]
+    fn test_parse_state_hash() {
+        let digest = parse_state_hash(&"ab".repeat(32)).unwrap();
+        assert_eq!(digest.as_bytes(), &[0xab; 32]);
+        assert_eq!(parse_state_hash(&digest.to_string()).unwrap(), digest);
+        assert!(parse_state_hash("abcd").is_err());
+        assert!(parse_state_hash(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_random_args_are_reproducible() {
+        let generate = |seed| {
+            let mut rng = SplitMix64(seed);
+            (0..4).map(|_| random_args(&mut rng, "modexp").unwrap()).collect::<Vec<_>>()
+        };
+        assert_eq!(generate(7), generate(7));
+        assert_ne!(generate(7), generate(8));
+        
+        let mut rng = SplitMix64(7);
+        for _ in 0..100 {
+            let range = random_args(&mut rng, "range").unwrap();
+            assert!(range[1].parse::<u64>().unwrap() <= range[2].parse::<u64>().unwrap());
+            assert!(random_args(&mut rng, "sqrt").unwrap()[0].parse::<f64>().unwrap() >= 0.0);
+        }
+        assert!(random_args(&mut rng, "dynamic").is_none());
+    }
+
+    #[test]
+    fn test_fail_on_unverified_enabled() {
+        // Legacy behavior stays the default
+        assert!(!fail_on_unverified_enabled(false, None));
+        assert!(!fail_on_unverified_enabled(false, Some("0")));
+        // Either the flag or the v2 environment opts in
+        assert!(fail_on_unverified_enabled(true, None));
+        assert!(fail_on_unverified_enabled(false, Some("1")));
+        assert!(fail_on_unverified_enabled(false, Some("true")));
+    }
+
+    #[test]
+    fn test_output_file_defaults_to_cwd() {
+        assert_eq!(output_file(std::path::Path::new(""), "proof_add_1700000000.bin"), "proof_add_1700000000.bin");
+        let under = output_file(std::path::Path::new("/data/proofs"), "proof_add_1700000000.bin");
+        assert_eq!(std::path::Path::new(&under), std::path::Path::new("/data/proofs/proof_add_1700000000.bin"));
+    }
+    
+    #[test]
+    fn test_operation_catalog_lists_each_operation_once() {
+        let catalog = operation_catalog(Registry::builtin());
+        let mut names: Vec<&str> = catalog.iter().map(|op| op.name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), catalog.len());
+        
+        let arg_count = |name: &str| catalog.iter().find(|op| op.name == name).map(|op| op.arg_count);
+        assert_eq!(arg_count("modexp"), Some(3));
+        assert_eq!(arg_count("batch"), Some(2));
+        assert_eq!(arg_count("factorial"), Some(1));
+        // Every listed name is one `main` can run
+        for op in &catalog {
+            assert!(matches!(op.name, "dynamic" | "precompiled") || Registry::builtin().get(op.name).is_some(), "{} is not an operation", op.name);
+        }
+    }
+}
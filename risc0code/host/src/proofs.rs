@@ -0,0 +1,253 @@
+// Saved proof files: reading receipts back (as assumptions, for bundles and for `--list-proofs`),
+// the envelope a proof is written in, and what a receipt says about how its guest halted.
+use methods::journal::{decode_dynamic_result, JournalCodec};
+use methods::operation::{DecodedJournal, Registry, ResultType};
+use methods::proof_file::{BundleEntry, MinimalEnvelope, ProofBundle, SavedProof};
+use risc0_zkvm::sha::{Digestible, Impl, Sha256};
+use risc0_zkvm::{ExitCode, InnerReceipt, Receipt};
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// How a saved proof stores its receipt: the full receipt, or a `MinimalEnvelope` holding only the
+// seal, journal and image ID
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Envelope {
+    #[default]
+    Full,
+    Minimal,
+}
+
+impl FromStr for Envelope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(Envelope::Full),
+            "minimal" => Ok(Envelope::Minimal),
+            other => Err(format!("envelope must be 'full' or 'minimal', got '{}'", other)),
+        }
+    }
+}
+
+// The bytes saved as a proof file; minimal envelopes need a succinct or Groth16 receipt
+pub fn encode_receipt(receipt: &Receipt, image_id: [u32; 8], envelope: Envelope) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    Ok(match envelope {
+        Envelope::Full => bincode::serialize(receipt)?,
+        Envelope::Minimal => MinimalEnvelope::new(receipt, image_id)?.encode()?,
+    })
+}
+
+// Read a saved proof's raw bytes, hex-decoding .hex files
+fn read_proof_bytes(path: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    if path.ends_with(".bin") {
+        Ok(fs::read(path).map_err(|e| format!("Failed to read {}: {}", path, e))?)
+    } else {
+        let hex_content = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        Ok(hex::decode(hex_content.trim()).map_err(|e| format!("{} is not valid hex: {}", path, e))?)
+    }
+}
+
+// Which proof system produced the receipt, as reported in `proof_system`
+pub fn proof_system(receipt: &Receipt) -> &'static str {
+    match &receipt.inner {
+        InnerReceipt::Composite(_) => "composite",
+        InnerReceipt::Succinct(_) => "succinct",
+        InnerReceipt::Groth16(_) => "groth16",
+        InnerReceipt::Fake(_) => "fake",
+        _ => "unknown",
+    }
+}
+
+// Read a saved proof (.bin or .hex), accepting both full receipts and minimal envelopes
+pub fn read_proof_file(path: &str) -> Result<Receipt, Box<dyn std::error::Error>> {
+    let receipt_bytes = read_proof_bytes(path)?;
+    let proof = SavedProof::decode(&receipt_bytes)
+        .map_err(|e| format!("{} is not a valid receipt: {}", path, e))?;
+    Ok(proof.receipt)
+}
+
+// Load a previously generated proof (.bin or .hex) for use as an assumption
+pub fn load_assumption(path: &str) -> Result<Receipt, Box<dyn std::error::Error>> {
+    read_proof_file(path).map_err(|e| format!("Invalid assumption: {}", e).into())
+}
+
+// One row of the `--list-proofs` inventory
+pub struct ProofSummary {
+    pub filename: String,
+    pub operation: String,
+    pub timestamp: u64,
+    pub image_id: String,
+    pub journal_len: usize,
+    pub result: String,
+}
+
+// Split a saved proof name (proof_<operation>_<timestamp>.bin|.hex) into operation and timestamp
+pub fn parse_proof_filename(filename: &str) -> Option<(String, u64)> {
+    let stem = filename.strip_suffix(".bin").or_else(|| filename.strip_suffix(".hex"))?;
+    let (operation, timestamp) = stem.strip_prefix("proof_")?.rsplit_once('_')?;
+    if operation.is_empty() {
+        return None;
+    }
+    Some((operation.to_string(), timestamp.parse().ok()?))
+}
+
+// Best-effort decode of the headline value in a journal, without verifying the receipt
+fn describe_journal(registry: &Registry, operation: &str, receipt: &Receipt) -> String {
+    let journal = &receipt.journal;
+    let described = match registry.get(operation) {
+        Some(op) => op.decode_journal(journal).map(|decoded| match &decoded {
+            DecodedJournal { range: Some(range), note: None, .. } => format!("in {}: {}", range.interval(), range.in_range),
+            DecodedJournal { results: Some(results), .. } => format!("{} pairs", results.len()),
+            DecodedJournal { note: Some(note), .. } => note.to_string(),
+            _ if op.result_type() == ResultType::Decimal => decoded.value.to_string(),
+            _ => op.result_type().to_json(decoded.value, decoded.fixed, decoded.integer).to_string(),
+        }).ok(),
+        // Dynamic and precompiled journals, read with the default codec
+        None => decode_dynamic_result(JournalCodec::default(), &journal.bytes).map(|value| value.to_string()).ok(),
+    };
+    described.unwrap_or_else(|| "-".to_string())
+}
+
+// Read a proof file's envelope and summarize it; the receipt is decoded but not verified
+fn summarize_proof(registry: &Registry, path: &std::path::Path) -> Result<ProofSummary, Box<dyn std::error::Error>> {
+    let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or_default().to_string();
+    let (operation, timestamp) = parse_proof_filename(&filename)
+        .ok_or_else(|| format!("{} is not named like a saved proof", filename))?;
+    
+    let receipt = read_proof_file(path.to_str().ok_or("Proof path is not valid UTF-8")?)?;
+    let image_id = match receipt.claim()?.as_value() {
+        Ok(claim) => claim.pre.digest().to_string(),
+        Err(_) => "pruned".to_string(),
+    };
+    
+    Ok(ProofSummary {
+        result: describe_journal(registry, &operation, &receipt),
+        journal_len: receipt.journal.bytes.len(),
+        filename,
+        operation,
+        timestamp,
+        image_id,
+    })
+}
+
+// Every saved proof in a directory, oldest first, and the files that could not be summarized
+pub struct ProofInventory {
+    pub summaries: Vec<ProofSummary>,
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+// Summarize every proof file in `dir` for `--list-proofs`; nothing is verified
+pub fn proof_inventory(dir: &str) -> Result<ProofInventory, Box<dyn std::error::Error>> {
+    let registry = Registry::builtin();
+    let mut inventory = ProofInventory { summaries: Vec::new(), skipped: Vec::new() };
+    for entry in fs::read_dir(dir).map_err(|e| format!("Failed to read proof directory {}: {}", dir, e))? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+        match summarize_proof(registry, &path) {
+            Ok(summary) => inventory.summaries.push(summary),
+            Err(e) => inventory.skipped.push((path, e.to_string())),
+        }
+    }
+    inventory.summaries.sort_by(|a, b| (a.timestamp, &a.filename).cmp(&(b.timestamp, &b.filename)));
+    Ok(inventory)
+}
+
+// Pack saved proofs into a single bundle; each proof is decoded first so a bundle never holds garbage
+pub fn build_bundle(paths: &[String]) -> Result<ProofBundle, Box<dyn std::error::Error>> {
+    let mut bundle = ProofBundle::default();
+    for path in paths {
+        let filename = std::path::Path::new(path).file_name().and_then(|n| n.to_str()).unwrap_or_default().to_string();
+        let (operation, _) = parse_proof_filename(&filename)
+            .ok_or_else(|| format!("{} is not named like a saved proof", filename))?;
+        
+        let proof_bytes = read_proof_bytes(path)?;
+        let receipt = SavedProof::decode(&proof_bytes)
+            .map_err(|e| format!("{} is not a valid receipt: {}", path, e))?
+            .receipt;
+        let image_id = receipt.claim()?.as_value()
+            .map_err(|_| format!("{} has a pruned claim; its image ID cannot be recorded", path))?
+            .pre.digest();
+        
+        bundle.manifest.push(BundleEntry {
+            filename,
+            operation,
+            image_id,
+            digest: *Impl::hash_bytes(&proof_bytes),
+        });
+        bundle.proofs.push(proof_bytes);
+    }
+    Ok(bundle)
+}
+
+// How the guest halted, from the receipt claim: (halt reason, exit code), normal only for ("halted", Some(0))
+pub fn halt_status(receipt: &Receipt) -> Result<(&'static str, Option<u32>), Box<dyn std::error::Error>> {
+    let claim = receipt.claim()?;
+    let exit_code = match claim.as_value() {
+        Ok(claim) => claim.exit_code,
+        Err(_) => return Ok(("unknown", None)), // Pruned claim carries no exit code
+    };
+    Ok(match exit_code {
+        ExitCode::Halted(code) => ("halted", Some(code)),
+        ExitCode::Paused(code) => ("paused", Some(code)),
+        ExitCode::SystemSplit => ("system_split", None),
+        ExitCode::SessionLimit => ("session_limit", None),
+        // ExitCode is non-exhaustive; a newer zkVM may halt in ways this host does not know
+        _ => ("unknown", None),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use methods::ADDITION_ID;
+
+    #[test]
+    fn test_fake_receipt_has_no_succinct_seal() {
+        use risc0_zkvm::{FakeReceipt, ReceiptClaim};
+        
+        let journal = 7i64.to_le_bytes().to_vec();
+        let claim = ReceiptClaim::ok(ADDITION_ID, journal.clone());
+        let receipt = Receipt::new(InnerReceipt::Fake(FakeReceipt::new(claim)), journal);
+        assert!(methods::proof_file::succinct_seal_bytes(&receipt).is_err());
+        assert_eq!(proof_system(&receipt), "fake");
+    }
+    
+    #[test]
+    fn test_halt_status_reports_guest_exit_code() {
+        use risc0_zkvm::{FakeReceipt, ReceiptClaim};
+        
+        let journal = 3i64.to_le_bytes().to_vec();
+        let claim = ReceiptClaim::ok(ADDITION_ID, journal.clone());
+        let receipt = Receipt::new(InnerReceipt::Fake(FakeReceipt::new(claim)), journal.clone());
+        assert_eq!(halt_status(&receipt).unwrap(), ("halted", Some(0)));
+        
+        // Same journal, but the guest called `env::exit(3)`
+        let mut claim = ReceiptClaim::ok(ADDITION_ID, journal.clone());
+        claim.exit_code = ExitCode::Halted(3);
+        let receipt = Receipt::new(InnerReceipt::Fake(FakeReceipt::new(claim)), journal);
+        assert_eq!(halt_status(&receipt).unwrap(), ("halted", Some(3)));
+    }
+
+    #[test]
+    fn test_parse_proof_filename() {
+        assert_eq!(parse_proof_filename("proof_add_1700000000.bin"), Some(("add".to_string(), 1700000000)));
+        assert_eq!(parse_proof_filename("proof_value_at_risk_1700000001.hex"), Some(("value_at_risk".to_string(), 1700000001)));
+        assert_eq!(parse_proof_filename("proof_add_latest.bin"), None);
+        assert_eq!(parse_proof_filename("proof_1700000000.bin"), None);
+        assert_eq!(parse_proof_filename("notes.txt"), None);
+    }
+
+    #[test]
+    fn test_load_assumption_rejects_invalid_receipt() {
+        let path = std::env::temp_dir().join("risc0_invalid_assumption.bin");
+        fs::write(&path, b"not a receipt").unwrap();
+        let result = load_assumption(path.to_str().unwrap());
+        let _ = fs::remove_file(&path);
+        assert!(result.is_err());
+        
+        assert!(load_assumption("/nonexistent/assumption.bin").is_err());
+    }
+}
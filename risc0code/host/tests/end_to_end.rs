@@ -0,0 +1,1013 @@
+// End-to-end coverage of prove -> save -> verify for the built-in operations.
+// Proofs are generated in dev mode so the suite runs in seconds. Dev mode is set on each prove
+// request and verifier context, and on the environment of each child process, never on this
+// process's environment, which tests running in parallel would race on.
+use methods::{ADDITION_ELF, ADDITION_ID, GUEST_AUTHENTICATED_ADD_ID, GUEST_COMPOSITION_ELF, GUEST_CALCULATOR_ID, GUEST_RANGE_ID, GUEST_STATE_TRANSITION_ID, MODEXP_GUEST_ID, MULTIPLY_GUEST_ID, SQRT_GUEST_ID, SUBTRACT_GUEST_ID, GUEST_DIVIDE_ID, GUEST_BATCH_ID, GUEST_MODINVERSE_ID, GUEST_GCD_ID, GUEST_POWER_ID, GUEST_FACTORIAL_ID, GUEST_VERIFY_SIGNATURE_ID};
+use host::{prove_operation, prove_operation_with, Program, ProofOutput, ProveError, ProveRequest};
+use methods::journal::{AuthenticatedAddJournal, RangeJournal};
+use methods::operation::{DecodedJournal, Operation, OperationError, Registry};
+use methods::proof_file::SavedProof;
+use risc0_zkvm::sha::{Digest, Digestible};
+use risc0_zkvm::{default_prover, ExecutorEnv, ExecutorEnvBuilder, Journal, ProverOpts, Receipt, VerifierContext};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus};
+
+// Fake receipts only verify in dev mode
+fn dev_mode() -> VerifierContext {
+    VerifierContext::default().with_dev_mode(true)
+}
+
+struct RoundTrip {
+    proof: ProofOutput,
+    receipt: Receipt,
+    // The result as the verifier decodes it from the saved proof
+    decoded: verify::DecodedResult,
+}
+
+// Prove a registered operation through the host library, save the proof under the name the binary
+// gives it, then detect, verify and decode the saved file through the verify library
+fn round_trip(operation: &str, args: &[&str]) -> RoundTrip {
+    let registry = Registry::builtin();
+    let op = registry.get(operation).unwrap_or_else(|| panic!("{} is not registered", operation));
+    let mut request = ProveRequest::new(Program::Registered(op, args.iter().map(|arg| arg.to_string()).collect()));
+    request.dev_mode = true;
+    let proof = prove_operation_with(&request).expect("proving failed");
+    
+    let work_dir = tempfile::tempdir().unwrap();
+    let filename = format!("proof_{}_1700000000.bin", operation);
+    fs::write(work_dir.path().join(&filename), &proof.receipt).unwrap();
+    let saved = SavedProof::decode(&fs::read(work_dir.path().join(&filename)).unwrap()).unwrap();
+    
+    let detected = verify::detect_operation(registry, &filename);
+    assert_eq!(detected, operation);
+    let matched = verify::verify_with_allowlist(&saved.receipt, &dev_mode(), op.image_id(), None).expect("saved receipt failed verification");
+    assert_eq!(matched, None);
+    let codec = verify::journal_codec(registry, &detected, None).unwrap();
+    let decoded = verify::decode_result(registry, &detected, codec, &saved.receipt.journal).unwrap();
+    
+    RoundTrip { proof, receipt: saved.receipt, decoded }
+}
+
+struct ProvedRun {
+    output: Value,
+    receipt: Receipt,
+}
+
+// Run the host binary for one operation in its own scratch directory and load the proof it saved
+fn prove(args: &[&str]) -> ProvedRun {
+    let work_dir = tempfile::tempdir().unwrap();
+    
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(args)
+        .current_dir(work_dir.path())
+        .env("RISC0_DEV_MODE", "1")
+        .output()
+        .expect("failed to run host binary");
+    assert!(output.status.success(), "host failed: {}", String::from_utf8_lossy(&output.stderr));
+    
+    let output: Value = serde_json::from_slice(&output.stdout).expect("host did not print valid JSON");
+    let proof_path = work_dir.path().join(output["proof_file_path"].as_str().unwrap());
+    let receipt: Receipt = bincode::deserialize(&fs::read(&proof_path).unwrap()).unwrap();
+    
+    ProvedRun { output, receipt }
+}
+
+// Run the host binary in its own scratch directory, for runs expected to fail: returns the exit
+// status, the JSON it printed (null if none) and its stderr
+fn run_host(args: &[&str]) -> (ExitStatus, Value, String) {
+    let work_dir = tempfile::tempdir().unwrap();
+    
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(args)
+        .current_dir(work_dir.path())
+        .env("RISC0_DEV_MODE", "1")
+        .output()
+        .expect("failed to run host binary");
+    let parsed = serde_json::from_slice(&output.stdout).unwrap_or(Value::Null);
+    
+    (output.status, parsed, String::from_utf8_lossy(&output.stderr).into_owned())
+}
+
+fn assert_verified(run: &ProvedRun, image_id: [u32; 8]) {
+    assert_eq!(run.output["verification_status"], "verified");
+    run.receipt.verify_with_context(&dev_mode(), image_id).expect("saved receipt failed verification");
+}
+
+#[test]
+fn add_round_trips() {
+    let run = round_trip("add", &["1.5", "2.25"]);
+    assert_eq!(run.proof.image_id, ADDITION_ID);
+    
+    let (a, b, result, overflow): (i64, i64, i64, u32) = run.receipt.journal.decode().unwrap();
+    assert_eq!((a, b, result, overflow), (150000, 225000, 375000, 0));
+    assert_eq!(run.proof.result, Some(3.75));
+    assert_eq!(run.decoded.decimal, Some(3.75));
+    assert_eq!(run.decoded.lines, vec!["🔢 Computation result: 1.5 + 2.25 = 3.75"]);
+}
+
+#[test]
+fn multiply_round_trips() {
+    let run = round_trip("multiply", &["2.5", "4.0"]);
+    assert_eq!(run.proof.image_id, MULTIPLY_GUEST_ID);
+    
+    let (_, _, result, overflow): (i64, i64, i64, u32) = run.receipt.journal.decode().unwrap();
+    assert_eq!((result, overflow), (1000000, 0));
+    assert_eq!((run.proof.result, run.proof.overflow), (Some(10.0), Some(false)));
+    assert_eq!(run.decoded.json_value(), Some(serde_json::json!(10.0)));
+}
+
+#[test]
+fn multiply_overflow_is_reported_instead_of_wrapping() {
+    let (status, parsed, stderr) = run_host(&["multiply", "99999999", "99999999"]);
+    
+    // The proof is valid, but the host reports the overflow and fails instead of printing a wrapped product
+    assert!(!status.success());
+    assert!(stderr.contains("multiply overflowed"));
+    assert_eq!(parsed["verification_status"], "verified");
+    assert_eq!(parsed["overflow"], true);
+    assert!(parsed["result"].is_null());
+    assert_eq!(parsed["result_note"], "result overflows i64");
+}
+
+#[test]
+fn batch_results_align_with_input_pairs() {
+    let run = prove(&["batch", "add", "[[1.5, 2.25], [-1, 0.5], [10, 20]]"]);
+    assert_verified(&run, GUEST_BATCH_ID);
+    
+    // One proof covers every pair; the journal carries one add layout per pair
+    let (operation, entries): (u32, Vec<(i64, i64, i64, u32)>) = run.receipt.journal.decode().unwrap();
+    assert_eq!(operation, 0);
+    assert_eq!(entries[1], (-100000, 50000, -50000, 0));
+    assert_eq!(run.output["results"], serde_json::json!([3.75, -0.5, 30.0]));
+    assert_eq!(run.output["overflow"], false);
+    assert!(run.output["result"].is_null());
+}
+
+#[test]
+fn modinverse_reports_non_invertible_inputs() {
+    let run = prove(&["--compare-host-compute", "modinverse", "17", "3120"]);
+    assert_verified(&run, GUEST_MODINVERSE_ID);
+    let journal: (u64, u64, u64, u32) = run.receipt.journal.decode().unwrap();
+    assert_eq!(journal, (17, 3120, 2753, 0));
+    assert_eq!(run.output["result"].as_f64().unwrap(), 2753.0);
+    assert_eq!(run.output["invertible"], true);
+    assert_eq!(run.output["host_compute_match"], true);
+    
+    // gcd(6, 9) = 3: the proof still verifies, but there is no inverse to report
+    let run = prove(&["modinverse", "6", "9"]);
+    assert_verified(&run, GUEST_MODINVERSE_ID);
+    assert_eq!(run.output["invertible"], false);
+    assert!(run.output["result"].is_null());
+    assert_eq!(run.output["result_note"], "not invertible");
+}
+
+#[test]
+fn gcd_commits_bezout_coefficients() {
+    let run = prove(&["--compare-host-compute", "gcd", "240", "46"]);
+    assert_verified(&run, GUEST_GCD_ID);
+    
+    let (a, b, gcd, x, y): (u64, u64, u64, i64, i64) = run.receipt.journal.decode().unwrap();
+    assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, gcd as i128);
+    assert_eq!(run.output["result"].as_f64().unwrap(), 2.0);
+    assert_eq!(run.output["bezout"], serde_json::json!({ "a": 240, "b": 46, "gcd": 2, "x": -9, "y": 47 }));
+    assert_eq!(run.output["host_compute_match"], true);
+}
+
+#[test]
+fn power_overflow_is_reported_instead_of_wrapping() {
+    let run = prove(&["--compare-host-compute", "power", "3", "39"]);
+    assert_verified(&run, GUEST_POWER_ID);
+    let journal: (u64, u64, u64, u32) = run.receipt.journal.decode().unwrap();
+    assert_eq!(journal, (3, 39, 4052555153018976267, 0));
+    assert_eq!(run.output["overflow"], false);
+    assert_eq!(run.output["host_compute_match"], true);
+    
+    let (status, parsed, stderr) = run_host(&["power", "2", "64"]);
+    
+    // 2^64 wraps to 0 in u64; the guest flags it and the host fails rather than report a value
+    assert!(!status.success());
+    assert!(stderr.contains("power overflowed"));
+    assert_eq!(parsed["verification_status"], "verified");
+    assert_eq!(parsed["overflow"], true);
+    assert!(parsed["result"].is_null());
+    assert_eq!(parsed["result_note"], "result overflows u64");
+}
+
+#[test]
+fn factorial_overflow_boundary_is_reported() {
+    let run = prove(&["factorial", "0"]);
+    assert_verified(&run, GUEST_FACTORIAL_ID);
+    let journal: (u64, u64, u32) = run.receipt.journal.decode().unwrap();
+    assert_eq!(journal, (0, 1, 0));
+    
+    let run = prove(&["--compare-host-compute", "factorial", "20"]);
+    assert_eq!(run.output["result"].as_f64().unwrap(), 2432902008176640000.0);
+    assert_eq!(run.output["overflow"], false);
+    assert_eq!(run.output["host_compute_match"], true);
+    
+    let (status, parsed, stderr) = run_host(&["factorial", "21"]);
+    
+    // 21! is the first factorial past u64::MAX; it is flagged rather than committed wrapped
+    assert!(!status.success());
+    assert!(stderr.contains("factorial overflowed"));
+    assert_eq!(parsed["verification_status"], "verified");
+    assert_eq!(parsed["overflow"], true);
+    assert!(parsed["result"].is_null());
+    let journal = Journal::new(serde_json::from_value(parsed["receipt_journal"].clone()).unwrap());
+    assert_eq!(journal.decode::<(u64, u64, u32)>().unwrap(), (21, 0, 1));
+}
+
+#[test]
+fn subtract_round_trips_negative_result() {
+    let run = prove(&["subtract", "1.25", "3.5"]);
+    assert_verified(&run, SUBTRACT_GUEST_ID);
+    
+    let (a, b, result): (i64, i64, i64) = run.receipt.journal.decode().unwrap();
+    assert_eq!((a, b, result), (125000, 350000, -225000));
+    assert_eq!(run.output["result"].as_f64().unwrap(), -2.25);
+}
+
+#[test]
+fn divide_round_trips_quotient_and_remainder() {
+    let run = prove(&["--compare-host-compute", "divide", "7", "2"]);
+    assert_verified(&run, GUEST_DIVIDE_ID);
+    
+    let journal: (i64, i64, i64, i64, u32) = run.receipt.journal.decode().unwrap();
+    assert_eq!(journal, (700000, 200000, 350000, 0, 0));
+    assert_eq!(run.receipt.journal.bytes.len(), 36);
+    assert_eq!(run.output["result"].as_f64().unwrap(), 3.5);
+    assert_eq!(run.output["host_compute_match"], true);
+}
+
+#[test]
+fn divide_by_zero_is_reported_in_output() {
+    let run = prove(&["divide", "1", "0"]);
+    assert_verified(&run, GUEST_DIVIDE_ID);
+    
+    let (_, _, _, _, flag): (i64, i64, i64, i64, u32) = run.receipt.journal.decode().unwrap();
+    assert_eq!(flag, 1);
+    assert!(run.output["result"].is_null());
+    assert_eq!(run.output["result_note"], "division by zero");
+}
+
+#[test]
+fn prove_operation_library_matches_binary() {
+    let run = round_trip("multiply", &["2.5", "4.0"]);
+    let binary = prove(&["multiply", "2.5", "4.0"]);
+    assert_verified(&binary, MULTIPLY_GUEST_ID);
+    
+    // The library and the binary prove the same guest on the same inputs, and commit the same journal
+    assert_eq!(run.proof.journal, binary.receipt.journal.bytes);
+    assert_eq!(hex::encode(Digest::from(run.proof.image_id).as_bytes()), binary.output["image_id"]);
+    assert_eq!(run.proof.result, binary.output["result"].as_f64());
+    
+    let zero_divisor = round_trip("divide", &["1", "0"]);
+    assert_eq!(zero_divisor.proof.result, None);
+    assert_eq!(zero_divisor.proof.result_note, Some("division by zero"));
+    assert!(zero_divisor.decoded.no_result);
+    
+    assert!(matches!(prove_operation("sqrt", &["4".to_string(), "9".to_string()]), Err(ProveError::InvalidInputs(_))));
+    // A dynamic guest is not a registered operation
+    let dynamic = ProveRequest::new(Program::Guest { operation: "dynamic", elf: ADDITION_ELF.to_vec(), inputs: "{}".to_string() });
+    assert!(matches!(prove_operation_with(&dynamic), Err(ProveError::UnknownOperation(_))));
+}
+
+#[test]
+fn sqrt_round_trips() {
+    let run = round_trip("sqrt", &["2"]);
+    assert_eq!(run.proof.image_id, SQRT_GUEST_ID);
+    
+    let (input, result): (i64, i64) = run.receipt.journal.decode().unwrap();
+    assert_eq!(input, 200000);
+    assert!((result as f64 / 100000.0 - std::f64::consts::SQRT_2).abs() < 1e-4);
+    assert!((run.decoded.decimal.unwrap() - std::f64::consts::SQRT_2).abs() < 1e-4);
+}
+
+#[test]
+fn modexp_round_trips_beyond_i32() {
+    // 2^40 does not fit in an i32, guarding against truncating decoders
+    let run = round_trip("modexp", &["2", "40", "18446744073709551615"]);
+    assert_eq!(run.proof.image_id, MODEXP_GUEST_ID);
+    
+    let (_, _, _, result): (u64, u64, u64, u64) = run.receipt.journal.decode().unwrap();
+    assert_eq!(result, 1u64 << 40);
+    assert_eq!(run.proof.result_integer, Some(1u64 << 40));
+    assert_eq!(run.decoded.decimal, None);
+    
+    // The binary prints the exact u64, not a float or an i32
+    let binary = prove(&["modexp", "2", "40", "18446744073709551615"]);
+    assert_verified(&binary, MODEXP_GUEST_ID);
+    assert_eq!(binary.output["result"].as_u64().unwrap(), 1u64 << 40);
+    assert_eq!(binary.output["result_type"], "integer");
+}
+
+#[test]
+fn range_round_trips() {
+    let run = round_trip("range", &["25", "18", "65"]);
+    assert_eq!(run.proof.image_id, GUEST_RANGE_ID);
+    
+    let (in_range, above_min, below_max, min_value, max_value, invalid_range, min_exclusive, max_exclusive): (bool, bool, bool, u64, u64, u32, u32, u32) = run.receipt.journal.decode().unwrap();
+    assert!(in_range && above_min && below_max);
+    assert_eq!((min_value, max_value, invalid_range), (18, 65, 0));
+    assert_eq!((min_exclusive, max_exclusive), (0, 0));
+    assert_eq!(run.proof.range.as_ref().map(|range| range.in_range), Some(true));
+    
+    // Both one-sided checks are reported, so a caller can tell which bound a secret missed
+    let run = round_trip("range", &["70", "18", "65"]);
+    let range = run.proof.range.unwrap();
+    assert!(!range.in_range && range.above_min && !range.below_max);
+    
+    // The binary reports the range result as a boolean
+    let binary = prove(&["range", "25", "18", "65"]);
+    assert_verified(&binary, GUEST_RANGE_ID);
+    assert_eq!(binary.output["result"], true);
+    assert_eq!(binary.output["result_type"], "boolean");
+    assert_eq!(binary.output["invalid_range"], false);
+}
+
+#[test]
+fn range_library_rejects_inverted_bounds() {
+    // The library shares the binary's bounds check, so min > max never reaches the prover
+    let args: Vec<String> = ["5", "10", "3"].iter().map(|arg| arg.to_string()).collect();
+    assert!(matches!(prove_operation("range", &args), Err(ProveError::InvalidInputs(_))));
+}
+
+#[test]
+fn range_rejects_min_above_max() {
+    let (status, _, stderr) = run_host(&["range", "5", "10", "3"]);
+    assert!(!status.success());
+    assert!(stderr.contains("min (10) must not exceed max (3)"), "unexpected stderr: {}", stderr);
+}
+
+#[test]
+fn range_exclusive_bounds_are_committed() {
+    // 65 is in [18, 65] but not in [18, 65), and the journal says which was proven
+    let run = prove(&["--max-exclusive", "range", "65", "18", "65"]);
+    assert_verified(&run, GUEST_RANGE_ID);
+    
+    let range: RangeJournal = run.receipt.journal.decode().unwrap();
+    assert_eq!((range.min_exclusive, range.max_exclusive, range.invalid_range), (0, 1, 0));
+    assert_eq!(range.interval(), "[18, 65)");
+    assert!(range.above_min && !range.below_max && !range.in_range);
+    assert_eq!(run.output["result"], false);
+    assert_eq!(run.output["inputs"]["max_exclusive"], true);
+    assert_eq!(run.output["inputs"]["min_exclusive"], false);
+    
+    // (5, 6) contains no integer, so the binary refuses to prove it; the guest flags it regardless
+    let (status, _, _) = run_host(&["--min-exclusive", "--max-exclusive", "range", "5", "5", "6"]);
+    assert!(!status.success());
+    let args: Vec<String> = ["5", "5", "6", "1", "1"].iter().map(|arg| arg.to_string()).collect();
+    assert!(matches!(prove_operation("range", &args), Err(ProveError::InvalidInputs(_))));
+}
+
+#[test]
+fn list_operations_reports_arities() {
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(["list-operations", "--json"])
+        .output()
+        .expect("failed to run host binary");
+    assert!(output.status.success(), "host failed: {}", String::from_utf8_lossy(&output.stderr));
+    
+    let parsed: Value = serde_json::from_slice(&output.stdout).expect("host did not print valid JSON");
+    let operations = parsed["operations"].as_array().unwrap();
+    let find = |name: &str| operations.iter().find(|op| op["name"] == name).unwrap_or_else(|| panic!("{} is not listed", name));
+    assert_eq!(find("range")["arg_count"], 3);
+    assert_eq!(find("range")["usage"], "<secret_number> <min> <max>");
+    // Registered operations are listed from the registry
+    assert_eq!(find("calculator")["arg_count"], 1);
+    
+    // The table form lists the same operations
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .arg("list-operations")
+        .output()
+        .expect("failed to run host binary");
+    let table = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(table.lines().count(), operations.len() + 1);
+}
+
+#[test]
+fn image_id_matches_the_built_guest() {
+    let elf_path: PathBuf = std::env::temp_dir().join(format!("risc0_e2e_image_id_{}.elf", std::process::id()));
+    fs::write(&elf_path, ADDITION_ELF).unwrap();
+    let run = |args: &[&str]| Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(args)
+        .output()
+        .expect("failed to run host binary");
+    let plain = run(&["image-id", elf_path.to_str().unwrap()]);
+    let json = run(&["image-id", elf_path.to_str().unwrap(), "--json"]);
+    let _ = fs::remove_file(&elf_path);
+    assert!(plain.status.success(), "host failed: {}", String::from_utf8_lossy(&plain.stderr));
+    
+    // The same ID the methods crate computed at build time, and the one proofs of this guest carry
+    let expected = hex::encode(Digest::from(ADDITION_ID).as_bytes());
+    assert_eq!(String::from_utf8_lossy(&plain.stdout).trim(), expected);
+    let parsed: Value = serde_json::from_slice(&json.stdout).expect("host did not print valid JSON");
+    assert_eq!(parsed["image_id"], expected);
+    assert_eq!(parsed["elf_size_bytes"], ADDITION_ELF.len());
+}
+
+#[test]
+fn trace_lists_addition_reads_and_commits() {
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(["--trace", "add", "1.5", "2.25"])
+        .env("RISC0_DEV_MODE", "1")
+        .output()
+        .expect("failed to run host binary");
+    assert!(output.status.success(), "host failed: {}", String::from_utf8_lossy(&output.stderr));
+    
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines, vec![
+        "read   a = 150000",
+        "read   b = 225000",
+        "commit a = 150000",
+        "commit b = 225000",
+        "commit result = 375000",
+        "exit   Halted(0)",
+    ]);
+}
+
+#[test]
+fn random_inputs_prove_and_verify_additions() {
+    let work_dir: PathBuf = std::env::temp_dir().join(format!("risc0_e2e_random_{}", std::process::id()));
+    fs::create_dir_all(&work_dir).unwrap();
+    
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(["--random-inputs", "42", "3", "add"])
+        .current_dir(&work_dir)
+        .env("RISC0_DEV_MODE", "1")
+        .output()
+        .expect("failed to run host binary");
+    let leftover = fs::read_dir(&work_dir).unwrap().count();
+    let _ = fs::remove_dir_all(&work_dir);
+    assert!(output.status.success(), "host failed: {}", String::from_utf8_lossy(&output.stderr));
+    
+    let summary: Value = serde_json::from_slice(&output.stdout).expect("host did not print valid JSON");
+    assert_eq!(summary["seed"], 42);
+    assert_eq!(summary["passed"], 3);
+    assert_eq!(summary["failed"], 0);
+    let runs = summary["runs"].as_array().unwrap();
+    assert_eq!(runs.len(), 3);
+    assert!(runs.iter().all(|run| run["status"] == "verified" && run["inputs"].as_array().unwrap().len() == 2));
+    
+    // Generated proofs are checked and discarded, not left behind
+    assert_eq!(leftover, 0);
+}
+
+#[test]
+fn fail_on_unverified_keeps_verified_proofs() {
+    // v2 only changes what happens on failure: a verified run still saves its proof and exits 0
+    let run = prove(&["--fail-on-unverified", "add", "1", "2"]);
+    assert_verified(&run, ADDITION_ID);
+    assert_eq!(run.output["result"].as_f64().unwrap(), 3.0);
+}
+
+#[test]
+fn output_dir_receives_proof_files() {
+    // A missing directory is created; `prove` reads the receipt back from the reported path
+    let run = prove(&["--output-dir", "data/proofs", "add", "1", "2"]);
+    assert_verified(&run, ADDITION_ID);
+    let proof_path = PathBuf::from(run.output["proof_file_path"].as_str().unwrap());
+    assert_eq!(proof_path.parent(), Some(std::path::Path::new("data/proofs")));
+}
+
+#[test]
+fn journal_out_writes_receipt_journal() {
+    let journal_path = std::env::temp_dir().join(format!("risc0_e2e_journal_{}.bin", std::process::id()));
+    let run = prove(&["--journal-out", journal_path.to_str().unwrap(), "multiply", "2.5", "4.0"]);
+    assert_verified(&run, MULTIPLY_GUEST_ID);
+    
+    let written = fs::read(&journal_path).unwrap();
+    let _ = fs::remove_file(&journal_path);
+    assert_eq!(written, run.receipt.journal.bytes);
+    assert_eq!(run.output["journal_file_path"], journal_path.to_str().unwrap());
+}
+
+#[test]
+fn seal_only_keeps_full_receipt() {
+    // Dev mode yields a fake receipt with no seal: the full receipt is still saved and verifies
+    let run = prove(&["--seal-only", "add", "1", "2"]);
+    assert_verified(&run, ADDITION_ID);
+    assert!(run.output["seal_file_path"].is_null());
+    assert!(run.output["seal_size_bytes"].is_null());
+}
+
+#[test]
+fn snark_reports_journal_digest() {
+    // Dev mode skips Groth16 compression, so the seal is absent but the journal digest is reported
+    let run = prove(&["--snark", "add", "1", "2"]);
+    assert_verified(&run, ADDITION_ID);
+    assert_eq!(run.output["proof_system"], "fake");
+    assert!(run.output["snark_seal_hex"].is_null());
+    assert_eq!(run.output["journal_digest"], hex::encode(run.receipt.journal.digest().as_bytes()));
+}
+
+// Top-level keys of schema version 12, in emission order (see "Host JSON output" in the README)
+const SCHEMA_V12_KEYS: &[&str] = &[
+    "schema_version", "timestamp", "inputs", "result", "result_type", "result_note", "result_value", "overflow", "results", "invertible", "bezout", "invalid_range", "above_min", "below_max", "host_compute_match", "guest_stdout",
+    "receipt_journal", "journal_codec", "image_id", "verification_status", "exit_code", "halt_reason",
+    "peak_memory_mb", "total_cycles", "user_cycles", "segments", "proof_generation_time_ms", "verification_time_ms", "total_time_ms", "proof_seal_hex",
+    "proof_size_bytes", "proof_system", "snark_seal_hex", "journal_digest", "proof_file_path", "journal_file_path", "seal_file_path", "seal_size_bytes", "dev_mode", "prover", "public_key", "signature",
+    "task_id", "auth_timestamp",
+];
+
+#[test]
+fn output_follows_schema_v12() {
+    let work_dir: PathBuf = std::env::temp_dir().join(format!("risc0_e2e_schema_{}", std::process::id()));
+    fs::create_dir_all(&work_dir).unwrap();
+    
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(["add", "1", "2"])
+        .current_dir(&work_dir)
+        .env("RISC0_DEV_MODE", "1")
+        .output()
+        .expect("failed to run host binary");
+    let _ = fs::remove_dir_all(&work_dir);
+    assert!(output.status.success(), "host failed: {}", String::from_utf8_lossy(&output.stderr));
+    
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: Value = serde_json::from_str(&stdout).expect("host did not print valid JSON");
+    assert_eq!(parsed["schema_version"], 12);
+    
+    // Every documented key is present, in order, even when its value is null
+    let keys: Vec<&str> = stdout.lines()
+        .filter_map(|line| line.strip_prefix("  \""))
+        .filter_map(|line| line.split('"').next())
+        .collect();
+    assert_eq!(keys, SCHEMA_V12_KEYS);
+    assert_eq!(parsed["prover"], "local");
+    // The suite runs with RISC0_DEV_MODE=1, and the output says so
+    assert_eq!(parsed["dev_mode"], true);
+    assert!(parsed["host_compute_match"].is_null());
+    assert!(parsed["public_key"].is_null());
+    // Dev mode still executes the guest, so the session stats are real
+    let (total_cycles, user_cycles) = (parsed["total_cycles"].as_u64().unwrap(), parsed["user_cycles"].as_u64().unwrap());
+    assert!(user_cycles > 0 && total_cycles >= user_cycles);
+    assert!(parsed["segments"].as_u64().unwrap() >= 1);
+    assert_eq!(parsed["result_type"], "decimal");
+    assert_eq!(parsed["journal_codec"], "risc0-serde");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn max_memory_mb_stops_proving() {
+    // The host process alone holds more than 1 MB, so proving is stopped with an error
+    let op = Registry::builtin().get("add").unwrap();
+    let mut request = ProveRequest::new(Program::Registered(op, vec!["1".to_string(), "2".to_string()]));
+    request.dev_mode = true;
+    request.max_memory_mb = Some(1);
+    assert!(matches!(host::prove(&request), Err(ProveError::MemoryLimit(_))));
+    
+    // A limit above the sampled peak lets proving finish, and the peak stays under it
+    request.max_memory_mb = Some(1 << 20);
+    let proof = host::prove(&request).expect("proving failed");
+    assert!(proof.peak_memory_mb.is_some_and(|mb| mb > 1 && mb <= 1 << 20));
+    
+    // The binary reports the stopped proof and saves nothing
+    let work_dir = tempfile::tempdir().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(["--max-memory-mb", "1", "add", "1", "2"])
+        .current_dir(work_dir.path())
+        .env("RISC0_DEV_MODE", "1")
+        .output()
+        .expect("failed to run host binary");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Proving aborted: resident memory"), "unexpected stderr: {}", stderr);
+    assert_eq!(fs::read_dir(work_dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn journal_codec_is_fixed_for_builtin_operations() {
+    let (status, _, stderr) = run_host(&["--journal-codec", "raw-le", "add", "1", "2"]);
+    assert!(!status.success());
+    assert!(stderr.contains("add journals are always risc0-serde"), "unexpected stderr: {}", stderr);
+}
+
+// A key directory of its own holding the named signing key, removed when dropped
+fn signing_key_dir(key_id: &str, seed: u8) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let keypair = ed25519_compact::KeyPair::from_seed(ed25519_compact::Seed::new([seed; 32]));
+    host::keys::write_keypair(dir.path(), key_id, &keypair).unwrap();
+    dir
+}
+
+#[test]
+fn authenticated_add_round_trips() {
+    // Library callers pass the signing key's seed after the key ID; the binary loads it from --key-dir
+    let keypair = ed25519_compact::KeyPair::from_seed(ed25519_compact::Seed::new([1; 32]));
+    let run = round_trip("authenticated_add", &["3", "4", "alice", &hex::encode(*keypair.sk.seed())]);
+    assert_eq!(run.proof.image_id, GUEST_AUTHENTICATED_ADD_ID);
+    
+    // ComputationResult { a, b, result, timestamp, task_id, public_key, signature }
+    let journal: AuthenticatedAddJournal = run.receipt.journal.decode().unwrap();
+    assert_eq!((journal.a, journal.b, journal.result), (3, 4, 7));
+    assert!(journal.task_id.starts_with("auth_") && journal.task_id.ends_with("_alice"));
+    assert_eq!(run.decoded.value, 7);
+    assert!(run.decoded.lines.contains(&format!("🏷️  Task ID: {}", journal.task_id)));
+    
+    // The guest signed the computation with the named key, and the proof commits that signature
+    assert_eq!(journal.public_key, *keypair.pk);
+    assert!(journal.signature_is_valid());
+    assert_eq!(run.proof.authenticated.unwrap().signature, journal.signature);
+    
+    // The binary signs with the same key from --key-dir and reports it
+    let key_dir = signing_key_dir("alice", 1);
+    let binary = prove(&["--key-dir", key_dir.path().to_str().unwrap(), "authenticated_add", "3", "4", "alice"]);
+    assert_verified(&binary, GUEST_AUTHENTICATED_ADD_ID);
+    assert_eq!(binary.output["public_key"], hex::encode(keypair.pk.as_ref()));
+    let journal: AuthenticatedAddJournal = binary.receipt.journal.decode().unwrap();
+    assert_eq!(binary.output["task_id"].as_str().unwrap(), journal.task_id);
+    assert_eq!(binary.output["signature"], hex::encode(&journal.signature));
+}
+
+#[test]
+fn authenticated_add_rejects_missing_key() {
+    let key_dir = signing_key_dir("alice", 1);
+    let (status, _, stderr) = run_host(&["--key-dir", key_dir.path().to_str().unwrap(), "authenticated_add", "3", "4", "bob"]);
+    assert!(!status.success());
+    assert!(stderr.contains("signing key 'bob' is not available"), "unexpected stderr: {}", stderr);
+}
+
+#[test]
+fn tagged_calculator_dispatches_add_and_multiply() {
+    let add = prove(&["calculator", r#"{"op": "add", "args": [1.5, 2.25]}"#]);
+    assert_verified(&add, GUEST_CALCULATOR_ID);
+    let (status, op, result): (i64, String, i64) = add.receipt.journal.decode().unwrap();
+    assert_eq!((status, op.as_str(), result), (1, "add", 375000));
+    
+    let multiply = prove(&["calculator", r#"{"op": "multiply", "args": [2.5, 4.0]}"#]);
+    assert_verified(&multiply, GUEST_CALCULATOR_ID);
+    let (status, op, result): (i64, String, i64) = multiply.receipt.journal.decode().unwrap();
+    assert_eq!((status, op.as_str(), result), (1, "multiply", 1000000));
+    assert_eq!(multiply.output["result"].as_f64().unwrap(), 10.0);
+    
+    // Both operations ran under the same image ID
+    assert_eq!(add.output["image_id"], multiply.output["image_id"]);
+}
+
+#[test]
+fn signature_is_verified_inside_the_guest() {
+    use ed25519_compact::{KeyPair, Seed};
+    use risc0_zkvm::sha::{Impl, Sha256};
+    
+    let keypair = KeyPair::from_seed(Seed::new([7u8; 32]));
+    let signature = keypair.sk.sign(b"transfer 100 to bob", None);
+    let inputs = |message: &str| serde_json::json!({
+        "public_key": hex::encode(keypair.pk.as_ref()),
+        "message": message,
+        "signature": hex::encode(signature.as_ref()),
+    }).to_string();
+    
+    let valid = prove(&["verify_signature", &inputs("transfer 100 to bob")]);
+    assert_verified(&valid, GUEST_VERIFY_SIGNATURE_ID);
+    let (status, message_hash, public_key): (i64, Digest, [u8; 32]) = valid.receipt.journal.decode().unwrap();
+    assert_eq!(status, 1);
+    assert_eq!(message_hash, *Impl::hash_bytes(b"transfer 100 to bob"));
+    assert_eq!(&public_key[..], keypair.pk.as_ref());
+    
+    // The same signature over a different message still proves, but reports it invalid
+    let tampered = prove(&["verify_signature", &inputs("transfer 900 to bob")]);
+    assert_verified(&tampered, GUEST_VERIFY_SIGNATURE_ID);
+    assert_eq!(tampered.output["result"].as_f64().unwrap(), 0.0);
+}
+
+// A trivial operation plugged in through the trait: doubles an integer by feeding it twice to the addition guest
+struct Double;
+
+impl Operation for Double {
+    fn name(&self) -> &'static str {
+        "double"
+    }
+    
+    fn description(&self) -> &'static str {
+        "Doubling"
+    }
+    
+    fn symbol(&self) -> &'static str {
+        "2x"
+    }
+    
+    fn usage(&self) -> &'static str {
+        "<a>"
+    }
+    
+    fn elf(&self) -> &'static [u8] {
+        ADDITION_ELF
+    }
+    
+    fn image_id(&self) -> [u32; 8] {
+        ADDITION_ID
+    }
+    
+    fn write_env(&self, builder: &mut ExecutorEnvBuilder<'_>, args: &[String], _reads: &mut Vec<String>) -> Result<(), OperationError> {
+        let a: i64 = args[0].parse()?;
+        builder.write(&a)?.write(&a)?;
+        Ok(())
+    }
+    
+    fn decode_journal(&self, journal: &Journal) -> Result<DecodedJournal, OperationError> {
+        let (_, _, result, _): (i64, i64, i64, u32) = journal.decode()?;
+        Ok(DecodedJournal::new(result as f64, result).line(format!("2x = {}", result)))
+    }
+}
+
+#[test]
+fn state_transitions_chain_through_prev_state() {
+    // Genesis (no --prev-state) -> mint 10 to alice
+    let first = prove(&["state_transition", r#"{"state": {}, "updates": [{"key": "alice", "delta": 10}]}"#]);
+    assert_verified(&first, GUEST_STATE_TRANSITION_ID);
+    let (status, genesis, first_state): (i64, Digest, Digest) = first.receipt.journal.decode().unwrap();
+    assert_eq!((status, genesis), (1, Digest::ZERO));
+    
+    // Chain from the first proof's new state -> transfer 3 to bob
+    let second = prove(&[
+        "--prev-state", &first_state.to_string(),
+        "state_transition", r#"{"state": {"alice": 10}, "updates": [{"key": "alice", "delta": -3}, {"key": "bob", "delta": 3}]}"#,
+    ]);
+    assert_verified(&second, GUEST_STATE_TRANSITION_ID);
+    let (status, prev_state, second_state): (i64, Digest, Digest) = second.receipt.journal.decode().unwrap();
+    assert_eq!((status, prev_state), (2, first_state));
+    assert_ne!(second_state, first_state);
+    
+    // Replaying against a stale prior state is rejected by the guest
+    let stale = prove(&[
+        "--prev-state", &second_state.to_string(),
+        "state_transition", r#"{"state": {"alice": 10}, "updates": [{"key": "alice", "delta": -1}]}"#,
+    ]);
+    let (status, _, _): (i64, Digest, Digest) = stale.receipt.journal.decode().unwrap();
+    assert_eq!(status, -5);
+}
+
+#[test]
+fn registered_operation_round_trips() {
+    let mut registry = Registry::new();
+    registry.register(Double);
+    let op = registry.detect_from_filename("proof_double_1700000000.bin").expect("registered operation not found");
+    
+    let mut builder = ExecutorEnv::builder();
+    op.write_env(&mut builder, &["21".to_string()], &mut Vec::new()).unwrap();
+    let receipt = default_prover().prove_with_opts(builder.build().unwrap(), op.elf(), &ProverOpts::default().with_dev_mode(true)).unwrap().receipt;
+    receipt.verify_with_context(&dev_mode(), op.image_id()).expect("receipt failed verification");
+    
+    let decoded = op.decode_journal(&receipt.journal).unwrap();
+    assert_eq!(decoded.fixed, 42);
+    assert_eq!(decoded.lines, vec!["2x = 42"]);
+}
+
+#[test]
+fn list_proofs_summarizes_directory() {
+    let work_dir: PathBuf = std::env::temp_dir().join(format!("risc0_e2e_list_{}", std::process::id()));
+    fs::create_dir_all(&work_dir).unwrap();
+    
+    for args in [&["add", "1.5", "2.25"][..], &["modexp", "2", "10", "1000"], &["range", "25", "18", "65"]] {
+        let output = Command::new(env!("CARGO_BIN_EXE_host"))
+            .args(args)
+            .current_dir(&work_dir)
+            .env("RISC0_DEV_MODE", "1")
+            .output()
+            .expect("failed to run host binary");
+        assert!(output.status.success(), "host failed: {}", String::from_utf8_lossy(&output.stderr));
+    }
+    fs::write(work_dir.join("notes.txt"), "not a proof").unwrap();
+    
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(["--list-proofs", work_dir.to_str().unwrap()])
+        .output()
+        .expect("failed to run host binary");
+    let _ = fs::remove_dir_all(&work_dir);
+    assert!(output.status.success(), "host failed: {}", String::from_utf8_lossy(&output.stderr));
+    
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let rows: Vec<&str> = stdout.lines().skip(1).collect();
+    assert_eq!(rows.len(), 3, "unexpected table: {}", stdout);
+    
+    // Proofs were generated in order, so the timestamp sort keeps add before modexp before range
+    let add_id = hex::encode(ADDITION_ID.iter().flat_map(|w| w.to_le_bytes()).collect::<Vec<u8>>());
+    assert!(rows[0].starts_with("proof_add_") && rows[0].contains(&add_id) && rows[0].ends_with("3.75"), "{}", rows[0]);
+    assert!(rows[1].starts_with("proof_modexp_") && rows[1].ends_with("24"), "{}", rows[1]);
+    assert!(rows[2].starts_with("proof_range_") && rows[2].ends_with("in [18, 65]: true"), "{}", rows[2]);
+}
+
+#[test]
+fn bundle_packs_proofs_with_manifest() {
+    let work_dir: PathBuf = std::env::temp_dir().join(format!("risc0_e2e_bundle_{}", std::process::id()));
+    fs::create_dir_all(&work_dir).unwrap();
+    
+    let mut proof_files = Vec::new();
+    for args in [&["add", "1.5", "2.25"][..], &["multiply", "2", "3"], &["sqrt", "16"]] {
+        let output = Command::new(env!("CARGO_BIN_EXE_host"))
+            .args(args)
+            .current_dir(&work_dir)
+            .env("RISC0_DEV_MODE", "1")
+            .output()
+            .expect("failed to run host binary");
+        assert!(output.status.success(), "host failed: {}", String::from_utf8_lossy(&output.stderr));
+        let output: Value = serde_json::from_slice(&output.stdout).unwrap();
+        proof_files.push(output["proof_file_path"].as_str().unwrap().to_string());
+    }
+    
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .arg("bundle")
+        .arg("proofs.bundle")
+        .args(&proof_files)
+        .current_dir(&work_dir)
+        .output()
+        .expect("failed to run host binary");
+    let bundle = fs::read(work_dir.join("proofs.bundle"));
+    let _ = fs::remove_dir_all(&work_dir);
+    assert!(output.status.success(), "host failed: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(bundle.unwrap().starts_with(b"R0BUNDLE"));
+    
+    // Manifest rows follow argument order and carry each proof's image ID
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let rows: Vec<&str> = stdout.lines().collect();
+    assert_eq!(rows.len(), 3, "unexpected manifest: {}", stdout);
+    for (row, image_id) in rows.iter().zip([ADDITION_ID, MULTIPLY_GUEST_ID, SQRT_GUEST_ID]) {
+        let image_id = hex::encode(image_id.iter().flat_map(|w| w.to_le_bytes()).collect::<Vec<u8>>());
+        assert!(row.ends_with(&image_id), "{}", row);
+    }
+    
+    // Files that are not saved proofs are refused rather than bundled
+    let output = Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(["bundle", "out.bundle", "Cargo.toml"])
+        .output()
+        .expect("failed to run host binary");
+    assert!(!output.status.success());
+}
+
+#[test]
+#[ignore = "compiles a dynamic guest with cargo risczero"]
+fn dynamic_stdio_echoes_transformed_stdin() {
+    let guest = concat!(env!("CARGO_MANIFEST_DIR"), "/../../examples/stdin_echo.rs");
+    let run = prove(&["--stdio", "dynamic", guest, "hello zkvm"]);
+    
+    assert_eq!(run.output["verification_status"], "verified");
+    assert_eq!(run.output["guest_stdout"], "HELLO ZKVM");
+    let processed: i64 = run.receipt.journal.decode().unwrap();
+    assert_eq!(processed, 10);
+}
+
+#[test]
+#[ignore = "compiles a dynamic guest with cargo risczero"]
+fn dynamic_nonzero_exit_is_reported_and_fails() {
+    let guest = concat!(env!("CARGO_MANIFEST_DIR"), "/../../examples/exit_code.rs");
+    let (status, output, _) = run_host(&["dynamic", guest, "3"]);
+    
+    assert!(!status.success(), "host accepted a guest that exited with code 3");
+    assert_eq!(output["exit_code"], 3);
+    assert_eq!(output["halt_reason"], "halted");
+}
+
+#[test]
+#[ignore = "compiles a dynamic guest with cargo risczero"]
+fn dynamic_empty_journal_verifies_without_result() {
+    let guest = concat!(env!("CARGO_MANIFEST_DIR"), "/../../examples/no_output.rs");
+    let run = prove(&["dynamic", guest, "{}"]);
+    
+    assert_eq!(run.output["verification_status"], "verified");
+    assert!(run.output["result"].is_null());
+    assert_eq!(run.output["result_note"], "no output committed");
+    assert!(run.receipt.journal.bytes.is_empty());
+}
+
+#[test]
+#[ignore = "compiles a dynamic guest with cargo risczero"]
+fn dynamic_typed_inputs_are_read_individually() {
+    let guest = concat!(env!("CARGO_MANIFEST_DIR"), "/../../examples/typed_inputs.rs");
+    let run = prove(&["dynamic", guest, r#"{"input_mode": "typed", "values": [{"i64": -3}, {"u64": 4}, {"fixed": 2.5}, {"string": "zkvm"}]}"#]);
+    
+    assert_eq!(run.output["verification_status"], "verified");
+    let result: i64 = run.receipt.journal.decode().unwrap();
+    assert_eq!(result, 11);
+}
+
+#[test]
+#[ignore = "compiles a dynamic guest with cargo risczero"]
+fn dynamic_result_envelope_is_decoded_to_json() {
+    let guest = concat!(env!("CARGO_MANIFEST_DIR"), "/../../examples/array_stats.rs");
+    let run = prove(&["dynamic", guest, "[4, 8, 15, 16, 23, 42]"]);
+    
+    assert_eq!(run.output["verification_status"], "verified");
+    assert_eq!(run.output["result_value"], serde_json::json!({"count": 6, "min": 4.0, "max": 42.0, "mean": 18.0}));
+    assert!(run.output["result"].is_null());
+    assert_eq!(run.output["result_note"], "structured result in result_value");
+}
+
+// What examples/composition.rs commits: (status, inner image ID, (base, exponent, modulus, result), (min, max))
+type CompositionJournal = (i64, Digest, (u64, u64, u64, u64), (u64, u64));
+
+#[test]
+#[ignore = "compiles a dynamic guest with cargo risczero"]
+fn dynamic_composition_verifies_inner_receipt() {
+    // Prove modexp first, then hand its receipt to the outer guest as an assumption
+    let inner = prove(&["modexp", "4", "13", "497"]);
+    let inner_path = std::env::temp_dir().join(format!("risc0_e2e_inner_{}.bin", std::process::id()));
+    fs::write(&inner_path, bincode::serialize(&inner.receipt).unwrap()).unwrap();
+    
+    let guest = concat!(env!("CARGO_MANIFEST_DIR"), "/../../examples/composition.rs");
+    let inputs = serde_json::json!({
+        "inner_image_id": inner.output["image_id"],
+        "base": 4, "exponent": 13, "modulus": 497, "result": 445,
+        "min": 400, "max": 500,
+    }).to_string();
+    let run = prove(&["--assumption", inner_path.to_str().unwrap(), "dynamic", guest, &inputs]);
+    let _ = fs::remove_file(&inner_path);
+    
+    assert_eq!(run.output["verification_status"], "verified");
+    assert_eq!(run.output["result"].as_f64().unwrap(), 1.0);
+    // The outer journal pins the inner program: a verifier compares it with the modexp image ID
+    let (status, inner_image_id, modexp, bounds): CompositionJournal = run.receipt.journal.decode().unwrap();
+    assert_eq!(status, 1);
+    assert_eq!(inner_image_id, Digest::from(MODEXP_GUEST_ID));
+    assert_eq!(modexp, (4, 13, 497, 445));
+    assert_eq!(bounds, (400, 500));
+}
+
+#[test]
+fn assumption_file_chains_into_outer_proof() {
+    let work_dir = tempfile::tempdir().unwrap();
+    let run_host = |args: &[&str]| Command::new(env!("CARGO_BIN_EXE_host"))
+        .args(args)
+        .current_dir(work_dir.path())
+        .env("RISC0_DEV_MODE", "1")
+        .output()
+        .expect("failed to run host binary");
+    
+    // One invocation saves the inner modexp proof; the next loads that file as an assumption
+    let inner = run_host(&["modexp", "4", "13", "497"]);
+    assert!(inner.status.success(), "host failed: {}", String::from_utf8_lossy(&inner.stderr));
+    let inner: Value = serde_json::from_slice(&inner.stdout).expect("host did not print valid JSON");
+    let inner_path = work_dir.path().join(inner["proof_file_path"].as_str().unwrap());
+    let inner_path = inner_path.to_str().unwrap();
+    
+    // The outer guest calls `env::verify` on the modexp claim its inputs describe
+    let guest_path = work_dir.path().join("composition.elf");
+    fs::write(&guest_path, GUEST_COMPOSITION_ELF).unwrap();
+    let guest_path = guest_path.to_str().unwrap();
+    let inputs = |result: u64| serde_json::json!({
+        "inner_image_id": inner["image_id"],
+        "base": 4, "exponent": 13, "modulus": 497, "result": result,
+        "min": 400, "max": 500,
+    }).to_string();
+    
+    let outer = run_host(&["--assumption", inner_path, "precompiled", guest_path, &inputs(445)]);
+    assert!(outer.status.success(), "host failed: {}", String::from_utf8_lossy(&outer.stderr));
+    assert!(String::from_utf8_lossy(&outer.stderr).contains("Loaded assumption receipt"));
+    let outer: Value = serde_json::from_slice(&outer.stdout).expect("host did not print valid JSON");
+    assert_eq!(outer["verification_status"], "verified");
+    assert_eq!(outer["result"].as_f64().unwrap(), 1.0);
+    let journal = Journal::new(serde_json::from_value(outer["receipt_journal"].clone()).unwrap());
+    let (status, inner_image_id, modexp, bounds): CompositionJournal = journal.decode().unwrap();
+    assert_eq!((status, inner_image_id), (1, Digest::from(MODEXP_GUEST_ID)));
+    assert_eq!((modexp, bounds), ((4, 13, 497, 445), (400, 500)));
+    
+    // Without the assumption, or with a claim the inner proof does not make, there is nothing to
+    // resolve the `env::verify` against and proving fails
+    assert!(!run_host(&["precompiled", guest_path, &inputs(445)]).status.success());
+    assert!(!run_host(&["--assumption", inner_path, "precompiled", guest_path, &inputs(444)]).status.success());
+    
+    // An assumption that is not a saved proof is rejected before proving
+    fs::write(work_dir.path().join("proof_bogus_1.bin"), b"not a receipt").unwrap();
+    let bogus = work_dir.path().join("proof_bogus_1.bin");
+    let rejected = run_host(&["--assumption", bogus.to_str().unwrap(), "precompiled", guest_path, &inputs(445)]);
+    assert!(!rejected.status.success());
+    assert!(String::from_utf8_lossy(&rejected.stderr).contains("Invalid assumption"));
+    
+    // The library takes the same file: load it and prove the outer guest in-process
+    let mut request = ProveRequest::new(Program::Guest { operation: "precompiled", elf: GUEST_COMPOSITION_ELF.to_vec(), inputs: inputs(445) });
+    request.assumptions.push(host::proofs::load_assumption(inner_path).unwrap());
+    request.dev_mode = true;
+    let proof = host::prove(&request).unwrap();
+    assert_eq!(proof.verification, Ok(()));
+    assert_eq!(proof.receipt.journal.bytes, journal.bytes);
+}
+
+#[test]
+fn regenerated_key_loads_with_regenerate_pubkey() {
+    let work_dir: PathBuf = std::env::temp_dir().join(format!("risc0_e2e_keys_{}", std::process::id()));
+    fs::create_dir_all(&work_dir).unwrap();
+    let run = |binary: &str, args: &[&str]| Command::new(binary)
+        .args(args)
+        .current_dir(&work_dir)
+        .output()
+        .expect("failed to run key tool");
+    
+    // One tool writes the key pair, the other re-derives the public key from the private key file
+    let generated = run(env!("CARGO_BIN_EXE_host"), &["regenerate_key"]);
+    assert!(generated.status.success(), "regenerate_key failed: {}", String::from_utf8_lossy(&generated.stderr));
+    let key_dir = work_dir.join(host::keys::KEY_DIR);
+    let public_key = fs::read_to_string(host::keys::public_key_path(&key_dir, "default")).unwrap();
+    
+    let rederived = run(env!("CARGO_BIN_EXE_regenerate_pubkey"), &[]);
+    let loaded = host::keys::load_keypair(&key_dir, "default");
+    let rewritten = fs::read_to_string(host::keys::public_key_path(&key_dir, "default")).unwrap();
+    let _ = fs::remove_dir_all(&work_dir);
+    
+    assert!(rederived.status.success(), "regenerate_pubkey failed: {}", String::from_utf8_lossy(&rederived.stderr));
+    assert!(String::from_utf8_lossy(&rederived.stdout).contains("already matches"));
+    assert_eq!(rewritten, public_key);
+    assert_eq!(hex::encode(loaded.unwrap().pk.as_ref()), public_key);
+}
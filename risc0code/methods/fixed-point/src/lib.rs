@@ -0,0 +1,16 @@
+// Fixed-point scale shared by the host, the verifier's decoders and every decimal guest.
+// Decimals travel as value * SCALE (5 decimal places). Guests depend on this crate instead of
+// declaring their own copy, so a guest cannot be compiled with a scale the host does not use.
+#![no_std]
+
+pub const SCALE: i64 = 100_000;
+
+// The same scale, for guests that compute in floating point
+pub const SCALE_F64: f64 = SCALE as f64;
+
+// The ML guests commit their f64 results at coarser scales, which the host divides back out
+// (`methods::journal::MlJournal::scale`): linear_regression and neural_network commit result * 1000
+pub const REGRESSION_SCALE: i64 = 1_000;
+
+// logistic_regression commits its probability * 10000
+pub const PROBABILITY_SCALE: i64 = 10_000;
@@ -0,0 +1,99 @@
+// A/B test significance with zero-knowledge proof
+// This runs a two-sided two-proportion z-test on private conversion counts and reveals only
+// whether the difference between the groups is significant at a public confidence level
+use risc0_zkvm::guest::env;
+
+// Bound sample sizes so the exact integer test below cannot overflow u128
+const MAX_SAMPLES: u64 = 1_000_000;
+
+// Two-sided critical values squared, scaled by 10000: (confidence %, z²·10000)
+const CRITICAL_Z_SQUARED: [(u64, u128); 3] = [(90, 27055), (95, 38415), (99, 66349)];
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "control": {"conversions": 120, "samples": 2400},
+    //   "treatment": {"conversions": 180, "samples": 2400},
+    //   "confidence": 95                                       // public level: 90, 95 or 99
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, confidence) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(control),
+            Some(treatment),
+            Some(confidence)
+        ) = (
+            parsed.get("control").and_then(group),
+            parsed.get("treatment").and_then(group),
+            parsed.get("confidence").and_then(|v| v.as_u64())
+        ) {
+            match CRITICAL_Z_SQUARED.iter().find(|(level, _)| *level == confidence) {
+                Some(&(_, critical)) => {
+                    if control.1 == 0 || treatment.1 == 0 || control.1 > MAX_SAMPLES || treatment.1 > MAX_SAMPLES {
+                        (-1, confidence) // Empty or oversized group
+                    } else if control.0 > control.1 || treatment.0 > treatment.1 {
+                        (-2, confidence) // More conversions than samples
+                    } else {
+                        (if is_significant(control, treatment, critical) { 1 } else { 0 }, confidence)
+                    }
+                },
+                None => (-5, confidence) // Unsupported confidence level
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit whether the difference is significant (1), not significant (0) or the input was invalid (negative),
+    // and the confidence level it was tested at
+    // Note: Conversion counts and sample sizes remain private!
+    env::commit(&status);
+    env::commit(&confidence);
+}
+
+// (conversions, samples) for one group
+fn group(value: &serde_json::Value) -> Option<(u64, u64)> {
+    Some((value.get("conversions")?.as_u64()?, value.get("samples")?.as_u64()?))
+}
+
+// Pooled two-proportion z-test, rearranged to avoid division and square roots:
+// z² = D²·N / (n1·n2·C·(N−C)) with D = c2·n1 − c1·n2, C = c1 + c2, N = n1 + n2
+fn is_significant((c1, n1): (u64, u64), (c2, n2): (u64, u64), critical_z_squared: u128) -> bool {
+    let (c1, n1, c2, n2) = (c1 as u128, n1 as u128, c2 as u128, n2 as u128);
+    let (conversions, samples) = (c1 + c2, n1 + n2);
+    if conversions == 0 || conversions == samples {
+        return false; // No variance: both groups converted at 0% or 100%
+    }
+    let difference = (c2 * n1).abs_diff(c1 * n2);
+    difference * difference * samples * 10000 >= critical_z_squared * n1 * n2 * conversions * (samples - conversions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const Z95: u128 = 38415;
+    const Z99: u128 = 66349;
+
+    #[test]
+    fn test_clearly_significant() {
+        // 5% vs 7.5% conversion over 2400 samples each: z ≈ 3.58
+        assert!(is_significant((120, 2400), (180, 2400), Z95));
+        assert!(is_significant((120, 2400), (180, 2400), Z99));
+        // Direction does not matter for a two-sided test
+        assert!(is_significant((180, 2400), (120, 2400), Z95));
+    }
+
+    #[test]
+    fn test_insignificant() {
+        // 5% vs 5.5% over 1000 samples each: z ≈ 0.50
+        assert!(!is_significant((50, 1000), (55, 1000), Z95));
+        // z ≈ 2.35 clears 95% but not 99%
+        assert!(is_significant((100, 2000), (135, 2000), Z95));
+        assert!(!is_significant((100, 2000), (135, 2000), Z99));
+        assert!(!is_significant((0, 100), (0, 100), Z95));
+    }
+}
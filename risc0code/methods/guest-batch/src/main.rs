@@ -0,0 +1,69 @@
+// Batched fixed-point addition or multiplication with zero-knowledge proof
+// This proves many pairs in one execution, so prover setup is paid once for the whole batch
+// instead of once per pair. Each pair is checked for overflow exactly as the add and multiply
+// guests do, and reports it through its own flag.
+//
+// Journal layout (risc0-serde, all little-endian):
+//   bytes  0..4   operation  u32, 0 = add, 1 = multiply
+//   bytes  4..8   count      u32, number of entries
+//   then per pair, in input order, 28 bytes laid out like the add/multiply journals:
+//                 a i64, b i64, result i64 (0 unless flag is 0), flag u32 (0 = ok, 1 = overflow)
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
+
+// Operation codes
+const ADD: u32 = 0;
+const MULTIPLY: u32 = 1;
+
+// Journal flag values
+const OK: u32 = 0;
+const OVERFLOW: u32 = 1;
+
+fn main() {
+    // Read the operation code and the fixed-point pairs (scaled integers) from the host
+    let operation: u32 = env::read();
+    let pairs: Vec<(i64, i64)> = env::read();
+
+    let entries: Vec<(i64, i64, i64, u32)> = pairs
+        .iter()
+        .map(|&(a, b)| {
+            let (result, flag) = match apply(operation, a, b) {
+                Some(result) => (result, OK),
+                None => (0, OVERFLOW),
+            };
+            (a, b, result, flag)
+        })
+        .collect();
+
+    // Commit the operation and every pair with its result and overflow flag for verification
+    env::commit(&operation);
+    env::commit(&entries);
+}
+
+// The fixed-point result of one pair, or None when it does not fit in an i64
+fn apply(operation: u32, a: i64, b: i64) -> Option<i64> {
+    match operation {
+        ADD => a.checked_add(b),
+        MULTIPLY => a.checked_mul(b).map(|product| product / SCALE),
+        _ => panic!("unknown batch operation {}", operation),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_and_multiply_pairs() {
+        assert_eq!(apply(ADD, 150000, 225000), Some(375000));
+        assert_eq!(apply(MULTIPLY, 250000, 400000), Some(1000000));
+    }
+
+    #[test]
+    fn test_overflow_is_flagged_per_pair() {
+        assert_eq!(apply(ADD, i64::MAX, 1), None);
+        assert_eq!(apply(MULTIPLY, 9999999900000, 9999999900000), None);
+    }
+}
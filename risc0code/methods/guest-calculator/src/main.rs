@@ -0,0 +1,89 @@
+// Tagged calculator with zero-knowledge proof
+// One guest dispatches on an "op" tag so several arithmetic operations share a single image ID
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "op": "add",        // add, sub, multiply or divide
+    //   "args": [1.5, 2.25]
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, op, result) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(op),
+            Some(args)
+        ) = (
+            parsed.get("op").and_then(|v| v.as_str()),
+            parsed.get("args").and_then(|v| v.as_array())
+        ) {
+            // Convert arguments to fixed point, as the dedicated arithmetic guests expect
+            let operands: Result<Vec<i64>, _> = args
+                .iter()
+                .map(|v| v.as_f64().map(|a| (a * SCALE as f64).round() as i64).ok_or("Invalid argument"))
+                .collect();
+            
+            let (status, result) = match operands {
+                Ok(operands) => match operands.as_slice() {
+                    [a, b] => match evaluate(op, *a, *b) {
+                        Ok(result) => (1, result),
+                        Err(code) => (code, 0),
+                    },
+                    _ => (-1, 0) // Every operation takes exactly two arguments
+                },
+                _ => (-2, 0) // Invalid argument data
+            };
+            (status, op.to_string(), result)
+        } else {
+            (-3, String::new(), 0) // Missing required fields
+        }
+    } else {
+        (-4, String::new(), 0) // JSON parse error
+    };
+    
+    // Commit the status (1 on success, negative on error), the op tag and the fixed-point result
+    env::commit(&status);
+    env::commit(&op);
+    env::commit(&result);
+}
+
+// Dispatch on the op tag; fixed-point products and quotients are rescaled like the dedicated guests
+fn evaluate(op: &str, a: i64, b: i64) -> Result<i64, i64> {
+    match op {
+        "add" => a.checked_add(b).ok_or(-7),
+        "sub" => a.checked_sub(b).ok_or(-7),
+        "multiply" => i64::try_from(a as i128 * b as i128 / SCALE as i128).map_err(|_| -7),
+        "divide" if b == 0 => Err(-6),
+        "divide" => i64::try_from(a as i128 * SCALE as i128 / b as i128).map_err(|_| -7),
+        _ => Err(-5), // Unknown op tag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispatch_add_and_multiply() {
+        // 1.5 + 2.25 = 3.75 and 2.5 * 4.0 = 10.0, matching the add and multiply guests
+        assert_eq!(evaluate("add", 150000, 225000), Ok(375000));
+        assert_eq!(evaluate("multiply", 250000, 400000), Ok(1000000));
+    }
+
+    #[test]
+    fn test_sub_and_divide() {
+        assert_eq!(evaluate("sub", 100000, 250000), Ok(-150000));
+        assert_eq!(evaluate("divide", 100000, 300000), Ok(33333));
+        assert_eq!(evaluate("divide", 100000, 0), Err(-6));
+    }
+
+    #[test]
+    fn test_unknown_op_and_overflow() {
+        assert_eq!(evaluate("pow", 1, 2), Err(-5));
+        assert_eq!(evaluate("add", i64::MAX, 1), Err(-7));
+    }
+}
@@ -0,0 +1,83 @@
+// Instrument calibration check with zero-knowledge proof
+// This proves a private measurement lies within a tolerance of a public reference value
+// without revealing the measurement itself
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Basis points in 100%
+const BPS: i128 = 10000;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "measured": 99.93,        // private instrument reading
+    //   "reference": 100.0,       // public reference standard
+    //   "tolerance_bps": 10       // allowed deviation in basis points of the reference (10 = 0.1%)
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, reference_fixed, tolerance_bps) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(measured),
+            Some(reference),
+            Some(tolerance)
+        ) = (
+            parsed.get("measured"),
+            parsed.get("reference"),
+            parsed.get("tolerance_bps")
+        ) {
+            match (measured.as_f64(), reference.as_f64(), tolerance.as_u64()) {
+                (Some(measured), Some(reference), Some(tolerance_bps)) if measured.is_finite() && reference.is_finite() => {
+                    let measured_fixed = (measured * SCALE).round() as i64;
+                    let reference_fixed = (reference * SCALE).round() as i64;
+                    let within = within_tolerance(measured_fixed, reference_fixed, tolerance_bps);
+                    (if within { 1 } else { 0 }, reference_fixed, tolerance_bps)
+                },
+                _ => (-2, 0, 0) // Invalid measurement, reference or tolerance
+            }
+        } else {
+            (-3, 0, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0, 0) // JSON parse error
+    };
+    
+    // Commit whether the measurement is within tolerance (1), outside it (0) or the input was invalid (negative),
+    // plus the public reference (fixed point) and tolerance it was checked against
+    // Note: The measurement itself remains private!
+    env::commit(&status);
+    env::commit(&reference_fixed);
+    env::commit(&tolerance_bps);
+}
+
+// |measured - reference| <= reference * tolerance_bps / 10000, compared exactly in integers
+fn within_tolerance(measured: i64, reference: i64, tolerance_bps: u64) -> bool {
+    let deviation = (measured as i128 - reference as i128).abs();
+    deviation * BPS <= (reference as i128).abs() * tolerance_bps as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tolerance_boundary() {
+        // 10 bps of 100.00000 is exactly 0.10000
+        assert!(within_tolerance(10010000, 10000000, 10));
+        assert!(within_tolerance(9990000, 10000000, 10));
+        assert!(!within_tolerance(10010001, 10000000, 10));
+        assert!(!within_tolerance(9989999, 10000000, 10));
+    }
+
+    #[test]
+    fn test_clearly_inside_and_outside() {
+        assert!(within_tolerance(9999300, 10000000, 10));
+        assert!(!within_tolerance(10500000, 10000000, 10));
+        // Negative references scale by magnitude; a zero reference demands an exact match
+        assert!(within_tolerance(-5002000, -5000000, 5));
+        assert!(within_tolerance(0, 0, 100));
+        assert!(!within_tolerance(1, 0, 100));
+    }
+}
@@ -0,0 +1,98 @@
+// Proof composition: a modexp result, range-checked
+// This is examples/composition.rs built into the workspace, so composition can be proven without
+// compiling a dynamic guest. It verifies a modexp receipt with `env::verify`, then range-checks the
+// result that receipt attests to. The host supplies the inner receipt with `--assumption`; proving
+// fails unless an assumption matches the claimed image ID and journal exactly.
+//
+// The inner image ID arrives as an input, so it is committed: whoever verifies this receipt must
+// compare it with the modexp guest's image ID, or a receipt from any other program could stand in.
+//
+// Journal layout (risc0-serde):
+//   status          i64, 1 = modexp result in range, 0 = out of range, negative on error
+//   inner_image_id  Digest, the image ID the inner receipt was verified against (zero on error)
+//   modexp          (u64, u64, u64, u64), base, exponent, modulus and result (zeros on error)
+//   bounds          (u64, u64), min and max (zeros on error)
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::Digest;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "inner_image_id": "<64 hex chars>",                        // image_id of the --assumption proof
+    //   "base": 4, "exponent": 13, "modulus": 497, "result": 445,  // that proof's journal
+    //   "min": 400,
+    //   "max": 500
+    // }
+    let inputs_json: String = env::read();
+
+    let (status, inner_image_id, modexp, bounds) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        let field = |name: &str| parsed.get(name).and_then(|v| v.as_u64());
+        if let (Some(image_id), Some(base), Some(exponent), Some(modulus), Some(result), Some(min), Some(max)) = (
+            parsed.get("inner_image_id").and_then(|v| v.as_str()),
+            field("base"),
+            field("exponent"),
+            field("modulus"),
+            field("result"),
+            field("min"),
+            field("max")
+        ) {
+            match decode_digest(image_id) {
+                Some(image_id) => {
+                    let modexp = (base, exponent, modulus, result);
+                    // Adds the inner claim as an assumption of this receipt; execution stops here if
+                    // the host has no receipt proving it
+                    env::verify(image_id, &modexp_journal(modexp)).unwrap();
+                    ((min <= result && result <= max) as i64, image_id, modexp, (min, max))
+                }
+                None => (-3, Digest::ZERO, (0, 0, 0, 0), (0, 0)) // inner_image_id is not 32 bytes of hex
+            }
+        } else {
+            (-1, Digest::ZERO, (0, 0, 0, 0), (0, 0)) // Missing required fields
+        }
+    } else {
+        (-2, Digest::ZERO, (0, 0, 0, 0), (0, 0)) // JSON parse error
+    };
+
+    // Commit the outcome and the full statement it is about
+    env::commit(&status);
+    env::commit(&inner_image_id);
+    env::commit(&modexp);
+    env::commit(&bounds);
+}
+
+// The journal exactly as the modexp guest commits it: four u64s as risc0 serde words
+fn modexp_journal(modexp: (u64, u64, u64, u64)) -> Vec<u8> {
+    risc0_zkvm::serde::to_vec(&modexp)
+        .unwrap()
+        .iter()
+        .flat_map(|word| word.to_le_bytes())
+        .collect()
+}
+
+// The host prints image IDs as the hex of their 32 bytes
+fn decode_digest(hex_digest: &str) -> Option<Digest> {
+    let bytes: [u8; 32] = hex::decode(hex_digest).ok()?.try_into().ok()?;
+    Some(Digest::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modexp_journal_matches_guest_commits() {
+        // Each u64 is two little-endian u32 words under risc0 serde
+        let journal = modexp_journal((4, 13, 497, 445));
+        assert_eq!(journal.len(), 32);
+        assert_eq!(&journal[..8], &4u64.to_le_bytes());
+        assert_eq!(&journal[24..], &445u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_decode_digest_requires_32_bytes() {
+        let hex_digest = "ab".repeat(32);
+        assert_eq!(decode_digest(&hex_digest), Some(Digest::from([0xab; 32])));
+        assert_eq!(decode_digest("abcd"), None);
+        assert_eq!(decode_digest(&"zz".repeat(32)), None);
+    }
+}
@@ -0,0 +1,158 @@
+// Fixed-point 2D convolution with zero-knowledge proof
+// This convolves a private input matrix with a private kernel ('valid' padding) as a building block for CNN layers
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+use serde_json::Value;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
+
+// Bound matrix dimensions to keep cycle counts predictable
+const MAX_DIMENSION: usize = 128;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "input": [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+    //   "kernel": [[1, 0], [0, -1]],
+    //   "commit_output": true   // optional, commit the full output instead of only its hash
+    // }
+    let inputs_json: String = env::read();
+    
+    let (rows, cols, output, output_hash) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(input),
+            Some(kernel)
+        ) = (
+            parsed.get("input"),
+            parsed.get("kernel")
+        ) {
+            let commit_output = parsed.get("commit_output").and_then(|v| v.as_bool()).unwrap_or(false);
+            
+            match (parse_matrix(input), parse_matrix(kernel)) {
+                (Some(input), Some(kernel)) => {
+                    match convolve_valid(&input, &kernel) {
+                        Some(output) => {
+                            let rows = output.len() as i64;
+                            let cols = output[0].len() as i64;
+                            let flat: Vec<i64> = output.into_iter().flatten().collect();
+                            let output_hash = hash_output(rows, cols, &flat);
+                            (rows, cols, if commit_output { flat } else { Vec::new() }, output_hash)
+                        },
+                        None => (-1, 0, Vec::new(), Digest::ZERO) // Kernel larger than input
+                    }
+                },
+                _ => (-2, 0, Vec::new(), Digest::ZERO) // Invalid or ragged matrices
+            }
+        } else {
+            (-3, 0, Vec::new(), Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, 0, Vec::new(), Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit the output dimensions (rows is negative on error), the fixed-point output
+    // (empty unless requested) and the hash of the output
+    // Note: The input matrix and kernel remain private!
+    env::commit(&rows);
+    env::commit(&cols);
+    env::commit(&output);
+    env::commit(&output_hash);
+}
+
+// Parse a non-empty rectangular matrix of numbers into fixed-point values
+fn parse_matrix(value: &Value) -> Option<Vec<Vec<i64>>> {
+    let rows = value.as_array()?;
+    if rows.is_empty() || rows.len() > MAX_DIMENSION {
+        return None;
+    }
+    
+    let mut matrix = Vec::with_capacity(rows.len());
+    for row in rows {
+        let row: Option<Vec<i64>> = row
+            .as_array()?
+            .iter()
+            .map(|v| v.as_f64().map(|x| (x * SCALE as f64).round() as i64))
+            .collect();
+        let row = row?;
+        if row.is_empty() || row.len() > MAX_DIMENSION {
+            return None;
+        }
+        if matrix.first().is_some_and(|first: &Vec<i64>| first.len() != row.len()) {
+            return None; // Ragged matrix
+        }
+        matrix.push(row);
+    }
+    
+    Some(matrix)
+}
+
+// 'Valid' 2D convolution as used in CNN layers (cross-correlation, kernel not flipped).
+// Products of two fixed-point values are accumulated in i128 and rescaled once per output cell.
+fn convolve_valid(input: &[Vec<i64>], kernel: &[Vec<i64>]) -> Option<Vec<Vec<i64>>> {
+    let (input_rows, input_cols) = (input.len(), input[0].len());
+    let (kernel_rows, kernel_cols) = (kernel.len(), kernel[0].len());
+    if kernel_rows > input_rows || kernel_cols > input_cols {
+        return None;
+    }
+    
+    let output_rows = input_rows - kernel_rows + 1;
+    let output_cols = input_cols - kernel_cols + 1;
+    let mut output = vec![vec![0i64; output_cols]; output_rows];
+    
+    for r in 0..output_rows {
+        for c in 0..output_cols {
+            let mut acc: i128 = 0;
+            for kr in 0..kernel_rows {
+                for kc in 0..kernel_cols {
+                    acc += input[r + kr][c + kc] as i128 * kernel[kr][kc] as i128;
+                }
+            }
+            output[r][c] = (acc / SCALE as i128) as i64;
+        }
+    }
+    
+    Some(output)
+}
+
+// SHA-256 over rows, cols and the row-major fixed-point output (all little-endian i64)
+fn hash_output(rows: i64, cols: i64, flat: &[i64]) -> Digest {
+    let mut bytes = Vec::with_capacity(16 + flat.len() * 8);
+    bytes.extend_from_slice(&rows.to_le_bytes());
+    bytes.extend_from_slice(&cols.to_le_bytes());
+    for value in flat {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    *Impl::hash_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_convolve_known_kernel() {
+        let input = parse_matrix(&json!([[1, 2, 3], [4, 5, 6], [7, 8, 9]])).unwrap();
+        let kernel = parse_matrix(&json!([[1, 0], [0, -1]])).unwrap();
+        let output = convolve_valid(&input, &kernel).unwrap();
+        
+        // Each cell is top-left minus bottom-right: always -4
+        assert_eq!(output, vec![vec![-4 * SCALE, -4 * SCALE], vec![-4 * SCALE, -4 * SCALE]]);
+    }
+
+    #[test]
+    fn test_convolve_fractional_kernel() {
+        let input = parse_matrix(&json!([[2, 4], [6, 8]])).unwrap();
+        let kernel = parse_matrix(&json!([[0.25, 0.25], [0.25, 0.25]])).unwrap();
+        assert_eq!(convolve_valid(&input, &kernel).unwrap(), vec![vec![5 * SCALE]]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_shapes() {
+        assert!(parse_matrix(&json!([[1, 2], [3]])).is_none());
+        let input = parse_matrix(&json!([[1, 2]])).unwrap();
+        let kernel = parse_matrix(&json!([[1], [1]])).unwrap();
+        assert!(convolve_valid(&input, &kernel).is_none());
+    }
+}
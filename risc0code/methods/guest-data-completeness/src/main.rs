@@ -0,0 +1,122 @@
+// Data-quality completeness check with zero-knowledge proof
+// This proves every record populates all required fields without revealing the records
+//
+// The required fields are what the claim is about, so they are committed: verifiers must check them,
+// or a proof that only required a field every record happens to have could stand in.
+//
+// Journal layout (risc0-serde):
+//   status           i64, 1 = complete, 0 = missing values, negative on error
+//   required_fields  Digest, SHA-256 of the field names, see `hash_names` (zero on error)
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+use serde_json::Value;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "records": [{"id": 1, "email": "a@b.c"}, {"id": 2, "email": "d@e.f"}],
+    //   "required_fields": ["id", "email"]
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, required_fields_hash): (i64, Digest) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(records),
+            Some(required_fields)
+        ) = (
+            parsed.get("records").and_then(|v| v.as_array()),
+            parsed.get("required_fields").and_then(|v| v.as_array())
+        ) {
+            // Convert required fields to Vec<&str>
+            let fields: Result<Vec<&str>, _> = required_fields
+                .iter()
+                .map(|v| v.as_str().ok_or("Invalid field name"))
+                .collect();
+            
+            match fields {
+                Ok(fields) => {
+                    if !fields.is_empty() {
+                        (if all_records_complete(records, &fields) { 1 } else { 0 }, hash_names(&fields))
+                    } else {
+                        (-1, Digest::ZERO) // No required fields
+                    }
+                },
+                _ => (-2, Digest::ZERO) // Invalid field list
+            }
+        } else {
+            (-3, Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit whether the dataset is complete (1), incomplete (0) or invalid (negative), along with
+    // the required fields it was checked against
+    // Note: The records themselves remain private!
+    env::commit(&status);
+    env::commit(&required_fields_hash);
+}
+
+// SHA-256 over each name's length (u32 little-endian) and UTF-8 bytes, in input order; the host
+// computes the same digest with `methods::journal::name_list_digest`
+fn hash_names(names: &[&str]) -> Digest {
+    let mut bytes = Vec::new();
+    for name in names {
+        bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(name.as_bytes());
+    }
+    *Impl::hash_bytes(&bytes)
+}
+
+// A record is complete when it is an object and every required field is present,
+// non-null and not an empty string
+fn all_records_complete(records: &[Value], fields: &[&str]) -> bool {
+    records.iter().all(|record| {
+        match record.as_object() {
+            Some(object) => fields.iter().all(|field| {
+                match object.get(*field) {
+                    None | Some(Value::Null) => false,
+                    Some(Value::String(text)) => !text.is_empty(),
+                    Some(_) => true,
+                }
+            }),
+            None => false,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_complete_dataset() {
+        let records = json!([
+            {"id": 1, "email": "a@example.com", "age": 30},
+            {"id": 2, "email": "b@example.com", "age": 0}
+        ]);
+        assert!(all_records_complete(records.as_array().unwrap(), &["id", "email", "age"]));
+    }
+
+    #[test]
+    fn test_incomplete_dataset() {
+        let missing = json!([{"id": 1, "email": "a@example.com"}, {"id": 2}]);
+        assert!(!all_records_complete(missing.as_array().unwrap(), &["id", "email"]));
+        
+        let null_value = json!([{"id": 1, "email": null}]);
+        assert!(!all_records_complete(null_value.as_array().unwrap(), &["id", "email"]));
+        
+        let empty_string = json!([{"id": 1, "email": ""}]);
+        assert!(!all_records_complete(empty_string.as_array().unwrap(), &["id", "email"]));
+        
+        let not_object = json!([[1, "a@example.com"]]);
+        assert!(!all_records_complete(not_object.as_array().unwrap(), &["id"]));
+    }
+
+    #[test]
+    fn test_hash_names_matches_host_digest() {
+        // Same vector as methods::journal's test of name_list_digest
+        assert_eq!(hash_names(&["id", "email"]).to_string(), "a6bd3eebba1f52619b10b331777f9d3418e9bf1a87ad4a2e73355e263c5e5330");
+    }
+}
@@ -0,0 +1,81 @@
+// Debt-to-income eligibility check with zero-knowledge proof
+// This proves a borrower's debt-to-income ratio is within a lender's public limit
+// without revealing their debt or income
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Basis points in 100%
+const BPS: i128 = 10000;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "monthly_debt": 1800.0,      // private recurring debt payments
+    //   "monthly_income": 5000.0,    // private gross monthly income
+    //   "max_ratio_bps": 4300        // public limit in basis points (4300 = 43%)
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, max_ratio_bps) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(debt),
+            Some(income),
+            Some(max_ratio)
+        ) = (
+            parsed.get("monthly_debt"),
+            parsed.get("monthly_income"),
+            parsed.get("max_ratio_bps")
+        ) {
+            match (to_fixed(debt), to_fixed(income), max_ratio.as_u64()) {
+                (Some(_), Some(0), Some(max_ratio_bps)) => (-5, max_ratio_bps), // Zero income has no defined ratio
+                (Some(debt), Some(income), Some(max_ratio_bps)) => {
+                    (if passes(debt, income, max_ratio_bps) { 1 } else { 0 }, max_ratio_bps)
+                },
+                _ => (-2, 0) // Amounts must be finite and non-negative, the limit a non-negative integer
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit whether the ratio passes (1), fails (0) or the input was invalid (negative), and the limit applied
+    // Note: Debt and income remain private!
+    env::commit(&status);
+    env::commit(&max_ratio_bps);
+}
+
+// Non-negative finite amount in fixed point
+fn to_fixed(value: &serde_json::Value) -> Option<i64> {
+    let amount = value.as_f64().filter(|a| a.is_finite() && *a >= 0.0)?;
+    Some((amount * SCALE).round() as i64)
+}
+
+// debt / income <= max_ratio_bps / 10000, compared exactly in integers (income must be positive)
+fn passes(debt: i64, income: i64, max_ratio_bps: u64) -> bool {
+    debt as i128 * BPS <= income as i128 * max_ratio_bps as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_threshold() {
+        // 2150 / 5000 is exactly 43%
+        assert!(passes(215000000, 500000000, 4300));
+        // One fixed-point unit of debt over the limit fails
+        assert!(!passes(215000001, 500000000, 4300));
+    }
+
+    #[test]
+    fn test_around_threshold() {
+        assert!(passes(180000000, 500000000, 4300)); // 36%
+        assert!(!passes(250000000, 500000000, 4300)); // 50%
+        assert!(passes(0, 500000000, 0));
+        assert!(!passes(1, 500000000, 0));
+    }
+}
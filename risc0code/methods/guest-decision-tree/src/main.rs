@@ -0,0 +1,179 @@
+// Decision tree classification with zero-knowledge proof
+// This walks a private decision tree on a private feature vector and reveals only the predicted
+// class. The tree is validated before it is walked, so a malformed model (a child index out of
+// range, or a node reachable twice, which includes every cycle) is reported as an error whatever
+// the features are, and evaluation can never loop.
+use risc0_zkvm::guest::env;
+use serde_json::Value;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Bound the tree to keep cycle counts predictable
+const MAX_NODES: usize = 4096;
+const MAX_FEATURES: usize = 256;
+
+// One node of the tree: split on `feature <= threshold` (left, fixed point) or a leaf class
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Node {
+    Split { feature: usize, threshold: i64, left: usize, right: usize },
+    Leaf(i64),
+}
+
+#[derive(Debug, PartialEq)]
+enum TreeError {
+    // A child index is out of range or a node is reachable twice
+    Malformed,
+    // A split reads a feature beyond the end of the feature vector
+    MissingFeature,
+}
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "nodes": [                                                    // root first
+    //     {"feature": 0, "threshold": 2.5, "left": 1, "right": 2},    // features[0] <= 2.5 goes left
+    //     {"leaf": 0},                                                // predicted class
+    //     {"leaf": 1}
+    //   ],
+    //   "features": [3.0, 1.2]
+    // }
+    let inputs_json: String = env::read();
+
+    let result = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(nodes),
+            Some(features)
+        ) = (
+            parsed.get("nodes").and_then(|v| v.as_array()),
+            parsed.get("features").and_then(|v| v.as_array())
+        ) {
+            // Convert nodes to Vec<Node> and features to fixed point
+            let tree: Option<Vec<Node>> = nodes.iter().map(parse_node).collect();
+            let features: Option<Vec<i64>> = features
+                .iter()
+                .map(|v| v.as_f64().map(|x| (x * SCALE).round() as i64))
+                .collect();
+
+            match (tree, features) {
+                (Some(tree), Some(features)) => {
+                    if tree.is_empty() || tree.len() > MAX_NODES || features.is_empty() || features.len() > MAX_FEATURES {
+                        -1 // Empty or oversized tree or feature vector
+                    } else {
+                        match classify(&tree, &features) {
+                            Ok(class) => class,
+                            Err(TreeError::Malformed) => -5, // Child index out of range or a cycle
+                            Err(TreeError::MissingFeature) => -6 // Feature vector shorter than the tree needs
+                        }
+                    }
+                },
+                _ => -2 // Invalid node or feature data
+            }
+        } else {
+            -3 // Missing required fields
+        }
+    } else {
+        -4 // JSON parse error
+    };
+
+    // Commit the predicted class (non-negative) or error code
+    // Note: The tree and the feature vector remain private!
+    env::commit(&result);
+}
+
+// Leaf classes are non-negative integers so they cannot be confused with error codes
+fn parse_node(node: &Value) -> Option<Node> {
+    if let Some(leaf) = node.get("leaf") {
+        return Some(Node::Leaf(i64::try_from(leaf.as_u64()?).ok()?));
+    }
+    Some(Node::Split {
+        feature: node.get("feature")?.as_u64()? as usize,
+        threshold: (node.get("threshold")?.as_f64()? * SCALE).round() as i64,
+        left: node.get("left")?.as_u64()? as usize,
+        right: node.get("right")?.as_u64()? as usize,
+    })
+}
+
+// Check every node reachable from the root: children in range, no node reached twice, and every
+// split's feature present. Visiting each node at most once bounds the work by the tree size.
+fn validate(tree: &[Node], feature_count: usize) -> Result<(), TreeError> {
+    let mut visited = vec![false; tree.len()];
+    let mut pending = vec![0];
+    let mut missing_feature = false;
+    while let Some(index) = pending.pop() {
+        let node = tree.get(index).ok_or(TreeError::Malformed)?;
+        if std::mem::replace(&mut visited[index], true) {
+            return Err(TreeError::Malformed);
+        }
+        if let Node::Split { feature, left, right, .. } = *node {
+            missing_feature |= feature >= feature_count;
+            pending.push(left);
+            pending.push(right);
+        }
+    }
+    // A broken structure is reported ahead of a short feature vector
+    if missing_feature {
+        return Err(TreeError::MissingFeature);
+    }
+    Ok(())
+}
+
+fn classify(tree: &[Node], features: &[i64]) -> Result<i64, TreeError> {
+    validate(tree, features.len())?;
+    // Validation guarantees the walk reaches a leaf in fewer than tree.len() steps
+    let mut index = 0;
+    loop {
+        match tree[index] {
+            Node::Leaf(class) => return Ok(class),
+            Node::Split { feature, threshold, left, right } => {
+                index = if features[feature] <= threshold { left } else { right };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const S: i64 = SCALE as i64;
+
+    // features[0] <= 2.5 predicts class 0; otherwise features[1] <= 1.0 predicts 1, else 2
+    fn tree() -> Vec<Node> {
+        vec![
+            Node::Split { feature: 0, threshold: 250000, left: 1, right: 2 },
+            Node::Leaf(0),
+            Node::Split { feature: 1, threshold: S, left: 3, right: 4 },
+            Node::Leaf(1),
+            Node::Leaf(2),
+        ]
+    }
+
+    #[test]
+    fn test_walks_to_leaf() {
+        assert_eq!(classify(&tree(), &[2 * S, 9 * S]), Ok(0));
+        assert_eq!(classify(&tree(), &[3 * S, S]), Ok(1));
+        assert_eq!(classify(&tree(), &[3 * S, 2 * S]), Ok(2));
+        assert_eq!(classify(&[Node::Leaf(7)], &[0]), Ok(7));
+    }
+
+    #[test]
+    fn test_malformed_trees_are_rejected() {
+        // Out-of-range child, even on a branch these features would not take
+        let mut out_of_range = tree();
+        out_of_range[2] = Node::Split { feature: 1, threshold: S, left: 3, right: 9 };
+        assert_eq!(classify(&out_of_range, &[0, 0]), Err(TreeError::Malformed));
+
+        let self_loop = vec![Node::Split { feature: 0, threshold: 0, left: 0, right: 0 }];
+        assert_eq!(classify(&self_loop, &[S]), Err(TreeError::Malformed));
+        let mut back_edge = tree();
+        back_edge[2] = Node::Split { feature: 1, threshold: S, left: 3, right: 0 };
+        assert_eq!(classify(&back_edge, &[0, 0]), Err(TreeError::Malformed));
+    }
+
+    #[test]
+    fn test_short_feature_vector_is_rejected() {
+        // Feature 1 is only read on the right branch, but the vector is checked up front
+        assert_eq!(classify(&tree(), &[0]), Err(TreeError::MissingFeature));
+    }
+}
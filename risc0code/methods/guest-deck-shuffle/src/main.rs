@@ -0,0 +1,124 @@
+// Deterministic deck shuffle with zero-knowledge proof
+// This proves a deck was shuffled fairly from a committed seed
+//
+// RNG: SplitMix64 seeded directly with the 64-bit seed. Each Fisher-Yates swap draws an index
+// in [0, i] by rejection sampling, so the permutation is unbiased and fully determined by the seed.
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+
+// Bound the deck size to keep cycle counts predictable
+const MAX_DECK_SIZE: u64 = 10_000;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "deck_size": 52,
+    //   "seed": 123456789,
+    //   "reveal_permutation": false   // optional, also commit the permutation itself
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, seed_hash, permutation_hash, permutation) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(deck_size),
+            Some(seed)
+        ) = (
+            parsed.get("deck_size").and_then(|v| v.as_u64()),
+            parsed.get("seed").and_then(|v| v.as_u64())
+        ) {
+            let reveal = parsed.get("reveal_permutation").and_then(|v| v.as_bool()).unwrap_or(false);
+            
+            if deck_size > 0 && deck_size <= MAX_DECK_SIZE {
+                let deck = shuffle_deck(deck_size as u32, seed);
+                let permutation_hash = hash_permutation(&deck);
+                (deck_size as i64, hash_seed(seed), permutation_hash, if reveal { deck } else { Vec::new() })
+            } else {
+                (-1, Digest::ZERO, Digest::ZERO, Vec::new()) // Invalid deck size
+            }
+        } else {
+            (-3, Digest::ZERO, Digest::ZERO, Vec::new()) // Missing required fields
+        }
+    } else {
+        (-4, Digest::ZERO, Digest::ZERO, Vec::new()) // JSON parse error
+    };
+    
+    // Commit the deck size (or error code), the seed commitment, the permutation hash
+    // and the permutation itself when requested (empty otherwise)
+    env::commit(&status);
+    env::commit(&seed_hash);
+    env::commit(&permutation_hash);
+    env::commit(&permutation);
+}
+
+// SplitMix64 pseudo-random generator
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform value in [0, bound) using rejection sampling to avoid modulo bias
+    fn next_below(&mut self, bound: u64) -> u64 {
+        let zone = u64::MAX - (u64::MAX % bound);
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+}
+
+// Fisher-Yates shuffle of the cards 0..deck_size
+fn shuffle_deck(deck_size: u32, seed: u64) -> Vec<u32> {
+    let mut deck: Vec<u32> = (0..deck_size).collect();
+    let mut rng = SplitMix64 { state: seed };
+    
+    for i in (1..deck.len()).rev() {
+        let j = rng.next_below(i as u64 + 1) as usize;
+        deck.swap(i, j);
+    }
+    
+    deck
+}
+
+// SHA-256 over the little-endian seed bytes
+fn hash_seed(seed: u64) -> Digest {
+    *Impl::hash_bytes(&seed.to_le_bytes())
+}
+
+// SHA-256 over the little-endian card values in shuffled order
+fn hash_permutation(deck: &[u32]) -> Digest {
+    let bytes: Vec<u8> = deck.iter().flat_map(|card| card.to_le_bytes()).collect();
+    *Impl::hash_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_permutation_hash() {
+        let first = shuffle_deck(52, 42);
+        let second = shuffle_deck(52, 42);
+        assert_eq!(first, second);
+        assert_eq!(hash_permutation(&first), hash_permutation(&second));
+        
+        let other = shuffle_deck(52, 43);
+        assert_ne!(hash_permutation(&first), hash_permutation(&other));
+    }
+
+    #[test]
+    fn test_shuffle_is_a_permutation() {
+        let mut deck = shuffle_deck(52, 7);
+        deck.sort_unstable();
+        assert_eq!(deck, (0..52).collect::<Vec<u32>>());
+    }
+}
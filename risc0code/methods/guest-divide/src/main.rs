@@ -0,0 +1,77 @@
+// Fixed-point division with zero-knowledge proof
+// This proves the quotient and remainder of dividing two fixed-point decimals: the quotient
+// (a * SCALE) / b is truncated toward zero and a * SCALE = quotient * b + remainder.
+// A zero divisor (or a quotient too large for i64) is reported through a flag instead of panicking.
+//
+// Journal layout (risc0-serde, 36 bytes, all little-endian):
+//   bytes  0..8   a          i64, fixed-point dividend
+//   bytes  8..16  b          i64, fixed-point divisor
+//   bytes 16..24  quotient   i64, fixed-point quotient (0 unless flag is 0)
+//   bytes 24..32  remainder  i64, in units of 1 / SCALE^2 (0 unless flag is 0)
+//   bytes 32..36  flag       u32, 0 = ok, 1 = division by zero, 2 = quotient overflows i64
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
+
+// Journal flag values
+const OK: u32 = 0;
+const DIVISION_BY_ZERO: u32 = 1;
+const OVERFLOW: u32 = 2;
+
+fn main() {
+    // Read two fixed-point numbers (scaled integers) from the host
+    let a: i64 = env::read();
+    let b: i64 = env::read();
+    
+    let (quotient, remainder, flag) = match divide_fixed(a, b) {
+        Ok((quotient, remainder)) => (quotient, remainder, OK),
+        Err(flag) => (0, 0, flag),
+    };
+    
+    // Commit the original inputs, the quotient and remainder, and the flag to the journal for verification
+    env::commit(&a);
+    env::commit(&b);
+    env::commit(&quotient);
+    env::commit(&remainder);
+    env::commit(&flag);
+}
+
+// (a * SCALE) / b truncated toward zero with the matching remainder, or the flag explaining why not
+fn divide_fixed(a: i64, b: i64) -> Result<(i64, i64), u32> {
+    if b == 0 {
+        return Err(DIVISION_BY_ZERO);
+    }
+    let scaled = a as i128 * SCALE as i128;
+    let quotient = i64::try_from(scaled / b as i128).map_err(|_| OVERFLOW)?;
+    // |remainder| < |b|, so it always fits
+    Ok((quotient, (scaled % b as i128) as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_point_quotient() {
+        // 7 / 2 = 3.5 and 1 / 3 = 0.33333 remainder 1 (in 1 / SCALE^2 units)
+        assert_eq!(divide_fixed(700000, 200000), Ok((350000, 0)));
+        assert_eq!(divide_fixed(100000, 300000), Ok((33333, 100000)));
+    }
+
+    #[test]
+    fn test_truncates_toward_zero() {
+        for (a, b) in [(-700000, 300000), (700000, -300000), (-700000, -300000), (123456, -1000)] {
+            let (quotient, remainder) = divide_fixed(a, b).unwrap();
+            assert_eq!(quotient as i128 * b as i128 + remainder as i128, a as i128 * SCALE as i128);
+            assert!(remainder == 0 || remainder.signum() == a.signum());
+        }
+        assert_eq!(divide_fixed(-700000, 300000), Ok((-233333, -100000)));
+    }
+
+    #[test]
+    fn test_zero_divisor_and_overflow_are_flagged() {
+        assert_eq!(divide_fixed(100000, 0), Err(DIVISION_BY_ZERO));
+        assert_eq!(divide_fixed(i64::MAX, 1), Err(OVERFLOW));
+    }
+}
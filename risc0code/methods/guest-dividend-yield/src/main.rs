@@ -0,0 +1,81 @@
+// Dividend yield screen with zero-knowledge proof
+// This proves a holding's dividend yield exceeds a public target without revealing
+// its price or dividend
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Basis points in 100%
+const BPS: i128 = 10000;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "price": 52.40,              // private share price
+    //   "annual_dividend": 2.10,     // private annual dividend per share
+    //   "target_yield_bps": 350      // public target in basis points (350 = 3.5%)
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, target_yield_bps) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(price),
+            Some(dividend),
+            Some(target)
+        ) = (
+            parsed.get("price"),
+            parsed.get("annual_dividend"),
+            parsed.get("target_yield_bps")
+        ) {
+            match (to_fixed(price), to_fixed(dividend), target.as_u64()) {
+                (Some(0), Some(_), Some(target_yield_bps)) => (-5, target_yield_bps), // Zero price has no defined yield
+                (Some(price), Some(dividend), Some(target_yield_bps)) => {
+                    (if exceeds_target(price, dividend, target_yield_bps) { 1 } else { 0 }, target_yield_bps)
+                },
+                _ => (-2, 0) // Amounts must be finite and non-negative, the target a non-negative integer
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit whether the yield exceeds the target (1), does not (0) or the input was invalid (negative),
+    // and the target applied
+    // Note: Price and dividend remain private!
+    env::commit(&status);
+    env::commit(&target_yield_bps);
+}
+
+// Non-negative finite amount in fixed point
+fn to_fixed(value: &serde_json::Value) -> Option<i64> {
+    let amount = value.as_f64().filter(|a| a.is_finite() && *a >= 0.0)?;
+    Some((amount * SCALE).round() as i64)
+}
+
+// dividend / price > target_yield_bps / 10000, compared exactly in integers (price must be positive)
+fn exceeds_target(price: i64, dividend: i64, target_yield_bps: u64) -> bool {
+    dividend as i128 * BPS > price as i128 * target_yield_bps as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_target() {
+        // 3.50 / 100.00 is exactly 3.5%, which does not exceed a 3.5% target
+        assert!(!exceeds_target(10000000, 350000, 350));
+        // One fixed-point unit more dividend does
+        assert!(exceeds_target(10000000, 350001, 350));
+    }
+
+    #[test]
+    fn test_around_target() {
+        assert!(exceeds_target(5240000, 210000, 350)); // ≈ 4.01%
+        assert!(!exceeds_target(5240000, 150000, 350)); // ≈ 2.86%
+        assert!(!exceeds_target(5240000, 0, 0));
+    }
+}
@@ -0,0 +1,121 @@
+// Multi-factor eligibility scorecard with zero-knowledge proof
+// This proves an applicant's weighted score over min/max-normalized factors clears a public
+// threshold without revealing the factors, their weights or the score itself
+use risc0_zkvm::guest::env;
+use serde_json::Value;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+const SCALE_I: i128 = fixed_point::SCALE as i128;
+
+// Bound the scorecard to keep cycle counts predictable
+const MAX_FACTORS: usize = 64;
+
+// One scorecard factor in fixed point
+struct Factor {
+    value: i64,
+    min: i64,
+    max: i64,
+    weight: i64,
+    invert: bool,
+}
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "factors": [                                                          // private
+    //     {"value": 720, "min": 300, "max": 850, "weight": 0.5},              // credit score
+    //     {"value": 0.31, "min": 0.0, "max": 0.6, "weight": 0.3, "invert": true}, // debt-to-income, lower is better
+    //     {"value": 6, "min": 0, "max": 10, "weight": 0.2}                    // years employed
+    //   ],
+    //   "threshold": 0.6                                                      // public pass mark for the weighted sum
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, threshold_fixed) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(factors),
+            Some(threshold)
+        ) = (
+            parsed.get("factors").and_then(|v| v.as_array()),
+            parsed.get("threshold").and_then(|v| v.as_f64())
+        ) {
+            let threshold_fixed = (threshold * SCALE).round() as i64;
+            match factors.iter().map(parse_factor).collect::<Option<Vec<Factor>>>() {
+                _ if factors.is_empty() || factors.len() > MAX_FACTORS => (-1, threshold_fixed), // No factors or too many
+                _ if !threshold.is_finite() => (-2, 0), // Threshold must be finite
+                None => (-2, threshold_fixed), // Factors need finite value/min/max and a non-negative weight
+                Some(factors) if factors.iter().any(|f| f.max <= f.min) => (-5, threshold_fixed), // Empty normalization range
+                Some(factors) => (if score(&factors) >= threshold_fixed { 1 } else { 0 }, threshold_fixed)
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit whether the applicant passes (1), fails (0) or the input was invalid (negative), and the threshold applied
+    // Note: The factors, weights and composite score remain private!
+    env::commit(&status);
+    env::commit(&threshold_fixed);
+}
+
+fn parse_factor(factor: &Value) -> Option<Factor> {
+    let field = |name: &str| factor.get(name)?.as_f64().filter(|v| v.is_finite()).map(|v| (v * SCALE).round() as i64);
+    Some(Factor {
+        value: field("value")?,
+        min: field("min")?,
+        max: field("max")?,
+        weight: field("weight").filter(|w| *w >= 0)?,
+        invert: factor.get("invert").and_then(|v| v.as_bool()).unwrap_or(false),
+    })
+}
+
+// Factor scaled into [0, 1] by its min/max (clamped, optionally inverted), in fixed point; needs max > min
+fn normalize(factor: &Factor) -> i128 {
+    let clamped = factor.value.clamp(factor.min, factor.max) as i128;
+    let normalized = (clamped - factor.min as i128) * SCALE_I / (factor.max as i128 - factor.min as i128);
+    if factor.invert { SCALE_I - normalized } else { normalized }
+}
+
+// Weighted sum of normalized factors in fixed point, each term rounded down
+fn score(factors: &[Factor]) -> i64 {
+    factors.iter()
+        .map(|f| f.weight as i128 * normalize(f) / SCALE_I)
+        .sum::<i128>() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn scorecard(value: Value) -> Vec<Factor> {
+        value.as_array().unwrap().iter().map(|f| parse_factor(f).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_passing_applicant() {
+        let factors = scorecard(json!([
+            {"value": 720, "min": 300, "max": 850, "weight": 0.5},
+            {"value": 0.3, "min": 0.0, "max": 0.6, "weight": 0.3, "invert": true},
+            {"value": 6, "min": 0, "max": 10, "weight": 0.2}
+        ]));
+        // 0.5 * 420/550 + 0.3 * 0.5 + 0.2 * 0.6 = 0.38181 + 0.15 + 0.12
+        assert_eq!(score(&factors), 65181);
+        assert!(score(&factors) >= 60000);
+    }
+
+    #[test]
+    fn test_failing_applicant() {
+        let factors = scorecard(json!([
+            {"value": 540, "min": 300, "max": 850, "weight": 0.5},
+            {"value": 0.9, "min": 0.0, "max": 0.6, "weight": 0.3, "invert": true},
+            {"value": 1, "min": 0, "max": 10, "weight": 0.2}
+        ]));
+        // Debt-to-income above the max clamps to the worst normalized value
+        assert_eq!(normalize(&factors[1]), 0);
+        assert!(score(&factors) < 60000);
+    }
+}
@@ -0,0 +1,133 @@
+// Tiered energy billing with zero-knowledge proof
+// This proves a utility bill was computed correctly from published tier rates without
+// revealing the household's consumption
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+const SCALE_I: i128 = fixed_point::SCALE as i128;
+
+// Bound the tariff to keep cycle counts predictable
+const MAX_TIERS: usize = 32;
+
+// A tier's upper bound in fixed-point kWh (None for the open-ended last tier) and its rate per kWh
+struct Tier {
+    up_to: Option<i64>,
+    rate: i64,
+}
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "kwh": 1250.0,                         // private consumption
+    //   "tiers": [                             // public tariff, thresholds strictly increasing
+    //     {"up_to": 500, "rate": 0.12},        // first 500 kWh
+    //     {"up_to": 1000, "rate": 0.15},       // next 500 kWh
+    //     {"rate": 0.20}                       // everything above; may be omitted if usage is capped
+    //   ]
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, total_fixed) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(kwh),
+            Some(tiers)
+        ) = (
+            parsed.get("kwh").and_then(|v| v.as_f64()),
+            parsed.get("tiers").and_then(|v| v.as_array())
+        ) {
+            match tiers.iter().map(parse_tier).collect::<Option<Vec<Tier>>>() {
+                _ if tiers.is_empty() || tiers.len() > MAX_TIERS => (-1, 0), // No tiers or too many
+                _ if !kwh.is_finite() || kwh < 0.0 => (-2, 0), // Consumption must be non-negative
+                None => (-2, 0), // Tiers need a non-negative rate and, if bounded, a positive threshold
+                Some(tiers) if !is_monotonic(&tiers) => (-5, 0), // Thresholds must strictly increase
+                Some(tiers) => match bill(&tiers, (kwh * SCALE).round() as i64) {
+                    Some(total) => (1, total),
+                    None => (-6, 0) // Consumption beyond the last bounded tier
+                }
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit the status (1 on success, negative on error) and the fixed-point bill total
+    // Note: The consumption remains private!
+    env::commit(&status);
+    env::commit(&total_fixed);
+}
+
+fn parse_tier(tier: &serde_json::Value) -> Option<Tier> {
+    let up_to = match tier.get("up_to") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(value) => Some(value.as_f64().filter(|t| t.is_finite() && *t > 0.0)?),
+    };
+    let rate = tier.get("rate")?.as_f64().filter(|r| r.is_finite() && *r >= 0.0)?;
+    Some(Tier {
+        up_to: up_to.map(|t| (t * SCALE).round() as i64),
+        rate: (rate * SCALE).round() as i64,
+    })
+}
+
+// Bounded thresholds strictly increase, and only the last tier may be open-ended
+fn is_monotonic(tiers: &[Tier]) -> bool {
+    tiers.windows(2).all(|pair| match (pair[0].up_to, pair[1].up_to) {
+        (Some(lower), Some(upper)) => lower < upper,
+        (Some(_), None) => true,
+        (None, _) => false,
+    })
+}
+
+// Sum of each tier's rate over the consumption falling inside it; None if usage exceeds every tier
+fn bill(tiers: &[Tier], kwh: i64) -> Option<i64> {
+    let mut total: i128 = 0;
+    let mut floor = 0i64;
+    for tier in tiers {
+        let ceiling = tier.up_to.unwrap_or(i64::MAX);
+        let in_tier = kwh.min(ceiling) - floor;
+        if in_tier <= 0 {
+            return Some(total as i64);
+        }
+        total += in_tier as i128 * tier.rate as i128 / SCALE_I;
+        floor = ceiling;
+    }
+    if kwh > floor { None } else { Some(total as i64) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tariff(open_ended: bool) -> Vec<Tier> {
+        let mut tiers = vec![
+            Tier { up_to: Some(50000000), rate: 12000 },
+            Tier { up_to: Some(100000000), rate: 15000 },
+        ];
+        if open_ended {
+            tiers.push(Tier { up_to: None, rate: 20000 });
+        }
+        tiers
+    }
+
+    #[test]
+    fn test_known_multi_tier_bill() {
+        // 500 * 0.12 + 500 * 0.15 + 250 * 0.20 = 60 + 75 + 50
+        assert_eq!(bill(&tariff(true), 125000000), Some(18500000));
+        // Within the first tier only
+        assert_eq!(bill(&tariff(true), 30000000), Some(3600000));
+    }
+
+    #[test]
+    fn test_tier_validation() {
+        assert!(is_monotonic(&tariff(true)));
+        // Usage past a capped tariff is rejected, not billed at zero
+        assert_eq!(bill(&tariff(false), 100000001), None);
+        assert_eq!(bill(&tariff(false), 100000000), Some(13500000));
+        let descending = vec![Tier { up_to: Some(100000000), rate: 1 }, Tier { up_to: Some(50000000), rate: 1 }];
+        assert!(!is_monotonic(&descending));
+        let open_in_middle = vec![Tier { up_to: None, rate: 1 }, Tier { up_to: Some(50000000), rate: 1 }];
+        assert!(!is_monotonic(&open_in_middle));
+    }
+}
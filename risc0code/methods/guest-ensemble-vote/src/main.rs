@@ -0,0 +1,102 @@
+// Ensemble majority voting with zero-knowledge proof
+// This combines per-model class predictions by majority vote without revealing individual model outputs
+use risc0_zkvm::guest::env;
+use std::collections::BTreeMap;
+
+// Bound the ensemble to keep cycle counts predictable
+const MAX_MODELS: usize = 64;
+const MAX_SAMPLES: usize = 4096;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "predictions": [
+    //     [0, 1, 2],   // model 0's class for each sample
+    //     [0, 2, 1],   // model 1
+    //     [1, 2, 0]    // model 2
+    //   ]
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, final_predictions) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let Some(predictions) = parsed.get("predictions").and_then(|v| v.as_array()) {
+            // Convert predictions to Vec<Vec<i64>>
+            let models: Option<Vec<Vec<i64>>> = predictions
+                .iter()
+                .map(|model| model.as_array().and_then(|row| row.iter().map(|v| v.as_i64()).collect()))
+                .collect();
+            
+            match models {
+                Some(models) => {
+                    let sample_count = models.first().map_or(0, |m| m.len());
+                    if !models.is_empty() && models.len() <= MAX_MODELS
+                        && sample_count > 0 && sample_count <= MAX_SAMPLES
+                        && models.iter().all(|m| m.len() == sample_count) {
+                        (sample_count as i64, majority_vote(&models))
+                    } else {
+                        (-1, Vec::new()) // Empty or ragged prediction matrix
+                    }
+                },
+                None => (-2, Vec::new()) // Invalid class labels
+            }
+        } else {
+            (-3, Vec::new()) // Missing required fields
+        }
+    } else {
+        (-4, Vec::new()) // JSON parse error
+    };
+    
+    // Commit the number of samples (or error code) and the ensemble's final predictions
+    // Note: The individual model outputs remain private!
+    env::commit(&status);
+    env::commit(&final_predictions);
+}
+
+// Majority class per sample across models; ties go to the lowest class label
+fn majority_vote(models: &[Vec<i64>]) -> Vec<i64> {
+    let sample_count = models[0].len();
+    (0..sample_count)
+        .map(|sample| {
+            let mut votes: BTreeMap<i64, usize> = BTreeMap::new();
+            for model in models {
+                *votes.entry(model[sample]).or_insert(0) += 1;
+            }
+            // BTreeMap iterates labels in ascending order, so keeping only strictly
+            // greater counts leaves the lowest label among the tied leaders
+            let mut best = (i64::MAX, 0);
+            for (label, count) in votes {
+                if count > best.1 {
+                    best = (label, count);
+                }
+            }
+            best.0
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_three_model_majority() {
+        let models = vec![
+            vec![0, 1, 2, 1],
+            vec![0, 2, 2, 1],
+            vec![1, 1, 0, 1],
+        ];
+        assert_eq!(majority_vote(&models), vec![0, 1, 2, 1]);
+    }
+
+    #[test]
+    fn test_ties_break_to_lowest_label() {
+        // Every model disagrees on the first sample; two-way split on the second
+        let models = vec![
+            vec![2, 3],
+            vec![1, 5],
+            vec![0, 5],
+            vec![4, 3],
+        ];
+        assert_eq!(majority_vote(&models), vec![0, 3]);
+    }
+}
@@ -0,0 +1,100 @@
+// Escrow release computation with zero-knowledge proof
+// This computes the amount to release from escrow without revealing individual milestone values
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "deposited": 10000,
+    //   "milestones": [2500, 2500, 5000],  // amount payable per milestone
+    //   "completed": [0, 2]                 // indices of completed milestones
+    // }
+    let inputs_json: String = env::read();
+    
+    let release_amount = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(deposited),
+            Some(milestones),
+            Some(completed)
+        ) = (
+            parsed.get("deposited").and_then(|v| v.as_i64()),
+            parsed.get("milestones").and_then(|v| v.as_array()),
+            parsed.get("completed").and_then(|v| v.as_array())
+        ) {
+            // Convert milestones to Vec<i64> and completed indices to Vec<u64>
+            let amounts: Result<Vec<i64>, _> = milestones
+                .iter()
+                .map(|v| v.as_i64().filter(|amount| *amount >= 0).ok_or("Invalid milestone amount"))
+                .collect();
+            
+            let indices: Result<Vec<u64>, _> = completed
+                .iter()
+                .map(|v| v.as_u64().ok_or("Invalid milestone index"))
+                .collect();
+            
+            match (amounts, indices) {
+                (Ok(amounts), Ok(indices)) => {
+                    if deposited >= 0 && !amounts.is_empty() {
+                        compute_release(deposited, &amounts, &indices)
+                    } else {
+                        -1 // Invalid deposit or no milestones
+                    }
+                },
+                _ => -2 // Invalid milestone data
+            }
+        } else {
+            -3 // Missing required fields
+        }
+    } else {
+        -4 // JSON parse error
+    };
+    
+    // Commit only the amount to release
+    // Note: The individual milestone values remain private!
+    env::commit(&release_amount);
+}
+
+// Sum the amounts of completed milestones.
+// Returns -5 for out-of-range or duplicate indices and -6 when milestones exceed the deposit.
+fn compute_release(deposited: i64, amounts: &[i64], completed: &[u64]) -> i64 {
+    let total_milestones = amounts.iter().try_fold(0i64, |acc, amount| acc.checked_add(*amount));
+    match total_milestones {
+        Some(total) if total <= deposited => {},
+        _ => return -6, // Milestones over-commit the escrow
+    }
+    
+    let mut seen = vec![false; amounts.len()];
+    let mut release = 0i64;
+    for &index in completed {
+        let index = index as usize;
+        if index >= amounts.len() || seen[index] {
+            return -5; // Unknown or repeated milestone
+        }
+        seen[index] = true;
+        release += amounts[index];
+    }
+    
+    release
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_release() {
+        let amounts = vec![2500, 2500, 5000];
+        assert_eq!(compute_release(10000, &amounts, &[0, 2]), 7500);
+        assert_eq!(compute_release(10000, &amounts, &[1]), 2500);
+        assert_eq!(compute_release(10000, &amounts, &[]), 0);
+        assert_eq!(compute_release(10000, &amounts, &[0, 1, 2]), 10000);
+    }
+
+    #[test]
+    fn test_invalid_completed_indices() {
+        let amounts = vec![2500, 2500, 5000];
+        assert_eq!(compute_release(10000, &amounts, &[3]), -5);
+        assert_eq!(compute_release(10000, &amounts, &[1, 1]), -5);
+        assert_eq!(compute_release(9000, &amounts, &[0]), -6);
+    }
+}
@@ -0,0 +1,52 @@
+// Factorial with overflow detection
+// This proves n! computed iteratively over u64. 20! is the largest factorial that fits, so for
+// n >= 21 the guest flags an overflow instead of committing a wrapped product.
+//
+// Journal layout (risc0-serde, 20 bytes, all little-endian):
+//   bytes  0..8   n         u64
+//   bytes  8..16  result    u64, 0 when n! overflows
+//   bytes 16..20  overflow  u32, 1 when n! exceeds u64::MAX, else 0
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Read n from the host
+    let n: u64 = env::read();
+
+    let (result, overflow) = match factorial(n) {
+        Some(result) => (result, 0u32),
+        None => (0, 1u32),
+    };
+
+    // Commit the input, the result and the overflow flag to the journal for verification
+    env::commit(&n);
+    env::commit(&result);
+    env::commit(&overflow);
+}
+
+// Multiply 1..=n, stopping at the first product that no longer fits (0! is the empty product, 1)
+fn factorial(n: u64) -> Option<u64> {
+    let mut result: u64 = 1;
+    for k in 2..=n {
+        result = result.checked_mul(k)?;
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_factorials() {
+        assert_eq!(factorial(0), Some(1));
+        assert_eq!(factorial(1), Some(1));
+        assert_eq!(factorial(5), Some(120));
+    }
+
+    #[test]
+    fn test_overflow_boundary() {
+        assert_eq!(factorial(20), Some(2432902008176640000));
+        assert_eq!(factorial(21), None);
+        assert_eq!(factorial(u64::MAX), None);
+    }
+}
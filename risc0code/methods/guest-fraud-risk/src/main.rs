@@ -0,0 +1,118 @@
+// Fraud-risk gate with zero-knowledge proof
+// This proves a transaction's risk tier under a logistic scoring model and public tier
+// thresholds without revealing the transaction's features or its exact score
+use risc0_zkvm::guest::env;
+
+// Basis points in 100%
+const BPS: f64 = 10000.0;
+
+// Pre-trained logistic model over [amount, velocity, geo_distance_km]: larger amounts, more
+// transactions per hour and purchases far from home all raise the risk
+const WEIGHTS: [f64; 3] = [0.0004, 0.5, 0.002];
+const BIAS: f64 = -4.0;
+
+// Committed risk tiers
+const LOW: i64 = 0;
+const MEDIUM: i64 = 1;
+const HIGH: i64 = 2;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "amount": 2000.0,            // private transaction amount
+    //   "velocity": 4,               // private transactions in the last hour
+    //   "geo_distance_km": 300.0,    // private distance from the cardholder's usual location
+    //   "medium_bps": 3000,          // public: fraud probability at or above this is medium risk
+    //   "high_bps": 7000             // public: at or above this is high risk
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, medium_bps, high_bps) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(amount),
+            Some(velocity),
+            Some(distance),
+            Some(medium_bps),
+            Some(high_bps)
+        ) = (
+            parsed.get("amount").and_then(|v| v.as_f64()),
+            parsed.get("velocity").and_then(|v| v.as_f64()),
+            parsed.get("geo_distance_km").and_then(|v| v.as_f64()),
+            parsed.get("medium_bps").and_then(|v| v.as_u64()),
+            parsed.get("high_bps").and_then(|v| v.as_u64())
+        ) {
+            let features = [amount, velocity, distance];
+            if features.iter().any(|f| !f.is_finite() || *f < 0.0) {
+                (-2, medium_bps, high_bps) // Features must be finite and non-negative
+            } else if medium_bps > high_bps || high_bps > 10000 {
+                (-5, medium_bps, high_bps) // Thresholds must satisfy medium <= high <= 100%
+            } else {
+                (classify(risk_bps(&features), medium_bps, high_bps), medium_bps, high_bps)
+            }
+        } else {
+            (-3, 0, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0, 0) // JSON parse error
+    };
+    
+    // Commit the risk tier (0 low, 1 medium, 2 high, negative on error) and the thresholds applied
+    // Note: The transaction features and fraud probability remain private!
+    env::commit(&status);
+    env::commit(&medium_bps);
+    env::commit(&high_bps);
+}
+
+// Fraud probability in basis points: sigmoid(w · x + b)
+fn risk_bps(features: &[f64; 3]) -> u64 {
+    let z = BIAS + WEIGHTS.iter().zip(features).map(|(w, x)| w * x).sum::<f64>();
+    (sigmoid(z) * BPS).round() as u64
+}
+
+// Same clamped sigmoid as the logistic regression guest
+fn sigmoid(x: f64) -> f64 {
+    if x > 20.0 {
+        1.0 // Prevent overflow
+    } else if x < -20.0 {
+        0.0 // Prevent underflow
+    } else {
+        1.0 / (1.0 + (-x).exp())
+    }
+}
+
+fn classify(risk_bps: u64, medium_bps: u64, high_bps: u64) -> i64 {
+    if risk_bps >= high_bps {
+        HIGH
+    } else if risk_bps >= medium_bps {
+        MEDIUM
+    } else {
+        LOW
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(amount: f64, velocity: f64, distance: f64) -> i64 {
+        classify(risk_bps(&[amount, velocity, distance]), 3000, 7000)
+    }
+
+    #[test]
+    fn test_low_and_medium_risk() {
+        // z = -3.47: everyday purchase close to home
+        assert_eq!(risk_bps(&[50.0, 1.0, 5.0]), 302);
+        assert_eq!(tier(50.0, 1.0, 5.0), LOW);
+        // z = -0.6: sizeable amount, several transactions, some distance
+        assert_eq!(tier(2000.0, 4.0, 300.0), MEDIUM);
+    }
+
+    #[test]
+    fn test_high_risk() {
+        // z = 6: large amount in a burst of transactions far from home
+        assert_eq!(tier(5000.0, 8.0, 2000.0), HIGH);
+        // Thresholds are inclusive
+        assert_eq!(classify(7000, 3000, 7000), HIGH);
+        assert_eq!(classify(2999, 3000, 7000), LOW);
+    }
+}
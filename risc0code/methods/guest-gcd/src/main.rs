@@ -0,0 +1,68 @@
+// Greatest common divisor with proven Bézout coefficients
+// This proves gcd(a, b) together with coefficients x and y such that a*x + b*y = gcd, so a verifier
+// can check the identity from the journal alone. The extended Euclidean algorithm keeps
+// |x| <= b / (2 gcd) and |y| <= a / (2 gcd), so both coefficients fit in an i64 for any u64 inputs.
+//
+// Journal layout (risc0-serde, 40 bytes, all little-endian):
+//   bytes  0..8   a    u64
+//   bytes  8..16  b    u64
+//   bytes 16..24  gcd  u64, 0 only when a = b = 0
+//   bytes 24..32  x    i64, coefficient of a
+//   bytes 32..40  y    i64, coefficient of b
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Read the two values from the host
+    let a: u64 = env::read();
+    let b: u64 = env::read();
+
+    let (gcd, x, y) = extended_gcd(a, b);
+
+    // Commit the inputs, the gcd and both coefficients to the journal for verification
+    env::commit(&a);
+    env::commit(&b);
+    env::commit(&gcd);
+    env::commit(&x);
+    env::commit(&y);
+}
+
+// Extended Euclidean algorithm on i128 so intermediate values of full-width u64 inputs fit
+fn extended_gcd(a: u64, b: u64) -> (u64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    // Within the bound in the header comment, so the conversions cannot fail
+    (old_r as u64, i64::try_from(old_x).unwrap(), i64::try_from(old_y).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holds(a: u64, b: u64) -> bool {
+        let (gcd, x, y) = extended_gcd(a, b);
+        a as i128 * x as i128 + b as i128 * y as i128 == gcd as i128
+    }
+
+    #[test]
+    fn test_bezout_identity() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert!(holds(17, 3120));
+        // Full-width operands keep the coefficients within i64
+        assert!(holds(u64::MAX, u64::MAX - 1));
+        assert!(holds(u64::MAX - 1, 1 << 63));
+    }
+
+    #[test]
+    fn test_zero_operands() {
+        assert_eq!(extended_gcd(0, 5), (5, 0, 1));
+        assert_eq!(extended_gcd(5, 0), (5, 1, 0));
+        assert_eq!(extended_gcd(0, 0), (0, 1, 0));
+    }
+}
@@ -0,0 +1,70 @@
+// Geometric mean with zero-knowledge proof
+// This proves the geometric mean of a private series of positive values, e.g. a performance index
+// built from period growth factors, without revealing the values
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Bound the series to keep cycle counts predictable
+const MAX_VALUES: usize = 10000;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "values": [1.05, 0.98, 1.12, 1.03]    // private, each strictly positive
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, mean_fixed) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let Some(values) = parsed.get("values").and_then(|v| v.as_array()) {
+            let values: Option<Vec<f64>> = values.iter().map(|v| v.as_f64().filter(|x| x.is_finite())).collect();
+            match values {
+                Some(values) if values.is_empty() || values.len() > MAX_VALUES => (-1, 0), // Empty or oversized series
+                Some(values) => match geometric_mean(&values) {
+                    Some(mean) => (values.len() as i64, (mean * SCALE).round() as i64),
+                    None => (-5, 0) // Every value must be strictly positive
+                },
+                None => (-2, 0) // Values must be finite numbers
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit the number of values (or a negative error code) and the fixed-point geometric mean
+    // Note: The individual values remain private!
+    env::commit(&status);
+    env::commit(&mean_fixed);
+}
+
+// exp of the mean natural log, which avoids the overflow of multiplying long series directly;
+// None if any value is zero or negative
+fn geometric_mean(values: &[f64]) -> Option<f64> {
+    if values.iter().any(|v| *v <= 0.0) {
+        return None;
+    }
+    let log_sum: f64 = values.iter().map(|v| v.ln()).sum();
+    Some((log_sum / values.len() as f64).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_geometric_mean() {
+        // (2 * 8)^(1/2) = 4 and (1 * 3 * 9)^(1/3) = 3
+        assert!((geometric_mean(&[2.0, 8.0]).unwrap() - 4.0).abs() < 1e-9);
+        assert!((geometric_mean(&[1.0, 3.0, 9.0]).unwrap() - 3.0).abs() < 1e-9);
+        assert_eq!((geometric_mean(&[4.0, 4.0, 4.0]).unwrap() * SCALE).round() as i64, 400000);
+    }
+
+    #[test]
+    fn test_rejects_non_positive() {
+        assert!(geometric_mean(&[2.0, 0.0, 8.0]).is_none());
+        assert!(geometric_mean(&[2.0, -8.0]).is_none());
+    }
+}
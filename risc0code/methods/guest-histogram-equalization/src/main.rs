@@ -0,0 +1,101 @@
+// Histogram equalization lookup with zero-knowledge proof
+// This derives the CDF-based equalization mapping from a private grayscale histogram
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+
+// Bound the number of gray levels (16-bit images at most)
+const MAX_LEVELS: usize = 65536;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "histogram": [0, 12, 40, 7, ...]   // pixel count per gray level
+    // }
+    let inputs_json: String = env::read();
+    
+    let (levels, mapping, histogram_hash) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let Some(histogram) = parsed.get("histogram").and_then(|v| v.as_array()) {
+            // Convert histogram to Vec<u64>
+            let counts: Result<Vec<u64>, _> = histogram
+                .iter()
+                .map(|v| v.as_u64().ok_or("Invalid histogram count"))
+                .collect();
+            
+            match counts {
+                Ok(counts) => {
+                    if counts.len() >= 2 && counts.len() <= MAX_LEVELS && counts.iter().any(|c| *c > 0) {
+                        (counts.len() as i64, equalization_mapping(&counts), hash_histogram(&counts))
+                    } else {
+                        (-1, Vec::new(), Digest::ZERO) // Too few/many levels or empty image
+                    }
+                },
+                _ => (-2, Vec::new(), Digest::ZERO) // Invalid histogram data
+            }
+        } else {
+            (-3, Vec::new(), Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, Vec::new(), Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit the number of levels (or error code), the lookup table and the histogram hash
+    // Note: The histogram remains private beyond its hash!
+    env::commit(&levels);
+    env::commit(&mapping);
+    env::commit(&histogram_hash);
+}
+
+// Standard CDF equalization: map[i] = round((cdf[i] - cdf_min) / (total - cdf_min) * (L - 1)),
+// where cdf_min is the CDF at the first occupied level. Single-level images map to themselves.
+fn equalization_mapping(counts: &[u64]) -> Vec<u32> {
+    let levels = counts.len() as u128;
+    let mut cdf = Vec::with_capacity(counts.len());
+    let mut running: u128 = 0;
+    for count in counts {
+        running += *count as u128;
+        cdf.push(running);
+    }
+    
+    let total = running;
+    let cdf_min = cdf.iter().copied().find(|c| *c > 0).unwrap_or(0);
+    let denominator = total - cdf_min;
+    if denominator == 0 {
+        return (0..counts.len() as u32).collect();
+    }
+    
+    cdf.iter()
+        .map(|c| {
+            let numerator = c.saturating_sub(cdf_min) * (levels - 1);
+            // Round half up in integer arithmetic
+            ((2 * numerator + denominator) / (2 * denominator)) as u32
+        })
+        .collect()
+}
+
+// SHA-256 over the little-endian u64 counts
+fn hash_histogram(counts: &[u64]) -> Digest {
+    let bytes: Vec<u8> = counts.iter().flat_map(|count| count.to_le_bytes()).collect();
+    *Impl::hash_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_equalization_mapping() {
+        // CDF = [0, 2, 4, 4, 8], cdf_min = 2, scale = 4 / 6
+        assert_eq!(equalization_mapping(&[0, 2, 2, 0, 4]), vec![0, 0, 1, 1, 4]);
+    }
+
+    #[test]
+    fn test_uniform_histogram_is_identity() {
+        let counts = vec![10; 8];
+        assert_eq!(equalization_mapping(&counts), (0..8).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn test_single_level_image() {
+        assert_eq!(equalization_mapping(&[0, 0, 9, 0]), vec![0, 1, 2, 3]);
+    }
+}
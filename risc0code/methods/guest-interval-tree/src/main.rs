@@ -0,0 +1,116 @@
+// Interval membership lookup with zero-knowledge proof
+// This finds which private intervals contain a query point, revealing only the matches and a hash of the intervals
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+
+// Bound the number of intervals to keep cycle counts predictable
+const MAX_INTERVALS: usize = 1024;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "intervals": [[0, 100], [50, 75], [200, 300]],  // inclusive [start, end] bounds
+    //   "query": 60
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, matches, intervals_hash) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(intervals),
+            Some(query)
+        ) = (
+            parsed.get("intervals").and_then(|v| v.as_array()),
+            parsed.get("query").and_then(|v| v.as_i64())
+        ) {
+            // Convert intervals to Vec<(i64, i64)>
+            let bounds: Result<Vec<(i64, i64)>, _> = intervals
+                .iter()
+                .map(|interval| {
+                    match interval.as_array().map(|pair| pair.as_slice()) {
+                        Some([start, end]) => match (start.as_i64(), end.as_i64()) {
+                            (Some(start), Some(end)) if start <= end => Ok((start, end)),
+                            _ => Err("Invalid interval bounds"),
+                        },
+                        _ => Err("Invalid interval format"),
+                    }
+                })
+                .collect();
+            
+            match bounds {
+                Ok(bounds) => {
+                    if !bounds.is_empty() && bounds.len() <= MAX_INTERVALS {
+                        let matches = find_containing(&bounds, query);
+                        (matches.len() as i64, matches, hash_intervals(&bounds))
+                    } else {
+                        (-1, Vec::new(), Digest::ZERO) // Empty or oversized interval set
+                    }
+                },
+                _ => (-2, Vec::new(), Digest::ZERO) // Invalid interval data
+            }
+        } else {
+            (-3, Vec::new(), Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, Vec::new(), Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit the match count (or error code), the matching interval indices and the interval hash
+    // Note: The interval boundaries remain private beyond their hash!
+    env::commit(&status);
+    env::commit(&matches);
+    env::commit(&intervals_hash);
+}
+
+// Return the indices of every interval containing the query, in input order.
+// Intervals are visited sorted by start so the scan stops at the first start beyond the query.
+fn find_containing(bounds: &[(i64, i64)], query: i64) -> Vec<u32> {
+    let mut by_start: Vec<usize> = (0..bounds.len()).collect();
+    by_start.sort_by_key(|&i| (bounds[i].0, i));
+    
+    let mut matches = Vec::new();
+    for i in by_start {
+        let (start, end) = bounds[i];
+        if start > query {
+            break;
+        }
+        if query <= end {
+            matches.push(i as u32);
+        }
+    }
+    
+    matches.sort_unstable();
+    matches
+}
+
+// SHA-256 over the little-endian (start, end) pairs in input order
+fn hash_intervals(bounds: &[(i64, i64)]) -> Digest {
+    let mut bytes = Vec::with_capacity(bounds.len() * 16);
+    for (start, end) in bounds {
+        bytes.extend_from_slice(&start.to_le_bytes());
+        bytes.extend_from_slice(&end.to_le_bytes());
+    }
+    *Impl::hash_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_intervals() {
+        let bounds = vec![(0, 100), (50, 75), (60, 60)];
+        assert_eq!(find_containing(&bounds, 60), vec![0, 1, 2]);
+        assert_eq!(find_containing(&bounds, 55), vec![0, 1]);
+        assert_eq!(find_containing(&bounds, 90), vec![0]);
+    }
+
+    #[test]
+    fn test_disjoint_intervals() {
+        let bounds = vec![(200, 300), (0, 99), (100, 199)];
+        assert_eq!(find_containing(&bounds, 150), vec![2]);
+        assert_eq!(find_containing(&bounds, 100), vec![2]);
+        assert_eq!(find_containing(&bounds, 99), vec![1]);
+        assert!(find_containing(&bounds, 301).is_empty());
+        assert!(find_containing(&bounds, -1).is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+// Inventory reorder decision with zero-knowledge proof
+// This decides whether stock has fallen to the reorder point and how much to order,
+// revealing only the reorder quantity and keeping stock and demand forecast private
+use risc0_zkvm::guest::env;
+
+// Bound the forecast horizon to keep cycle counts predictable
+const MAX_PERIODS: usize = 365;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "stock": 40,                          // units on hand
+    //   "demand_forecast": [20, 25, 30],      // forecast units per period over the replenishment horizon
+    //   "reorder_point": 50                   // reorder once stock is at or below this level
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, quantity) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(stock),
+            Some(forecast),
+            Some(reorder_point)
+        ) = (
+            parsed.get("stock"),
+            parsed.get("demand_forecast").and_then(|v| v.as_array()),
+            parsed.get("reorder_point")
+        ) {
+            // Convert forecast to Vec<u64>
+            let demand: Result<Vec<u64>, _> = forecast
+                .iter()
+                .map(|v| v.as_u64().ok_or("Invalid demand"))
+                .collect();
+            
+            match (stock.as_u64(), demand, reorder_point.as_u64()) {
+                (Some(stock), Ok(demand), Some(reorder_point)) => {
+                    if demand.is_empty() || demand.len() > MAX_PERIODS {
+                        (-1, 0) // Empty or oversized forecast
+                    } else {
+                        let quantity = reorder_quantity(stock, &demand, reorder_point);
+                        (if quantity > 0 { 1 } else { 0 }, quantity)
+                    }
+                },
+                _ => (-2, 0) // Stock, demand and reorder point must be non-negative integers
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit whether to reorder (1), not to reorder (0) or an error code (negative), and the quantity
+    // Note: Stock level, forecast and reorder point remain private!
+    env::commit(&status);
+    env::commit(&quantity);
+}
+
+// At or below the reorder point, order enough to cover forecast demand and end the horizon back at
+// the reorder point; above it, order nothing
+fn reorder_quantity(stock: u64, demand: &[u64], reorder_point: u64) -> u64 {
+    if stock > reorder_point {
+        return 0;
+    }
+    let total_demand = demand.iter().fold(0u64, |total, &d| total.saturating_add(d));
+    total_demand.saturating_add(reorder_point).saturating_sub(stock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_above_reorder_point_orders_nothing() {
+        assert_eq!(reorder_quantity(80, &[20, 25, 30], 50), 0);
+        assert_eq!(reorder_quantity(51, &[1000], 50), 0);
+    }
+
+    #[test]
+    fn test_below_reorder_point_covers_demand() {
+        // 75 units of demand plus the 50-unit reorder point, less the 40 on hand
+        assert_eq!(reorder_quantity(40, &[20, 25, 30], 50), 85);
+        // Exactly at the reorder point still triggers an order
+        assert_eq!(reorder_quantity(50, &[10], 50), 10);
+        assert_eq!(reorder_quantity(0, &[u64::MAX, 1], 5), u64::MAX);
+    }
+}
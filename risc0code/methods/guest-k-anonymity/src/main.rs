@@ -0,0 +1,129 @@
+// K-anonymity check with zero-knowledge proof
+// This proves every quasi-identifier combination appears at least k times without revealing the data
+//
+// k and the quasi-identifier columns are what the claim is about, so both are committed: verifiers
+// must check them, or a proof with k = 1 (which every dataset passes) could stand in.
+//
+// Journal layout (risc0-serde):
+//   status             i64, 1 = k-anonymous, 0 = violated, negative on error
+//   k                  u64 (zero when it could not be read)
+//   quasi_identifiers  Digest, SHA-256 of the column names, see `hash_names` (zero on error)
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "records": [{"zip": "941**", "age_band": "30-39", "diagnosis": "flu"}, ...],
+    //   "quasi_identifiers": ["zip", "age_band"],
+    //   "k": 3
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, k, quasi_identifiers_hash): (i64, u64, Digest) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(records),
+            Some(quasi_identifiers),
+            Some(k)
+        ) = (
+            parsed.get("records").and_then(|v| v.as_array()),
+            parsed.get("quasi_identifiers").and_then(|v| v.as_array()),
+            parsed.get("k").and_then(|v| v.as_u64())
+        ) {
+            // Convert quasi-identifier columns to Vec<&str>
+            let columns: Result<Vec<&str>, _> = quasi_identifiers
+                .iter()
+                .map(|v| v.as_str().ok_or("Invalid column name"))
+                .collect();
+            
+            match columns {
+                Ok(columns) => {
+                    let columns_hash = hash_names(&columns);
+                    if !records.is_empty() && !columns.is_empty() && k > 0 {
+                        (if is_k_anonymous(records, &columns, k as usize) { 1 } else { 0 }, k, columns_hash)
+                    } else {
+                        (-1, k, columns_hash) // Empty dataset, no quasi-identifiers or k == 0
+                    }
+                },
+                _ => (-2, k, Digest::ZERO) // Invalid column list
+            }
+        } else {
+            (-3, 0, Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, 0, Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit whether the dataset is k-anonymous (1), violates it (0) or is invalid (negative), along
+    // with the k and the quasi-identifier columns it was checked against
+    // Note: The records and the equivalence class sizes remain private!
+    env::commit(&status);
+    env::commit(&k);
+    env::commit(&quasi_identifiers_hash);
+}
+
+// SHA-256 over each name's length (u32 little-endian) and UTF-8 bytes, in input order; the host
+// computes the same digest with `methods::journal::name_list_digest`
+fn hash_names(names: &[&str]) -> Digest {
+    let mut bytes = Vec::new();
+    for name in names {
+        bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(name.as_bytes());
+    }
+    *Impl::hash_bytes(&bytes)
+}
+
+// Group records by their quasi-identifier values and require every group to hold at least k records.
+// A missing column is grouped as null so it cannot be used to single out a record.
+fn is_k_anonymous(records: &[Value], columns: &[&str], k: usize) -> bool {
+    let mut class_sizes: BTreeMap<String, usize> = BTreeMap::new();
+    
+    for record in records {
+        let key: Vec<&Value> = columns
+            .iter()
+            .map(|column| record.get(*column).unwrap_or(&Value::Null))
+            .collect();
+        let key = serde_json::to_string(&key).unwrap_or_default();
+        *class_sizes.entry(key).or_insert(0) += 1;
+    }
+    
+    class_sizes.values().all(|size| *size >= k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_dataset_satisfies_k_anonymity() {
+        let records = json!([
+            {"zip": "941**", "age_band": "30-39", "diagnosis": "flu"},
+            {"zip": "941**", "age_band": "30-39", "diagnosis": "cold"},
+            {"zip": "941**", "age_band": "40-49", "diagnosis": "flu"},
+            {"zip": "941**", "age_band": "40-49", "diagnosis": "asthma"}
+        ]);
+        assert!(is_k_anonymous(records.as_array().unwrap(), &["zip", "age_band"], 2));
+    }
+
+    #[test]
+    fn test_dataset_violates_k_anonymity() {
+        let records = json!([
+            {"zip": "941**", "age_band": "30-39", "diagnosis": "flu"},
+            {"zip": "941**", "age_band": "30-39", "diagnosis": "cold"},
+            {"zip": "100**", "age_band": "30-39", "diagnosis": "flu"}
+        ]);
+        assert!(!is_k_anonymous(records.as_array().unwrap(), &["zip", "age_band"], 2));
+        assert!(is_k_anonymous(records.as_array().unwrap(), &["age_band"], 3));
+    }
+
+    #[test]
+    fn test_hash_names_matches_host_digest() {
+        // Same vector as methods::journal's test of name_list_digest
+        assert_eq!(hash_names(&["zip", "age_band"]).to_string(), "041cb8067c72b349d8cee1919526125beec5beb4801cc18110456159a1de2ea5");
+        // Length prefixes keep differently split names apart
+        assert_ne!(hash_names(&["zipa", "ge_band"]), hash_names(&["zip", "age_band"]));
+    }
+}
@@ -0,0 +1,92 @@
+// Levenshtein edit distance with zero-knowledge proof
+// This computes the edit distance between two private strings without revealing them
+use risc0_zkvm::guest::env;
+
+// Cap string lengths so the O(n*m) dynamic program stays within a predictable cycle budget
+const MAX_STRING_LENGTH: usize = 256;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "a": "kitten",
+    //   "b": "sitting"
+    // }
+    let inputs_json: String = env::read();
+    
+    let distance = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(a),
+            Some(b)
+        ) = (
+            parsed.get("a").and_then(|v| v.as_str()),
+            parsed.get("b").and_then(|v| v.as_str())
+        ) {
+            let a_chars: Vec<char> = a.chars().collect();
+            let b_chars: Vec<char> = b.chars().collect();
+            
+            if a_chars.len() <= MAX_STRING_LENGTH && b_chars.len() <= MAX_STRING_LENGTH {
+                levenshtein_distance(&a_chars, &b_chars) as i64
+            } else {
+                -1 // String too long
+            }
+        } else {
+            -3 // Missing required fields
+        }
+    } else {
+        -4 // JSON parse error
+    };
+    
+    // Commit only the edit distance
+    // Note: Both strings remain private!
+    env::commit(&distance);
+}
+
+// Classic two-row dynamic program over Unicode scalar values
+fn levenshtein_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution_cost = if a_char == b_char { 0 } else { 1 };
+            current[j + 1] = (previous[j] + substitution_cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distance(a: &str, b: &str) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        levenshtein_distance(&a, &b)
+    }
+
+    #[test]
+    fn test_identical_strings() {
+        assert_eq!(distance("zero knowledge", "zero knowledge"), 0);
+        assert_eq!(distance("", ""), 0);
+    }
+
+    #[test]
+    fn test_single_edit() {
+        assert_eq!(distance("proof", "proofs"), 1);
+        assert_eq!(distance("proof", "roof"), 1);
+        assert_eq!(distance("proof", "prove"), 2);
+        assert_eq!(distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_completely_different_strings() {
+        assert_eq!(distance("abc", "xyz"), 3);
+        assert_eq!(distance("", "risc"), 4);
+    }
+}
@@ -2,6 +2,9 @@
 // This performs linear regression (y = mx + b) on a dataset without revealing the data
 use risc0_zkvm::guest::env;
 
+// Scale of the committed prediction, shared with the host's decoder
+use fixed_point::REGRESSION_SCALE;
+
 fn main() {
     // Read input from the host - expects:
     // {
@@ -37,7 +40,7 @@ fn main() {
                     if x.len() == y.len() && x.len() > 1 {
                         let prediction = linear_regression_predict(&x, &y, predict_x);
                         // Scale to integer for commitment (multiply by 1000 for precision)
-                        (prediction * 1000.0) as i64
+                        (prediction * REGRESSION_SCALE as f64) as i64
                     } else {
                         -1 // Mismatched or insufficient data
                     }
@@ -51,7 +54,7 @@ fn main() {
         -4 // JSON parse error
     };
     
-    // Commit the prediction result (scaled by 1000)
+    // Commit the prediction result (scaled by REGRESSION_SCALE)
     // Note: The training data remains private!
     env::commit(&result);
 }
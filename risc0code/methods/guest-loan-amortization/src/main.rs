@@ -0,0 +1,100 @@
+// Loan amortization with zero-knowledge proof
+// This computes the fixed monthly payment and total repaid for an amortized loan
+use risc0_zkvm::guest::env;
+
+// Fixed-point scale for the monthly rate and growth factor (12 decimal places)
+const RATE_SCALE: i128 = 1_000_000_000_000;
+
+// Bound the schedule length to keep cycle counts predictable (100 years)
+const MAX_MONTHS: u64 = 1200;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "principal": 20000000,  // in cents
+    //   "rate_bps": 600,        // annual rate in basis points (6.00%)
+    //   "months": 360
+    // }
+    let inputs_json: String = env::read();
+    
+    let (monthly_payment, total_paid) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(principal),
+            Some(rate_bps),
+            Some(months)
+        ) = (
+            parsed.get("principal").and_then(|v| v.as_i64()),
+            parsed.get("rate_bps").and_then(|v| v.as_u64()),
+            parsed.get("months").and_then(|v| v.as_u64())
+        ) {
+            if principal > 0 && months > 0 && months <= MAX_MONTHS {
+                match amortize(principal, rate_bps, months) {
+                    Some((payment, total)) => (payment, total),
+                    None => (-5, 0) // Arithmetic overflow
+                }
+            } else {
+                (-1, 0) // Invalid principal or term
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit the monthly payment and the total amount paid (both in cents)
+    env::commit(&monthly_payment);
+    env::commit(&total_paid);
+}
+
+// Standard annuity formula: payment = P * r * (1 + r)^n / ((1 + r)^n - 1), with r the monthly rate.
+// All intermediate values use checked i128 fixed-point arithmetic; returns None on overflow.
+fn amortize(principal: i64, rate_bps: u64, months: u64) -> Option<(i64, i64)> {
+    let principal = principal as i128;
+    let months_i = months as i128;
+    
+    // Monthly rate in fixed point: rate_bps / 10000 / 12
+    let monthly_rate = (rate_bps as i128).checked_mul(RATE_SCALE)? / 120_000;
+    
+    let payment = if monthly_rate == 0 {
+        // Interest-free loan: split the principal evenly (rounded to nearest cent)
+        (principal + months_i / 2) / months_i
+    } else {
+        // Growth factor (1 + r)^n in fixed point
+        let mut growth = RATE_SCALE;
+        for _ in 0..months {
+            growth = growth.checked_mul(RATE_SCALE + monthly_rate)? / RATE_SCALE;
+        }
+        
+        let numerator = principal.checked_mul(monthly_rate)?.checked_mul(growth)?;
+        let denominator = (growth - RATE_SCALE).checked_mul(RATE_SCALE)?;
+        // Round to the nearest cent
+        (numerator + denominator / 2) / denominator
+    };
+    
+    let total = payment.checked_mul(months_i)?;
+    Some((i64::try_from(payment).ok()?, i64::try_from(total).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_mortgage() {
+        // $200,000 at 6% over 30 years -> $1,199.10 per month
+        assert_eq!(amortize(20_000_000, 600, 360), Some((119_910, 43_167_600)));
+    }
+
+    #[test]
+    fn test_one_year_loan() {
+        // $10,000 at 12% over 12 months -> $888.49 per month
+        assert_eq!(amortize(1_000_000, 1200, 12), Some((88_849, 1_066_188)));
+    }
+
+    #[test]
+    fn test_zero_rate_and_overflow() {
+        assert_eq!(amortize(1_200_000, 0, 12), Some((100_000, 1_200_000)));
+        assert_eq!(amortize(i64::MAX, 100_000, 1200), None);
+    }
+}
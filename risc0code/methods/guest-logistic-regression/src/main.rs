@@ -2,6 +2,9 @@
 // This performs binary classification without revealing the model weights or training data
 use risc0_zkvm::guest::env;
 
+// Scale of the committed probability, shared with the host's decoder
+use fixed_point::PROBABILITY_SCALE;
+
 fn main() {
     // Read input from the host - expects:
     // {
@@ -30,7 +33,7 @@ fn main() {
                     if x.len() >= 2 && !task.is_empty() {
                         let prediction = logistic_regression_predict(&x, task);
                         // Return probability scaled by 10000 for precision
-                        (prediction * 10000.0) as i64
+                        (prediction * PROBABILITY_SCALE as f64) as i64
                     } else {
                         -1 // Invalid features or task
                     }
@@ -44,7 +47,7 @@ fn main() {
         -4 // JSON parse error
     };
     
-    // Commit the prediction probability (scaled by 10000)
+    // Commit the prediction probability (scaled by PROBABILITY_SCALE)
     // Note: The model weights and training process remain private!
     env::commit(&result);
 }
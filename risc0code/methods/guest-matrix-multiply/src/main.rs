@@ -0,0 +1,128 @@
+// Fixed-point matrix multiplication with zero-knowledge proof
+// This multiplies two private matrices, e.g. a linear layer's weight matrix by a batch of inputs,
+// and commits the product with its dimensions so the host can reshape it
+use risc0_zkvm::guest::env;
+use serde_json::Value;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
+
+// Bound matrix dimensions to keep cycle counts predictable
+const MAX_DIMENSION: usize = 128;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "a": [[1, 2, 3], [4, 5, 6]],        // rows x inner
+    //   "b": [[7, 8], [9, 10], [11, 12]]    // inner x cols
+    // }
+    let inputs_json: String = env::read();
+    
+    let (rows, cols, product) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(a),
+            Some(b)
+        ) = (
+            parsed.get("a"),
+            parsed.get("b")
+        ) {
+            match (parse_matrix(a), parse_matrix(b)) {
+                (Some(a), Some(b)) => {
+                    if a[0].len() != b.len() {
+                        (-1, 0, Vec::new()) // Dimension mismatch: A.cols != B.rows
+                    } else {
+                        match multiply(&a, &b) {
+                            Some(product) => (a.len() as i64, b[0].len() as i64, product.into_iter().flatten().collect()),
+                            None => (-5, 0, Vec::new()) // An entry of the product overflows i64
+                        }
+                    }
+                },
+                _ => (-2, 0, Vec::new()) // Invalid or ragged matrices
+            }
+        } else {
+            (-3, 0, Vec::new()) // Missing required fields
+        }
+    } else {
+        (-4, 0, Vec::new()) // JSON parse error
+    };
+    
+    // Commit the product dimensions (rows is negative on error) and the row-major fixed-point product
+    // Note: The input matrices remain private!
+    env::commit(&rows);
+    env::commit(&cols);
+    env::commit(&product);
+}
+
+// Parse a non-empty rectangular matrix of numbers into fixed-point values
+fn parse_matrix(value: &Value) -> Option<Vec<Vec<i64>>> {
+    let rows = value.as_array()?;
+    if rows.is_empty() || rows.len() > MAX_DIMENSION {
+        return None;
+    }
+    
+    let mut matrix = Vec::with_capacity(rows.len());
+    for row in rows {
+        let row: Option<Vec<i64>> = row
+            .as_array()?
+            .iter()
+            .map(|v| v.as_f64().map(|x| (x * SCALE as f64).round() as i64))
+            .collect();
+        let row = row?;
+        if row.is_empty() || row.len() > MAX_DIMENSION {
+            return None;
+        }
+        if matrix.first().is_some_and(|first: &Vec<i64>| first.len() != row.len()) {
+            return None; // Ragged matrix
+        }
+        matrix.push(row);
+    }
+    
+    Some(matrix)
+}
+
+// A x B for A.cols == B.rows. Products of two fixed-point values are accumulated in i128 and
+// rescaled once per entry; None if a rescaled entry does not fit in an i64.
+fn multiply(a: &[Vec<i64>], b: &[Vec<i64>]) -> Option<Vec<Vec<i64>>> {
+    let (rows, inner, cols) = (a.len(), b.len(), b[0].len());
+    let mut product = vec![vec![0i64; cols]; rows];
+    
+    for r in 0..rows {
+        for c in 0..cols {
+            let mut acc: i128 = 0;
+            for k in 0..inner {
+                acc += a[r][k] as i128 * b[k][c] as i128;
+            }
+            product[r][c] = i64::try_from(acc / SCALE as i128).ok()?;
+        }
+    }
+    
+    Some(product)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_multiply_known_matrices() {
+        let a = parse_matrix(&json!([[1, 2, 3], [4, 5, 6]])).unwrap();
+        let b = parse_matrix(&json!([[7, 8], [9, 10], [11, 12]])).unwrap();
+        let product = multiply(&a, &b).unwrap();
+        assert_eq!(product, vec![vec![58 * SCALE, 64 * SCALE], vec![139 * SCALE, 154 * SCALE]]);
+    }
+
+    #[test]
+    fn test_multiply_fractional_entries() {
+        let a = parse_matrix(&json!([[0.5, -0.25]])).unwrap();
+        let b = parse_matrix(&json!([[4], [2]])).unwrap();
+        assert_eq!(multiply(&a, &b).unwrap(), vec![vec![150000]]);
+    }
+
+    #[test]
+    fn test_overflow_is_detected() {
+        let a = vec![vec![i64::MAX]];
+        let b = vec![vec![2 * SCALE]];
+        assert!(multiply(&a, &b).is_none());
+    }
+}
@@ -0,0 +1,132 @@
+// Minimum spanning tree weight with zero-knowledge proof
+// This computes the total weight of a private graph's MST (Kruskal) and reveals only that weight
+// and a hash binding the proof to the edge list
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Bound the graph size to keep cycle counts predictable
+const MAX_NODES: usize = 1024;
+const MAX_EDGES: usize = 8192;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "nodes": 4,                                   // nodes are numbered 0..nodes
+    //   "edges": [[0, 1, 1.0], [1, 2, 2.5], [2, 3, 1.0], [0, 3, 4.0]]   // undirected [u, v, weight]
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, total_weight, edges_hash) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(nodes),
+            Some(edges)
+        ) = (
+            parsed.get("nodes").and_then(|v| v.as_u64()),
+            parsed.get("edges").and_then(|v| v.as_array())
+        ) {
+            // Convert edges to Vec<(usize, usize, i64)> with fixed-point weights
+            let edges: Option<Vec<(usize, usize, i64)>> = edges
+                .iter()
+                .map(|edge| {
+                    let edge = edge.as_array().filter(|e| e.len() == 3)?;
+                    let weight = edge[2].as_f64().filter(|w| w.is_finite())?;
+                    Some((edge[0].as_u64()? as usize, edge[1].as_u64()? as usize, (weight * SCALE).round() as i64))
+                })
+                .collect();
+            let nodes = nodes as usize;
+            
+            match edges {
+                Some(edges) => {
+                    if nodes == 0 || nodes > MAX_NODES || edges.len() > MAX_EDGES {
+                        (-1, 0, Digest::ZERO) // Empty or oversized graph
+                    } else if edges.iter().any(|&(u, v, _)| u >= nodes || v >= nodes) {
+                        (-2, 0, Digest::ZERO) // Edge refers to an unknown node
+                    } else {
+                        match mst_weight(nodes, &edges) {
+                            Some(weight) => ((nodes - 1) as i64, weight, hash_edges(&edges)),
+                            None => (-5, 0, Digest::ZERO) // Graph is disconnected: no spanning tree
+                        }
+                    }
+                },
+                None => (-2, 0, Digest::ZERO) // Invalid edge data
+            }
+        } else {
+            (-3, 0, Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, 0, Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit the number of tree edges (or error code), the fixed-point MST weight and the edge list hash
+    // Note: The edges themselves remain private!
+    env::commit(&status);
+    env::commit(&total_weight);
+    env::commit(&edges_hash);
+}
+
+// Kruskal's algorithm with a path-compressing union-find; None if the graph is disconnected
+fn mst_weight(nodes: usize, edges: &[(usize, usize, i64)]) -> Option<i64> {
+    let mut sorted = edges.to_vec();
+    sorted.sort_by_key(|&(_, _, weight)| weight);
+    
+    let mut parent: Vec<usize> = (0..nodes).collect();
+    let mut total = 0i64;
+    let mut tree_edges = 0;
+    for (u, v, weight) in sorted {
+        let (root_u, root_v) = (find(&mut parent, u), find(&mut parent, v));
+        if root_u != root_v {
+            parent[root_u] = root_v;
+            total = total.checked_add(weight)?;
+            tree_edges += 1;
+        }
+    }
+    (tree_edges == nodes - 1).then_some(total)
+}
+
+fn find(parent: &mut [usize], node: usize) -> usize {
+    let mut root = node;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    let mut current = node;
+    while parent[current] != root {
+        let next = parent[current];
+        parent[current] = root;
+        current = next;
+    }
+    root
+}
+
+// SHA-256 over the edges in input order, each as little-endian (u, v, weight)
+fn hash_edges(edges: &[(usize, usize, i64)]) -> Digest {
+    let mut bytes = Vec::with_capacity(edges.len() * 24);
+    for &(u, v, weight) in edges {
+        bytes.extend_from_slice(&(u as u64).to_le_bytes());
+        bytes.extend_from_slice(&(v as u64).to_le_bytes());
+        bytes.extend_from_slice(&weight.to_le_bytes());
+    }
+    *Impl::hash_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const S: i64 = SCALE as i64;
+
+    #[test]
+    fn test_known_mst_weight() {
+        // Square with one diagonal: the MST takes 0-1 (1), 2-3 (1) and 1-2 (2), skipping 0-3 (4) and 0-2 (3)
+        let edges = vec![(0, 1, S), (1, 2, 2 * S), (2, 3, S), (0, 3, 4 * S), (0, 2, 3 * S)];
+        assert_eq!(mst_weight(4, &edges), Some(4 * S));
+        assert_eq!(mst_weight(1, &[]), Some(0));
+    }
+
+    #[test]
+    fn test_disconnected_graph() {
+        assert_eq!(mst_weight(4, &[(0, 1, S), (2, 3, S)]), None);
+    }
+}
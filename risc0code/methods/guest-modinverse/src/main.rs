@@ -0,0 +1,78 @@
+// Modular multiplicative inverse with zero-knowledge proof
+// This proves a^(-1) mod n computed with the extended Euclidean algorithm. When gcd(a, n) != 1
+// (or n is 0) no inverse exists, and that is reported through a flag instead of a bogus value.
+//
+// Journal layout (risc0-serde, 28 bytes, all little-endian):
+//   bytes  0..8   a        u64
+//   bytes  8..16  n        u64, the modulus
+//   bytes 16..24  inverse  u64, in [0, n) with a * inverse = 1 mod n (0 unless flag is 0)
+//   bytes 24..28  flag     u32, 0 = ok, 1 = not invertible
+use risc0_zkvm::guest::env;
+
+// Journal flag values
+const OK: u32 = 0;
+const NOT_INVERTIBLE: u32 = 1;
+
+fn main() {
+    // Read the value and the modulus from the host
+    let a: u64 = env::read();
+    let n: u64 = env::read();
+
+    let (inverse, flag) = match modular_inverse(a, n) {
+        Some(inverse) => (inverse, OK),
+        None => (0, NOT_INVERTIBLE),
+    };
+
+    // Commit the inputs, the inverse and the flag to the journal for verification
+    env::commit(&a);
+    env::commit(&n);
+    env::commit(&inverse);
+    env::commit(&flag);
+}
+
+// Extended Euclidean algorithm on i128 so the Bezout coefficients of full-width u64 inputs fit
+fn modular_inverse(a: u64, n: u64) -> Option<u64> {
+    if n == 0 {
+        return None;
+    }
+    let (mut old_r, mut r) = ((a % n) as i128, n as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    // old_r is gcd(a, n) and old_s * a = gcd (mod n)
+    if old_r != 1 {
+        return None;
+    }
+    Some(old_s.rem_euclid(n as i128) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse_times_value_is_one() {
+        assert_eq!(modular_inverse(3, 11), Some(4));
+        assert_eq!(modular_inverse(17, 3120), Some(2753));
+        // Full-width operands: the product is checked in u128
+        let (a, n) = (u64::MAX - 1, u64::MAX);
+        let inverse = modular_inverse(a, n).unwrap();
+        assert_eq!((a as u128 * inverse as u128) % n as u128, 1);
+    }
+
+    #[test]
+    fn test_not_coprime_has_no_inverse() {
+        assert_eq!(modular_inverse(6, 9), None);
+        assert_eq!(modular_inverse(0, 7), None);
+        assert_eq!(modular_inverse(5, 0), None);
+    }
+
+    #[test]
+    fn test_modulus_one() {
+        // Everything is congruent to 0 mod 1, and 0 is its own inverse there
+        assert_eq!(modular_inverse(5, 1), Some(0));
+    }
+}
@@ -1,24 +1,53 @@
-#![no_main]
-#![no_std]
-
+// Fixed-point multiplication with zero-knowledge proof
+// The product is computed with `checked_mul`; inputs whose product does not fit in an i64 set the
+// overflow flag instead of committing a wrapped result that would still verify.
+//
+// Journal layout (risc0-serde, 28 bytes, all little-endian):
+//   bytes  0..8   a         i64, fixed-point
+//   bytes  8..16  b         i64, fixed-point
+//   bytes 16..24  result    i64, fixed-point (a * b) / SCALE (0 unless flag is 0)
+//   bytes 24..28  flag      u32, 0 = ok, 1 = overflow
 use risc0_zkvm::guest::env;
 
-risc0_zkvm::guest::entry!(main);
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
 
-// Fixed-point arithmetic scale factor (5 decimal places for better precision)
-const SCALE: i64 = 100000;
+// Journal flag values
+const OK: u32 = 0;
+const OVERFLOW: u32 = 1;
 
 fn main() {
     // Read two fixed-point numbers (scaled integers) from the host
     let a: i64 = env::read();
     let b: i64 = env::read();
-    
-    // Perform fixed-point multiplication
-    // When multiplying two scaled numbers, we need to divide by the scale to maintain precision
-    let result = (a * b) / SCALE;
-    
-    // Commit the original inputs and result to the journal for verification
+
+    let (result, flag) = match multiply_fixed(a, b) {
+        Some(result) => (result, OK),
+        None => (0, OVERFLOW),
+    };
+
+    // Commit the original inputs, the result and the overflow flag to the journal for verification
     env::commit(&a);
     env::commit(&b);
     env::commit(&result);
-}
\ No newline at end of file
+    env::commit(&flag);
+}
+
+// Perform fixed-point multiplication
+// When multiplying two scaled numbers, we need to divide by the scale to maintain precision
+fn multiply_fixed(a: i64, b: i64) -> Option<i64> {
+    a.checked_mul(b).map(|product| product / SCALE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_point_product() {
+        // 2.5 * 4.0 = 10.0, encoded with the scale the host uses
+        let (a, b) = (25 * SCALE / 10, 4 * SCALE);
+        assert_eq!(multiply_fixed(a, b), Some(10 * SCALE));
+        assert_eq!(multiply_fixed(i64::MAX, 2), None);
+    }
+}
@@ -2,6 +2,9 @@
 // This performs inference on a pre-trained single-layer perceptron without revealing weights
 use risc0_zkvm::guest::env;
 
+// Scale of the committed result, shared with the host's decoder
+use fixed_point::REGRESSION_SCALE;
+
 fn main() {
     // Read input from the host - expects:
     // {
@@ -32,7 +35,7 @@ fn main() {
                     if !x.is_empty() && learning_rate > 0.0 && epochs > 0 {
                         let result = neural_network_train_and_predict(&x, learning_rate, epochs as usize);
                         // Scale result by 1000 for precision
-                        (result * 1000.0) as i64
+                        (result * REGRESSION_SCALE as f64) as i64
                     } else {
                         -1 // Invalid parameters
                     }
@@ -0,0 +1,116 @@
+// Pedersen-style commitment opening with zero-knowledge proof
+// This proves knowledge of a value and blinding factor that open a published commitment,
+// optionally also proving the value lies in a public range, without revealing either
+//
+// Commitment scheme: C = G^value * H^blinding mod P, in the multiplicative group of the
+// Mersenne prime P = 2^61 - 1. G = 37 is a primitive root of P; H is derived by hashing
+// (first 8 bytes, little-endian, of SHA-256("risc0mcp-pedersen-h") mod P) so nobody chose
+// log_G(H). Hiding: a uniformly random blinding makes C uniformly distributed whatever the
+// value. Binding rests on discrete log being hard in this group; at 61 bits it is NOT, so
+// these are demonstration parameters for the proof flow, not for protecting real secrets.
+// Commitments are additively homomorphic: C(v1, r1) * C(v2, r2) = C(v1 + v2, r1 + r2).
+use risc0_zkvm::guest::env;
+
+// Group modulus 2^61 - 1 and generators
+const P: u64 = (1 << 61) - 1;
+const G: u64 = 37;
+const H: u64 = 686264692327659184;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "value": 4200,                        // private committed value
+    //   "blinding": 9182736450192837,         // private random blinding factor
+    //   "range": {"min": 0, "max": 10000}     // optional public range the value must fall in
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, commitment, range) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(value),
+            Some(blinding)
+        ) = (
+            parsed.get("value").and_then(|v| v.as_u64()),
+            parsed.get("blinding").and_then(|v| v.as_u64())
+        ) {
+            let commitment = commit(value, blinding);
+            match parsed.get("range") {
+                None => (1, commitment, None),
+                Some(range) => match (range.get("min").and_then(|v| v.as_u64()), range.get("max").and_then(|v| v.as_u64())) {
+                    (Some(min), Some(max)) => match in_range(value, min, max) {
+                        Some(in_range) => (1, commitment, Some((min, max, in_range))),
+                        None => (-5, 0, None) // min must not exceed max
+                    },
+                    _ => (-2, 0, None) // Range needs integer min and max
+                }
+            }
+        } else {
+            (-3, 0, None) // Missing fields; value and blinding must be non-negative integers
+        }
+    } else {
+        (-4, 0, None) // JSON parse error
+    };
+    
+    // Commit the status, the commitment C, and (min, max, in_range) when a range was requested
+    // Note: The value and blinding factor remain private!
+    env::commit(&status);
+    env::commit(&commitment);
+    env::commit(&range);
+}
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % P as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64) -> u64 {
+    let mut result = 1;
+    base %= P;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base);
+        }
+        base = mul_mod(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+// C = G^value * H^blinding mod P
+fn commit(value: u64, blinding: u64) -> u64 {
+    mul_mod(pow_mod(G, value), pow_mod(H, blinding))
+}
+
+// Whether min <= value <= max; None for an empty range
+fn in_range(value: u64, min: u64, max: u64) -> Option<bool> {
+    (min <= max).then(|| (min..=max).contains(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use risc0_zkvm::sha::{Impl, Sha256};
+
+    #[test]
+    fn test_commitment_is_deterministic() {
+        assert_eq!(commit(4200, 9182736450192837), commit(4200, 9182736450192837));
+        // A different blinding hides the same value behind a different commitment
+        assert_ne!(commit(4200, 9182736450192837), commit(4200, 9182736450192838));
+        // Homomorphic: commitments multiply as their openings add
+        assert_eq!(mul_mod(commit(10, 3), commit(32, 4)), commit(42, 7));
+    }
+
+    #[test]
+    fn test_generator_h_is_derived_from_hash() {
+        let digest = Impl::hash_bytes(b"risc0mcp-pedersen-h");
+        let bytes: [u8; 8] = digest.as_bytes()[..8].try_into().unwrap();
+        assert_eq!(u64::from_le_bytes(bytes) % P, H);
+    }
+
+    #[test]
+    fn test_range_sub_check() {
+        assert_eq!(in_range(4200, 0, 10000), Some(true));
+        assert_eq!(in_range(10000, 0, 10000), Some(true));
+        assert_eq!(in_range(10001, 0, 10000), Some(false));
+        assert_eq!(in_range(5, 10, 0), None);
+    }
+}
@@ -0,0 +1,65 @@
+// Permission bitmask check with zero-knowledge proof
+// This proves a private permission set grants every required permission without revealing the set itself
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "permissions": 45,   // private bitmask of granted permissions (bit i = permission i)
+    //   "required": 12       // public bitmask of permissions the caller must hold
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, required) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(permissions),
+            Some(required)
+        ) = (
+            parsed.get("permissions"),
+            parsed.get("required")
+        ) {
+            match (permissions.as_u64(), required.as_u64()) {
+                (Some(_), Some(0)) => (-1, 0), // Requiring nothing proves nothing
+                (Some(permissions), Some(required)) => {
+                    (if has_required(permissions, required) { 1 } else { 0 }, required)
+                },
+                _ => (-2, 0) // Masks must be non-negative integers that fit in 64 bits
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit whether all required permissions are present (1), missing (0) or the input was invalid (negative),
+    // and the required mask that was checked
+    // Note: The full permission set remains private!
+    env::commit(&status);
+    env::commit(&required);
+}
+
+// Every bit set in `required` must also be set in `permissions`
+fn has_required(permissions: u64, required: u64) -> bool {
+    permissions & required == required
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_superset_passes() {
+        // 0b101101 grants permissions 0, 2, 3 and 5; 0b1100 asks for 2 and 3
+        assert!(has_required(0b101101, 0b1100));
+        assert!(has_required(u64::MAX, 1 << 63));
+        assert!(has_required(0b1100, 0b1100));
+    }
+
+    #[test]
+    fn test_missing_permission_fails() {
+        // Permission 1 is required but not granted
+        assert!(!has_required(0b101101, 0b0110));
+        assert!(!has_required(0, 1));
+    }
+}
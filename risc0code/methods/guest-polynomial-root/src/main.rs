@@ -0,0 +1,97 @@
+// Polynomial root knowledge with zero-knowledge proof
+// This proves a private candidate solves a polynomial equation, revealing only the outcome and a hash of the coefficients
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// |p(x)| at or below this counts as a root, absorbing fixed-point rounding of the coefficients
+const TOLERANCE: f64 = 1e-6;
+
+// Bound the polynomial degree to keep cycle counts predictable
+const MAX_COEFFS: usize = 64;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "coeffs": [-6.0, 1.0, 1.0],   // ascending powers: -6 + x + x^2
+    //   "x": 2.0                      // private candidate root
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, coeffs_hash) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(coeffs),
+            Some(x)
+        ) = (
+            parsed.get("coeffs").and_then(|v| v.as_array()),
+            parsed.get("x").and_then(|v| v.as_f64())
+        ) {
+            // Convert coefficients to fixed point so the hash commits to exactly what is evaluated
+            let coeff_vec: Result<Vec<i64>, _> = coeffs
+                .iter()
+                .map(|v| v.as_f64().map(|c| (c * SCALE).round() as i64).ok_or("Invalid coefficient"))
+                .collect();
+            
+            match coeff_vec {
+                Ok(c) => {
+                    if !c.is_empty() && c.len() <= MAX_COEFFS {
+                        (if is_root(&c, x) { 1 } else { 0 }, hash_coeffs(&c))
+                    } else {
+                        (-1, Digest::ZERO) // Empty or oversized polynomial
+                    }
+                },
+                _ => (-2, Digest::ZERO) // Invalid coefficient data
+            }
+        } else {
+            (-3, Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit only whether the candidate is a root (1), is not (0) or the input was invalid (negative),
+    // together with the polynomial hash
+    // Note: The candidate solution remains private!
+    env::commit(&status);
+    env::commit(&coeffs_hash);
+}
+
+// Evaluate the fixed-point polynomial at x with Horner's rule and compare against the tolerance
+fn is_root(coeffs: &[i64], x: f64) -> bool {
+    let value = coeffs.iter().rev().fold(0.0, |acc, &c| acc * x + c as f64 / SCALE);
+    value.is_finite() && value.abs() <= TOLERANCE
+}
+
+// SHA-256 over the little-endian fixed-point coefficients in ascending order
+fn hash_coeffs(coeffs: &[i64]) -> Digest {
+    let bytes: Vec<u8> = coeffs.iter().flat_map(|c| c.to_le_bytes()).collect();
+    *Impl::hash_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed(coeffs: &[f64]) -> Vec<i64> {
+        coeffs.iter().map(|c| (c * SCALE).round() as i64).collect()
+    }
+
+    #[test]
+    fn test_correct_root() {
+        // x^2 + x - 6 = (x - 2)(x + 3)
+        let coeffs = fixed(&[-6.0, 1.0, 1.0]);
+        assert!(is_root(&coeffs, 2.0));
+        assert!(is_root(&coeffs, -3.0));
+        assert!(is_root(&fixed(&[-2.5, 1.0]), 2.5));
+    }
+
+    #[test]
+    fn test_incorrect_root() {
+        let coeffs = fixed(&[-6.0, 1.0, 1.0]);
+        assert!(!is_root(&coeffs, 1.0));
+        assert!(!is_root(&coeffs, 2.001));
+        assert!(!is_root(&fixed(&[1.0]), 0.0));
+    }
+}
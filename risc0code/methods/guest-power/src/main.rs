@@ -0,0 +1,59 @@
+// Plain (non-modular) exponentiation with overflow detection
+// This proves base^exponent for u64 operands. The power is computed with `checked_pow`, so a result
+// beyond u64 is flagged instead of committed as a wrapped value.
+//
+// Journal layout (risc0-serde, 28 bytes, all little-endian):
+//   bytes  0..8   base      u64
+//   bytes  8..16  exponent  u64
+//   bytes 16..24  result    u64, 0 when the power overflows
+//   bytes 24..28  overflow  u32, 1 when base^exponent exceeds u64::MAX, else 0
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Read the base and the exponent from the host
+    let base: u64 = env::read();
+    let exponent: u64 = env::read();
+
+    let (result, overflow) = match checked_power(base, exponent) {
+        Some(result) => (result, 0u32),
+        None => (0, 1u32),
+    };
+
+    // Commit the inputs, the result and the overflow flag to the journal for verification
+    env::commit(&base);
+    env::commit(&exponent);
+    env::commit(&result);
+    env::commit(&overflow);
+}
+
+// `checked_pow` takes a u32 exponent; past that only 0 and 1 have a power that fits in u64
+fn checked_power(base: u64, exponent: u64) -> Option<u64> {
+    match base {
+        0 | 1 => Some(if exponent == 0 { 1 } else { base }),
+        _ => base.checked_pow(u32::try_from(exponent).ok()?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powers_in_range() {
+        assert_eq!(checked_power(2, 10), Some(1024));
+        assert_eq!(checked_power(7, 0), Some(1));
+        assert_eq!(checked_power(0, 0), Some(1));
+        assert_eq!(checked_power(2, 63), Some(1 << 63));
+        assert_eq!(checked_power(u64::MAX, 1), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_overflow_is_detected() {
+        assert_eq!(checked_power(2, 64), None);
+        assert_eq!(checked_power(10, 20), None);
+        // Exponents beyond u32 overflow for every base but 0 and 1
+        assert_eq!(checked_power(2, u64::from(u32::MAX) + 1), None);
+        assert_eq!(checked_power(1, u64::MAX), Some(1));
+        assert_eq!(checked_power(0, u64::MAX), Some(0));
+    }
+}
@@ -0,0 +1,111 @@
+// Quadratic voting tally with zero-knowledge proof
+// This totals each option's voting power (sqrt of credits spent) without revealing any voter's allocation
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
+
+// Bound the ballot size to keep cycle counts predictable
+const MAX_VOTERS: usize = 10000;
+const MAX_OPTIONS: usize = 64;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "credits": [[4, 9, 0], [16, 1, 1]]   // one row per voter, credits spent on each option
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, totals) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let Some(credits) = parsed.get("credits").and_then(|v| v.as_array()) {
+            // Convert ballots to Vec<Vec<u64>>
+            let ballots: Result<Vec<Vec<u64>>, _> = credits
+                .iter()
+                .map(|ballot| {
+                    ballot
+                        .as_array()
+                        .ok_or("Invalid ballot")?
+                        .iter()
+                        .map(|v| v.as_u64().ok_or("Invalid credit amount"))
+                        .collect()
+                })
+                .collect();
+            
+            match ballots {
+                Ok(ballots) => {
+                    let options = ballots.first().map_or(0, |ballot| ballot.len());
+                    if ballots.is_empty() || ballots.len() > MAX_VOTERS || options == 0 || options > MAX_OPTIONS {
+                        (-1, Vec::new()) // Empty or oversized ballot set
+                    } else if ballots.iter().any(|ballot| ballot.len() != options) {
+                        (-5, Vec::new()) // Every voter must allocate across the same options
+                    } else {
+                        (ballots.len() as i64, tally(&ballots, options))
+                    }
+                },
+                _ => (-2, Vec::new()) // Invalid ballot data
+            }
+        } else {
+            (-3, Vec::new()) // Missing required fields
+        }
+    } else {
+        (-4, Vec::new()) // JSON parse error
+    };
+    
+    // Commit the voter count (or error code) and the fixed-point voting power per option
+    // Note: Individual credit allocations remain private!
+    env::commit(&status);
+    env::commit(&totals);
+}
+
+// Sum sqrt(credits) per option across all voters, in fixed point
+fn tally(ballots: &[Vec<u64>], options: usize) -> Vec<i64> {
+    let mut totals = vec![0i64; options];
+    for ballot in ballots {
+        for (total, &credits) in totals.iter_mut().zip(ballot.iter()) {
+            *total += fixed_point_sqrt(credits);
+        }
+    }
+    totals
+}
+
+// Fixed-point square root using binary search, as in the sqrt guest:
+// the largest x with x² ≤ credits·SCALE², i.e. floor(sqrt(credits) · SCALE)
+fn fixed_point_sqrt(credits: u64) -> i64 {
+    let scale = SCALE as i128;
+    let target = credits as i128 * scale * scale;
+    let mut left = 0i128;
+    let mut right = (credits as i128 + 1) * scale;
+    let mut result = 0i128;
+    
+    while left <= right {
+        let mid = left + (right - left) / 2;
+        if mid * mid <= target {
+            result = mid;
+            left = mid + 1;
+        } else {
+            right = mid - 1;
+        }
+    }
+    
+    result as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_totals() {
+        // Option 0: sqrt(4) + sqrt(16) = 6, option 1: sqrt(9) + sqrt(1) = 4, option 2: 0 + 1 = 1
+        let ballots = vec![vec![4, 9, 0], vec![16, 1, 1]];
+        assert_eq!(tally(&ballots, 3), vec![600000, 400000, 100000]);
+    }
+
+    #[test]
+    fn test_fixed_point_sqrt() {
+        assert_eq!(fixed_point_sqrt(0), 0);
+        assert_eq!(fixed_point_sqrt(2), 141421);
+        assert_eq!(fixed_point_sqrt(100), 1000000);
+        assert_eq!(fixed_point_sqrt(u32::MAX as u64), 6553599999);
+    }
+}
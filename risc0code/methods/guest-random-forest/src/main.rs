@@ -0,0 +1,184 @@
+// Random forest inference with zero-knowledge proof
+// This evaluates a private forest of decision trees on a feature vector and reveals only the
+// combined prediction (majority vote or average), keeping every tree's structure private
+use risc0_zkvm::guest::env;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Bound the forest to keep cycle counts predictable
+const MAX_TREES: usize = 64;
+const MAX_NODES: usize = 1024;
+const MAX_DEPTH: usize = 32;
+const MAX_FEATURES: usize = 256;
+
+// One node of a tree: split on `feature <= threshold` (left) or a leaf prediction, both fixed point
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Node {
+    Split { feature: usize, threshold: i64, left: usize, right: usize },
+    Leaf(i64),
+}
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "trees": [
+    //     [                                                               // nodes of one tree, root first
+    //       {"feature": 0, "threshold": 2.5, "left": 1, "right": 2},      // features[0] <= 2.5 goes left
+    //       {"leaf": 0},
+    //       {"leaf": 1}
+    //     ]
+    //   ],
+    //   "features": [3.0, 1.2],
+    //   "mode": "vote"                                                    // optional: "vote" (default) or "average"
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, averaged, prediction, agreeing) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(trees),
+            Some(features)
+        ) = (
+            parsed.get("trees").and_then(|v| v.as_array()),
+            parsed.get("features").and_then(|v| v.as_array())
+        ) {
+            let averaged = parsed.get("mode").and_then(|v| v.as_str()) == Some("average");
+            
+            // Convert trees to Vec<Vec<Node>> and features to fixed point
+            let forest: Option<Vec<Vec<Node>>> = trees
+                .iter()
+                .map(|tree| tree.as_array().and_then(|nodes| nodes.iter().map(parse_node).collect()))
+                .collect();
+            let features: Option<Vec<i64>> = features
+                .iter()
+                .map(|v| v.as_f64().map(|x| (x * SCALE).round() as i64))
+                .collect();
+            
+            match (forest, features) {
+                (Some(forest), Some(features)) => {
+                    if forest.is_empty() || forest.len() > MAX_TREES
+                        || forest.iter().any(|tree| tree.is_empty() || tree.len() > MAX_NODES)
+                        || features.is_empty() || features.len() > MAX_FEATURES {
+                        (-1, averaged, 0, 0) // Empty or oversized forest or feature vector
+                    } else {
+                        let leaves: Option<Vec<i64>> = forest.iter().map(|tree| evaluate(tree, &features)).collect();
+                        match leaves {
+                            Some(leaves) if averaged => (forest.len() as i64, true, average(&leaves), leaves.len() as u64),
+                            Some(leaves) => {
+                                let (winner, votes) = majority(&leaves);
+                                (forest.len() as i64, false, winner, votes)
+                            },
+                            None => (-5, averaged, 0, 0) // Malformed tree: bad index, unknown feature or too deep
+                        }
+                    }
+                },
+                _ => (-2, averaged, 0, 0) // Invalid node or feature data
+            }
+        } else {
+            (-3, false, 0, 0) // Missing required fields
+        }
+    } else {
+        (-4, false, 0, 0) // JSON parse error
+    };
+    
+    // Commit the number of trees (or error code), whether predictions were averaged,
+    // the fixed-point combined prediction and how many trees agree with it
+    // Note: The trees and the feature vector remain private!
+    env::commit(&status);
+    env::commit(&averaged);
+    env::commit(&prediction);
+    env::commit(&agreeing);
+}
+
+fn parse_node(node: &Value) -> Option<Node> {
+    if let Some(leaf) = node.get("leaf") {
+        return Some(Node::Leaf((leaf.as_f64()? * SCALE).round() as i64));
+    }
+    Some(Node::Split {
+        feature: node.get("feature")?.as_u64()? as usize,
+        threshold: (node.get("threshold")?.as_f64()? * SCALE).round() as i64,
+        left: node.get("left")?.as_u64()? as usize,
+        right: node.get("right")?.as_u64()? as usize,
+    })
+}
+
+// Walk one tree from its root; None if an index is out of range or the path exceeds MAX_DEPTH
+fn evaluate(tree: &[Node], features: &[i64]) -> Option<i64> {
+    let mut index = 0;
+    for _ in 0..=MAX_DEPTH {
+        match *tree.get(index)? {
+            Node::Leaf(value) => return Some(value),
+            Node::Split { feature, threshold, left, right } => {
+                index = if *features.get(feature)? <= threshold { left } else { right };
+            }
+        }
+    }
+    None
+}
+
+// Most common leaf value and its vote count; ties go to the lowest value
+fn majority(leaves: &[i64]) -> (i64, u64) {
+    let mut votes: BTreeMap<i64, u64> = BTreeMap::new();
+    for &leaf in leaves {
+        *votes.entry(leaf).or_insert(0) += 1;
+    }
+    let mut best = (i64::MAX, 0);
+    for (value, count) in votes {
+        if count > best.1 {
+            best = (value, count);
+        }
+    }
+    best
+}
+
+fn average(leaves: &[i64]) -> i64 {
+    (leaves.iter().map(|&v| v as i128).sum::<i128>() / leaves.len() as i128) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const S: i64 = SCALE as i64;
+
+    // Single split on `feature`: at or below `threshold` predicts `low`, above predicts `high`
+    fn stump(feature: usize, threshold: i64, low: i64, high: i64) -> Vec<Node> {
+        vec![
+            Node::Split { feature, threshold: threshold * S, left: 1, right: 2 },
+            Node::Leaf(low * S),
+            Node::Leaf(high * S),
+        ]
+    }
+
+    #[test]
+    fn test_trees_agree() {
+        let forest = [stump(0, 5, 0, 1), stump(1, 2, 0, 1), stump(0, 3, 0, 1)];
+        let features = vec![7 * S, 4 * S];
+        let leaves: Vec<i64> = forest.iter().map(|tree| evaluate(tree, &features).unwrap()).collect();
+        assert_eq!(majority(&leaves), (S, 3));
+        assert_eq!(average(&leaves), S);
+    }
+
+    #[test]
+    fn test_trees_split() {
+        // Feature 0 = 4 sends the first tree right (1) and the others left (0)
+        let forest = [stump(0, 3, 0, 1), stump(0, 5, 0, 1), stump(1, 9, 0, 1)];
+        let features = vec![4 * S, 4 * S];
+        let leaves: Vec<i64> = forest.iter().map(|tree| evaluate(tree, &features).unwrap()).collect();
+        assert_eq!(majority(&leaves), (0, 2));
+        assert_eq!(average(&leaves), 33333);
+        
+        // An even split breaks toward the lower prediction
+        assert_eq!(majority(&leaves[..2]), (0, 1));
+    }
+
+    #[test]
+    fn test_malformed_trees_are_rejected() {
+        let features = vec![S];
+        assert_eq!(evaluate(&stump(3, 1, 0, 1), &features), None); // Unknown feature
+        let cyclic = vec![Node::Split { feature: 0, threshold: 0, left: 0, right: 0 }];
+        assert_eq!(evaluate(&cyclic, &features), None);
+    }
+}
@@ -13,12 +13,25 @@ fn main() {
     let min_value: u64 = env::read();
     let max_value: u64 = env::read();
     
-    // Perform the range check
-    let in_range = secret_number >= min_value && secret_number <= max_value;
+    // Read whether each bound is exclusive (1) or inclusive (0)
+    let min_exclusive: u32 = env::read();
+    let max_exclusive: u32 = env::read();
+    
+    // A range no integer satisfies (min > max, or e.g. [5, 5)) makes the check below always report
+    // "not in range"; flag it so the statement is not mistaken for a meaningful negative result
+    let lowest = min_value.checked_add((min_exclusive != 0) as u64);
+    let highest = max_value.checked_sub((max_exclusive != 0) as u64);
+    let invalid_range: u32 = match (lowest, highest) {
+        (Some(lowest), Some(highest)) if lowest <= highest => 0,
+        _ => 1,
+    };
     
-    // Also compute some derived values for additional verification
-    let above_min = secret_number >= min_value;
-    let below_max = secret_number <= max_value;
+    // Check each bound with the requested semantics
+    let above_min = if min_exclusive != 0 { secret_number > min_value } else { secret_number >= min_value };
+    let below_max = if max_exclusive != 0 { secret_number < max_value } else { secret_number <= max_value };
+    
+    // Perform the range check
+    let in_range = above_min && below_max;
     
     // Commit the range check result to the journal
     // This proves the secret number is (or isn't) in the specified range
@@ -27,7 +40,11 @@ fn main() {
     env::commit(&above_min);
     env::commit(&below_max);
     
-    // Commit the range bounds for verification
+    // Commit the range bounds for verification, whether they were empty, and which semantics the
+    // bounds were checked with
     env::commit(&min_value);
     env::commit(&max_value);
+    env::commit(&invalid_range);
+    env::commit(&min_exclusive);
+    env::commit(&max_exclusive);
 }
\ No newline at end of file
@@ -0,0 +1,97 @@
+// Recommendation scoring with zero-knowledge proof
+// This scores a user/item embedding pair without revealing either vector
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "user": [0.2, 0.8, 0.1],
+    //   "item": [0.3, 0.7, 0.0],
+    //   "metric": "cosine"   // or "dot"
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, score) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(user),
+            Some(item)
+        ) = (
+            parsed.get("user").and_then(|v| v.as_array()),
+            parsed.get("item").and_then(|v| v.as_array())
+        ) {
+            let metric = parsed.get("metric").and_then(|v| v.as_str()).unwrap_or("cosine");
+            
+            // Convert vectors to Vec<f64>
+            let user_vec: Result<Vec<f64>, _> = user.iter().map(|v| v.as_f64().ok_or("Invalid user feature")).collect();
+            let item_vec: Result<Vec<f64>, _> = item.iter().map(|v| v.as_f64().ok_or("Invalid item feature")).collect();
+            
+            match (user_vec, item_vec) {
+                (Ok(u), Ok(i)) => {
+                    if u.len() == i.len() && !u.is_empty() {
+                        match recommendation_score(&u, &i, metric) {
+                            Some(score) => (u.len() as i64, (score * SCALE).round() as i64),
+                            None => (-5, 0) // Unknown metric or zero-length vector for cosine
+                        }
+                    } else {
+                        (-1, 0) // Mismatched or empty vectors
+                    }
+                },
+                _ => (-2, 0) // Invalid feature data
+            }
+        } else {
+            (-3, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit the vector dimension (or error code) and the fixed-point score
+    // Note: The user and item embeddings remain private!
+    env::commit(&status);
+    env::commit(&score);
+}
+
+// Dot product or cosine similarity between two embeddings
+fn recommendation_score(user: &[f64], item: &[f64], metric: &str) -> Option<f64> {
+    let dot: f64 = user.iter().zip(item.iter()).map(|(u, i)| u * i).sum();
+    match metric {
+        "dot" => Some(dot),
+        "cosine" => {
+            let user_norm = user.iter().map(|u| u * u).sum::<f64>().sqrt();
+            let item_norm = item.iter().map(|i| i * i).sum::<f64>().sqrt();
+            if user_norm == 0.0 || item_norm == 0.0 {
+                None
+            } else {
+                Some(dot / (user_norm * item_norm))
+            }
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aligned_vectors() {
+        let score = recommendation_score(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0], "cosine").unwrap();
+        assert!((score - 1.0).abs() < 1e-12);
+        assert_eq!(recommendation_score(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0], "dot"), Some(28.0));
+    }
+
+    #[test]
+    fn test_orthogonal_vectors() {
+        assert_eq!(recommendation_score(&[1.0, 0.0], &[0.0, 5.0], "cosine"), Some(0.0));
+        assert_eq!(recommendation_score(&[1.0, 0.0], &[0.0, 5.0], "dot"), Some(0.0));
+    }
+
+    #[test]
+    fn test_invalid_metric_or_zero_vector() {
+        assert_eq!(recommendation_score(&[1.0], &[1.0], "euclidean"), None);
+        assert_eq!(recommendation_score(&[0.0, 0.0], &[1.0, 1.0], "cosine"), None);
+    }
+}
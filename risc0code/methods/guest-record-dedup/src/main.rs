@@ -0,0 +1,85 @@
+// Record deduplication check with zero-knowledge proof
+// This proves whether two records are the same entity, by the cosine similarity of their
+// feature vectors against a public threshold, without revealing either record's features
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Bound the vectors to keep cycle counts predictable
+const MAX_FEATURES: usize = 4096;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "record_a": [0.9, 0.1, 0.4],   // private feature vector
+    //   "record_b": [0.8, 0.2, 0.4],   // private feature vector, same length
+    //   "threshold": 0.95              // public: similarity at or above this means duplicate
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, threshold_fixed) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(a),
+            Some(b),
+            Some(threshold)
+        ) = (
+            parsed.get("record_a").and_then(to_vector),
+            parsed.get("record_b").and_then(to_vector),
+            parsed.get("threshold").and_then(|v| v.as_f64()).filter(|t| (-1.0..=1.0).contains(t))
+        ) {
+            let threshold_fixed = (threshold * SCALE).round() as i64;
+            match cosine_similarity(&a, &b) {
+                _ if a.is_empty() || a.len() > MAX_FEATURES => (-1, threshold_fixed), // Empty or oversized vectors
+                _ if a.len() != b.len() => (-5, threshold_fixed), // Records must have the same features
+                Some(similarity) => (if similarity >= threshold { 1 } else { 0 }, threshold_fixed),
+                None => (-6, threshold_fixed) // A zero vector has no direction to compare
+            }
+        } else {
+            (-3, 0) // Missing fields, non-numeric features or a threshold outside [-1, 1]
+        }
+    } else {
+        (-4, 0) // JSON parse error
+    };
+    
+    // Commit whether the records are duplicates (1), distinct (0) or the input was invalid (negative),
+    // and the threshold applied
+    // Note: Both feature vectors and their similarity remain private!
+    env::commit(&status);
+    env::commit(&threshold_fixed);
+}
+
+// Finite numeric features, or None
+fn to_vector(value: &serde_json::Value) -> Option<Vec<f64>> {
+    value.as_array()?.iter().map(|v| v.as_f64().filter(|x| x.is_finite())).collect()
+}
+
+// dot(a, b) / (|a| |b|) for equal-length vectors; None if either is all zeros
+fn cosine_similarity(a: &[f64], b: &[f64]) -> Option<f64> {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|y| y * y).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return None;
+    }
+    Some(dot / (norm_a * norm_b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_pair() {
+        // Same direction at a different magnitude is the same entity
+        assert!((cosine_similarity(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]).unwrap() - 1.0).abs() < 1e-12);
+        assert!(cosine_similarity(&[0.9, 0.1, 0.4], &[0.8, 0.2, 0.4]).unwrap() >= 0.95);
+    }
+
+    #[test]
+    fn test_non_matching_pair() {
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).unwrap().abs() < 1e-12);
+        assert!(cosine_similarity(&[0.9, 0.1, 0.4], &[0.1, 0.9, 0.2]).unwrap() < 0.95);
+        assert!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]).is_none());
+    }
+}
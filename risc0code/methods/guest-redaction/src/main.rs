@@ -0,0 +1,135 @@
+// Document redaction check with zero-knowledge proof
+// This proves a redacted document is the original with only the listed fields removed or masked,
+// revealing the redacted document's hash and a hash of the removed content but not the content itself
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+use serde_json::{Map, Value};
+
+// Placeholder accepted in place of a removed field when no "mask" is given
+const DEFAULT_MASK: &str = "[REDACTED]";
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "original": {"name": "Ada", "ssn": "123-45-6789", "dob": "1815-12-10"},
+    //   "redacted": {"name": "Ada", "ssn": "[REDACTED]"},
+    //   "fields": ["ssn", "dob"],          // top-level fields that must be removed or masked
+    //   "mask": "[REDACTED]"               // optional placeholder for masked fields
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, redacted_hash, removed_hash) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(original),
+            Some(redacted),
+            Some(fields)
+        ) = (
+            parsed.get("original").and_then(|v| v.as_object()),
+            parsed.get("redacted").and_then(|v| v.as_object()),
+            parsed.get("fields").and_then(|v| v.as_array())
+        ) {
+            let mask = parsed.get("mask").and_then(|v| v.as_str()).unwrap_or(DEFAULT_MASK);
+            
+            // Convert field names to Vec<&str>
+            let field_names: Result<Vec<&str>, _> = fields
+                .iter()
+                .map(|v| v.as_str().ok_or("Invalid field name"))
+                .collect();
+            
+            match field_names {
+                Ok(field_names) => {
+                    if !field_names.is_empty() {
+                        let removed = removed_content(original, &field_names);
+                        (
+                            if is_correct_redaction(original, redacted, &field_names, mask) { 1 } else { 0 },
+                            hash_object(redacted),
+                            hash_object(&removed),
+                        )
+                    } else {
+                        (-1, Digest::ZERO, Digest::ZERO) // No fields to redact
+                    }
+                },
+                _ => (-2, Digest::ZERO, Digest::ZERO) // Invalid field list
+            }
+        } else {
+            (-3, Digest::ZERO, Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, Digest::ZERO, Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit whether the redaction is correct (1), incorrect (0) or the input was invalid (negative),
+    // the redacted document hash and the removed content hash
+    // Note: The original document and the removed values remain private!
+    env::commit(&status);
+    env::commit(&redacted_hash);
+    env::commit(&removed_hash);
+}
+
+// Every listed field must be absent from the redacted document or equal to the mask;
+// every other field must be carried over unchanged and nothing may be added
+fn is_correct_redaction(original: &Map<String, Value>, redacted: &Map<String, Value>, fields: &[&str], mask: &str) -> bool {
+    let redacted_ok = fields.iter().all(|field| match redacted.get(*field) {
+        None => true,
+        Some(Value::String(text)) => text == mask,
+        Some(_) => false,
+    });
+    let kept_ok = original
+        .iter()
+        .filter(|(key, _)| !fields.contains(&key.as_str()))
+        .all(|(key, value)| redacted.get(key) == Some(value));
+    let nothing_added = redacted.keys().all(|key| original.contains_key(key));
+    
+    redacted_ok && kept_ok && nothing_added
+}
+
+// The original values of the listed fields that are present
+fn removed_content(original: &Map<String, Value>, fields: &[&str]) -> Map<String, Value> {
+    original
+        .iter()
+        .filter(|(key, _)| fields.contains(&key.as_str()))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+// SHA-256 over the canonical JSON encoding (keys sorted) of an object
+fn hash_object(object: &Map<String, Value>) -> Digest {
+    let canonical = serde_json::to_vec(object).unwrap_or_default();
+    *Impl::hash_bytes(&canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn object(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_correct_redaction() {
+        let original = object(json!({"name": "Ada", "ssn": "123-45-6789", "dob": "1815-12-10"}));
+        let removed = object(json!({"name": "Ada", "dob": "1815-12-10"}));
+        let masked = object(json!({"name": "Ada", "ssn": "[REDACTED]", "dob": "[REDACTED]"}));
+        assert!(is_correct_redaction(&original, &removed, &["ssn"], DEFAULT_MASK));
+        assert!(is_correct_redaction(&original, &masked, &["ssn", "dob"], DEFAULT_MASK));
+        assert_eq!(removed_content(&original, &["ssn"]), object(json!({"ssn": "123-45-6789"})));
+    }
+
+    #[test]
+    fn test_incorrect_redaction() {
+        let original = object(json!({"name": "Ada", "ssn": "123-45-6789", "dob": "1815-12-10"}));
+        // Sensitive field left in place
+        assert!(!is_correct_redaction(&original, &original, &["ssn"], DEFAULT_MASK));
+        // A kept field was altered
+        let altered = object(json!({"name": "Eve", "dob": "1815-12-10"}));
+        assert!(!is_correct_redaction(&original, &altered, &["ssn"], DEFAULT_MASK));
+        // A kept field was dropped
+        let dropped = object(json!({"name": "Ada"}));
+        assert!(!is_correct_redaction(&original, &dropped, &["ssn"], DEFAULT_MASK));
+        // Masked with the wrong placeholder
+        let wrong_mask = object(json!({"name": "Ada", "ssn": "***", "dob": "1815-12-10"}));
+        assert!(!is_correct_redaction(&original, &wrong_mask, &["ssn"], DEFAULT_MASK));
+    }
+}
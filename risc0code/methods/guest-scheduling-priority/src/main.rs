@@ -0,0 +1,103 @@
+// Weighted scheduling priority with zero-knowledge proof
+// This proves the order tasks should run in under a weighted-deadline priority rule
+// without revealing any task's weight or deadline
+use risc0_zkvm::guest::env;
+use std::cmp::Ordering;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Bound the task list to keep cycle counts predictable
+const MAX_TASKS: usize = 1000;
+
+// One task; weight in fixed point, deadline in time units from now
+struct Task {
+    id: u64,
+    weight: i64,
+    deadline: u64,
+}
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "tasks": [                                          // ids public, weights/deadlines private
+    //     {"id": 1, "weight": 3.0, "deadline": 10},
+    //     {"id": 2, "weight": 1.5, "deadline": 2}
+    //   ]
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, order) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let Some(tasks) = parsed.get("tasks").and_then(|v| v.as_array()) {
+            match tasks.iter().map(parse_task).collect::<Option<Vec<Task>>>() {
+                _ if tasks.is_empty() || tasks.len() > MAX_TASKS => (-1, Vec::new()), // No tasks or too many
+                None => (-2, Vec::new()), // Tasks need an integer id, non-negative weight and positive deadline
+                Some(tasks) if has_duplicate_ids(&tasks) => (-5, Vec::new()), // Ids must be unique
+                Some(tasks) => (tasks.len() as i64, schedule(tasks))
+            }
+        } else {
+            (-3, Vec::new()) // Missing required fields
+        }
+    } else {
+        (-4, Vec::new()) // JSON parse error
+    };
+    
+    // Commit the task count (or a negative error code) and the task ids in priority order
+    // Note: Weights and deadlines remain private!
+    env::commit(&status);
+    env::commit(&order);
+}
+
+fn parse_task(task: &serde_json::Value) -> Option<Task> {
+    let weight = task.get("weight")?.as_f64().filter(|w| w.is_finite() && *w >= 0.0)?;
+    Some(Task {
+        id: task.get("id")?.as_u64()?,
+        weight: (weight * SCALE).round() as i64,
+        deadline: task.get("deadline")?.as_u64().filter(|d| *d > 0)?,
+    })
+}
+
+fn has_duplicate_ids(tasks: &[Task]) -> bool {
+    let mut ids: Vec<u64> = tasks.iter().map(|t| t.id).collect();
+    ids.sort_unstable();
+    ids.windows(2).any(|pair| pair[0] == pair[1])
+}
+
+// Highest weight / deadline first, compared exactly by cross-multiplying;
+// ties go to the earlier deadline, then the lower id, so the order is fully deterministic
+fn compare(a: &Task, b: &Task) -> Ordering {
+    let a_priority = a.weight as i128 * b.deadline as i128;
+    let b_priority = b.weight as i128 * a.deadline as i128;
+    b_priority.cmp(&a_priority)
+        .then(a.deadline.cmp(&b.deadline))
+        .then(a.id.cmp(&b.id))
+}
+
+fn schedule(mut tasks: Vec<Task>) -> Vec<u64> {
+    tasks.sort_by(compare);
+    tasks.into_iter().map(|t| t.id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: u64, weight: f64, deadline: u64) -> Task {
+        Task { id, weight: (weight * SCALE).round() as i64, deadline }
+    }
+
+    #[test]
+    fn test_known_priority_order() {
+        // Priorities: 1 -> 0.3, 2 -> 0.75, 3 -> 0.5, 4 -> 0.1
+        let tasks = vec![task(1, 3.0, 10), task(2, 1.5, 2), task(3, 2.0, 4), task(4, 0.5, 5)];
+        assert_eq!(schedule(tasks), vec![2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn test_tie_breaking() {
+        // All priorities equal 0.5: earlier deadline first, then lower id
+        let tasks = vec![task(9, 2.0, 4), task(7, 1.0, 2), task(3, 2.0, 4), task(5, 1.0, 2)];
+        assert_eq!(schedule(tasks), vec![5, 7, 3, 9]);
+        assert!(has_duplicate_ids(&[task(1, 1.0, 1), task(1, 2.0, 2)]));
+    }
+}
@@ -0,0 +1,101 @@
+// Scoring rubric evaluation with zero-knowledge proof
+// This grades a set of answers against a weighted rubric without revealing the answers
+//
+// The pass threshold is a public parameter of the claim, so it is committed: verifiers must check it,
+// or a PASS against a threshold of 0 could stand in.
+//
+// Journal layout (risc0-serde):
+//   total_score  i64, negative on error
+//   passed       bool, total_score >= threshold
+//   threshold    i64 (zero when it could not be read)
+use risc0_zkvm::guest::env;
+use serde_json::Value;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "answers": ["b", "c", 42, "a"],
+    //   "rubric": [{"weight": 2, "key": "b"}, {"weight": 3, "key": "a"}, ...],
+    //   "threshold": 5
+    // }
+    let inputs_json: String = env::read();
+    
+    let (total_score, passed, threshold): (i64, bool, i64) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(answers),
+            Some(rubric),
+            Some(threshold)
+        ) = (
+            parsed.get("answers").and_then(|v| v.as_array()),
+            parsed.get("rubric").and_then(|v| v.as_array()),
+            parsed.get("threshold").and_then(|v| v.as_i64())
+        ) {
+            // Convert rubric entries to (weight, key) pairs
+            let criteria: Result<Vec<(i64, Value)>, _> = rubric
+                .iter()
+                .map(|entry| {
+                    match (entry.get("weight").and_then(|w| w.as_i64()), entry.get("key")) {
+                        (Some(weight), Some(key)) if weight >= 0 => Ok((weight, key.clone())),
+                        _ => Err("Invalid rubric entry"),
+                    }
+                })
+                .collect();
+            
+            match criteria {
+                Ok(criteria) => {
+                    if answers.len() == criteria.len() && !criteria.is_empty() {
+                        let score = score_answers(answers, &criteria);
+                        (score, score >= threshold, threshold)
+                    } else {
+                        (-1, false, threshold) // Mismatched or empty rubric
+                    }
+                },
+                _ => (-2, false, threshold) // Invalid rubric entries
+            }
+        } else {
+            (-3, false, 0) // Missing required fields
+        }
+    } else {
+        (-4, false, 0) // JSON parse error
+    };
+    
+    // Commit the total score, the pass/fail outcome and the threshold it was judged against
+    // Note: The individual answers remain private!
+    env::commit(&total_score);
+    env::commit(&passed);
+    env::commit(&threshold);
+}
+
+// Sum the weights of every answer that matches its rubric key
+fn score_answers(answers: &[Value], criteria: &[(i64, Value)]) -> i64 {
+    answers
+        .iter()
+        .zip(criteria.iter())
+        .filter(|(answer, (_, key))| *answer == key)
+        .map(|(_, (weight, _))| *weight)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_score_answers() {
+        let criteria = vec![
+            (2, json!("b")),
+            (3, json!("a")),
+            (1, json!(42)),
+            (4, json!("d")),
+        ];
+        let answers = vec![json!("b"), json!("c"), json!(42), json!("d")];
+        assert_eq!(score_answers(&answers, &criteria), 7);
+        
+        let perfect = vec![json!("b"), json!("a"), json!(42), json!("d")];
+        assert_eq!(score_answers(&perfect, &criteria), 10);
+        
+        let none = vec![json!("x"), json!("x"), json!(0), json!("x")];
+        assert_eq!(score_answers(&none, &criteria), 0);
+    }
+}
@@ -0,0 +1,110 @@
+// Shipping cost lookup with zero-knowledge proof
+// This prices a parcel from a private rate table and reveals only the cost and a hash
+// committing to the table that was used
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+use serde_json::{Map, Value};
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Bound the table to keep cycle counts predictable
+const MAX_ZONES: usize = 256;
+const MAX_BRACKETS: usize = 64;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "weight": 3.2,
+    //   "zone": "2",
+    //   "rate_table": {                                  // per zone, weight brackets in ascending order
+    //     "1": [{"max_weight": 1.0, "rate": 4.5}, {"max_weight": 5.0, "rate": 8.0}],
+    //     "2": [{"max_weight": 1.0, "rate": 6.0}, {"max_weight": 5.0, "rate": 11.25}]
+    //   }
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, cost, table_hash) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(weight),
+            Some(zone),
+            Some(table)
+        ) = (
+            parsed.get("weight").and_then(|v| v.as_f64()),
+            parsed.get("zone").and_then(|v| v.as_str()),
+            parsed.get("rate_table").and_then(|v| v.as_object())
+        ) {
+            let table_hash = hash_table(table);
+            match table.get(zone) {
+                _ if table.is_empty() || table.len() > MAX_ZONES => (-1, 0, table_hash), // Empty or oversized table
+                _ if !weight.is_finite() || weight <= 0.0 => (-2, 0, table_hash), // Weight must be positive
+                None => (-6, 0, table_hash), // Zone not in the table
+                Some(brackets) => match parse_brackets(brackets) {
+                    Some(brackets) => match lookup(&brackets, (weight * SCALE).round() as i64) {
+                        Some(cost) => (1, cost, table_hash),
+                        None => (-5, 0, table_hash) // Heavier than the zone's largest bracket
+                    },
+                    None => (-2, 0, table_hash) // Brackets must be ascending {max_weight, rate} pairs
+                }
+            }
+        } else {
+            (-3, 0, Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, 0, Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit the status (1 on success, negative on error), the fixed-point cost and the rate table hash
+    // Note: The rate table, weight and zone remain private!
+    env::commit(&status);
+    env::commit(&cost);
+    env::commit(&table_hash);
+}
+
+// (max_weight, rate) brackets in fixed point; None unless non-empty, bounded and strictly ascending
+fn parse_brackets(brackets: &Value) -> Option<Vec<(i64, i64)>> {
+    let brackets: Vec<(i64, i64)> = brackets
+        .as_array()
+        .filter(|b| !b.is_empty() && b.len() <= MAX_BRACKETS)?
+        .iter()
+        .map(|bracket| {
+            let max_weight = bracket.get("max_weight")?.as_f64()?;
+            let rate = bracket.get("rate")?.as_f64().filter(|r| *r >= 0.0)?;
+            Some(((max_weight * SCALE).round() as i64, (rate * SCALE).round() as i64))
+        })
+        .collect::<Option<_>>()?;
+    brackets.windows(2).all(|pair| pair[0].0 < pair[1].0).then_some(brackets)
+}
+
+// Rate of the first bracket whose max_weight covers the parcel
+fn lookup(brackets: &[(i64, i64)], weight: i64) -> Option<i64> {
+    brackets.iter().find(|(max_weight, _)| weight <= *max_weight).map(|(_, rate)| *rate)
+}
+
+// SHA-256 over the canonical JSON encoding (keys sorted) of the rate table
+fn hash_table(table: &Map<String, Value>) -> Digest {
+    let canonical = serde_json::to_vec(table).unwrap_or_default();
+    *Impl::hash_bytes(&canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_known_cost() {
+        let brackets = parse_brackets(&json!([{"max_weight": 1.0, "rate": 6.0}, {"max_weight": 5.0, "rate": 11.25}])).unwrap();
+        assert_eq!(lookup(&brackets, 320000), Some(1125000));
+        // A bracket's max weight is inclusive
+        assert_eq!(lookup(&brackets, 100000), Some(600000));
+    }
+
+    #[test]
+    fn test_out_of_table() {
+        let brackets = parse_brackets(&json!([{"max_weight": 1.0, "rate": 6.0}])).unwrap();
+        assert_eq!(lookup(&brackets, 100001), None);
+        // Brackets out of order are rejected rather than silently mispriced
+        assert!(parse_brackets(&json!([{"max_weight": 5.0, "rate": 8.0}, {"max_weight": 1.0, "rate": 4.5}])).is_none());
+    }
+}
@@ -3,10 +3,10 @@
 
 use risc0_zkvm::guest::env;
 
-risc0_zkvm::guest::entry!(main);
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
 
-// Fixed-point arithmetic scale factor (5 decimal places for better precision)
-const SCALE: i64 = 100000;
+risc0_zkvm::guest::entry!(main);
 
 fn main() {
     // Read the fixed-point input number from the host
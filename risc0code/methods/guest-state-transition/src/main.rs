@@ -0,0 +1,127 @@
+// Verifiable state transition with zero-knowledge proof
+// This applies balance updates to a private key/value state whose hash matches a public prior state hash,
+// committing only the prior and the new state hash so transitions can be chained like a mini rollup
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+use std::collections::BTreeMap;
+
+// Bound the state and batch size to keep cycle counts predictable
+const MAX_KEYS: usize = 1024;
+const MAX_UPDATES: usize = 1024;
+
+fn main() {
+    // Read input from the host - the prior state hash (Digest::ZERO for the empty genesis state),
+    // then JSON that expects:
+    // {
+    //   "state": {"alice": 10, "bob": 5},          // full prior state, must hash to the prior state hash
+    //   "updates": [                               // applied in order
+    //     {"key": "alice", "delta": -3},
+    //     {"key": "bob", "delta": 3}
+    //   ]
+    // }
+    let prev_state: Digest = env::read();
+    let inputs_json: String = env::read();
+    
+    let (status, new_state) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(state),
+            Some(updates)
+        ) = (
+            parsed.get("state").and_then(|v| v.as_object()),
+            parsed.get("updates").and_then(|v| v.as_array())
+        ) {
+            // Convert state to BTreeMap<String, i64> and updates to Vec<(String, i64)>
+            let state: Option<BTreeMap<String, i64>> = state
+                .iter()
+                .map(|(key, value)| value.as_i64().map(|balance| (key.clone(), balance)))
+                .collect();
+            let updates: Option<Vec<(String, i64)>> = updates
+                .iter()
+                .map(|update| Some((update.get("key")?.as_str()?.to_string(), update.get("delta")?.as_i64()?)))
+                .collect();
+            
+            match (state, updates) {
+                (Some(state), Some(updates)) => {
+                    if updates.is_empty() || updates.len() > MAX_UPDATES || state.len() > MAX_KEYS {
+                        (-1, Digest::ZERO) // Empty or oversized batch or state
+                    } else if state_hash(&state) != prev_state {
+                        (-5, Digest::ZERO) // Private state does not match the prior state hash
+                    } else {
+                        match apply_updates(state, &updates) {
+                            Some(next) => (updates.len() as i64, state_hash(&next)),
+                            None => (-6, Digest::ZERO) // Update overdraws a balance, overflows or grows the state too far
+                        }
+                    }
+                },
+                _ => (-2, Digest::ZERO) // Invalid balances or updates
+            }
+        } else {
+            (-3, Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit the number of updates applied (or error code), the prior and the new state hash
+    // Note: The balances and the individual updates remain private!
+    env::commit(&status);
+    env::commit(&prev_state);
+    env::commit(&new_state);
+}
+
+// Hash of a state: Digest::ZERO for the empty genesis state, otherwise SHA-256 over its canonical
+// JSON encoding (keys sorted), so anyone holding the state can recompute it
+fn state_hash(state: &BTreeMap<String, i64>) -> Digest {
+    if state.is_empty() {
+        return Digest::ZERO;
+    }
+    let canonical = serde_json::to_vec(state).unwrap_or_default();
+    *Impl::hash_bytes(&canonical)
+}
+
+// Apply balance deltas in order; balances may not go negative and new keys start at zero
+fn apply_updates(mut state: BTreeMap<String, i64>, updates: &[(String, i64)]) -> Option<BTreeMap<String, i64>> {
+    for (key, delta) in updates {
+        let balance = state.entry(key.clone()).or_insert(0);
+        *balance = balance.checked_add(*delta).filter(|b| *b >= 0)?;
+    }
+    if state.len() > MAX_KEYS {
+        return None;
+    }
+    Some(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(key: &str, delta: i64) -> (String, i64) {
+        (key.to_string(), delta)
+    }
+
+    #[test]
+    fn test_chained_transitions() {
+        // Genesis -> mint to alice -> transfer to bob; each step starts from the previous new state
+        let genesis = BTreeMap::new();
+        assert_eq!(state_hash(&genesis), Digest::ZERO);
+        
+        let first = apply_updates(genesis, &[update("alice", 10)]).unwrap();
+        let first_hash = state_hash(&first);
+        assert_ne!(first_hash, Digest::ZERO);
+        
+        let second = apply_updates(first.clone(), &[update("alice", -3), update("bob", 3)]).unwrap();
+        assert_eq!(second, BTreeMap::from([("alice".to_string(), 7), ("bob".to_string(), 3)]));
+        assert_ne!(state_hash(&second), first_hash);
+        
+        // Hashing is independent of insertion order
+        let reordered = BTreeMap::from([("bob".to_string(), 3), ("alice".to_string(), 7)]);
+        assert_eq!(state_hash(&reordered), state_hash(&second));
+    }
+
+    #[test]
+    fn test_invalid_updates_are_rejected() {
+        let state = BTreeMap::from([("alice".to_string(), 5)]);
+        assert!(apply_updates(state.clone(), &[update("alice", -6)]).is_none());
+        assert!(apply_updates(state, &[update("alice", i64::MAX)]).is_none());
+    }
+}
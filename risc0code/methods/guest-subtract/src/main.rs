@@ -0,0 +1,38 @@
+// Fixed-point subtraction with zero-knowledge proof
+// The difference is computed with `checked_sub`; inputs whose difference does not fit in an i64 set
+// the overflow flag instead of committing a wrapped result that would still verify.
+//
+// Journal layout (risc0-serde, 28 bytes, all little-endian):
+//   bytes  0..8   a         i64, fixed-point
+//   bytes  8..16  b         i64, fixed-point
+//   bytes 16..24  result    i64, fixed-point a - b (0 unless flag is 0)
+//   bytes 24..28  flag      u32, 0 = ok, 1 = overflow
+#![no_main]
+#![no_std]
+
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::guest::entry!(main);
+
+// Journal flag values
+const OK: u32 = 0;
+const OVERFLOW: u32 = 1;
+
+fn main() {
+    // Read two fixed-point numbers (scaled integers) from the host
+    let a: i64 = env::read();
+    let b: i64 = env::read();
+    
+    // Perform subtraction (fixed-point subtraction is just regular subtraction);
+    // negative results stay negative i64 values in the same scale
+    let (result, flag) = match a.checked_sub(b) {
+        Some(result) => (result, OK),
+        None => (0, OVERFLOW),
+    };
+    
+    // Commit the original inputs, the result and the overflow flag to the journal for verification
+    env::commit(&a);
+    env::commit(&b);
+    env::commit(&result);
+    env::commit(&flag);
+}
@@ -0,0 +1,87 @@
+// Likert survey aggregate with zero-knowledge proof
+// This reports the mean response and how many respondents chose each point of a 1–5 scale
+// without revealing any individual response
+use risc0_zkvm::guest::env;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
+
+// Likert scale points
+const MIN_RESPONSE: u64 = 1;
+const MAX_RESPONSE: u64 = 5;
+
+// Bound the survey size to keep cycle counts predictable
+const MAX_RESPONSES: usize = 100000;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "responses": [5, 4, 4, 3, 5, 1]    // one Likert response (1–5) per respondent
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, mean, distribution) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let Some(responses) = parsed.get("responses").and_then(|v| v.as_array()) {
+            // Convert responses to Vec<u64>
+            let responses: Result<Vec<u64>, _> = responses
+                .iter()
+                .map(|v| v.as_u64().ok_or("Invalid response"))
+                .collect();
+            
+            match responses {
+                Ok(responses) => {
+                    if responses.is_empty() || responses.len() > MAX_RESPONSES {
+                        (-1, 0, Vec::new()) // Empty or oversized survey
+                    } else if responses.iter().any(|r| !(MIN_RESPONSE..=MAX_RESPONSE).contains(r)) {
+                        (-5, 0, Vec::new()) // Response outside the 1–5 scale
+                    } else {
+                        let (mean, distribution) = aggregate(&responses);
+                        (responses.len() as i64, mean, distribution)
+                    }
+                },
+                _ => (-2, 0, Vec::new()) // Invalid response data
+            }
+        } else {
+            (-3, 0, Vec::new()) // Missing required fields
+        }
+    } else {
+        (-4, 0, Vec::new()) // JSON parse error
+    };
+    
+    // Commit the number of responses (or error code), the fixed-point mean and the count per scale point
+    // Note: Individual responses remain private!
+    env::commit(&status);
+    env::commit(&mean);
+    env::commit(&distribution);
+}
+
+// Fixed-point mean (rounded down) and bucket counts for scale points 1..=5
+fn aggregate(responses: &[u64]) -> (i64, Vec<u64>) {
+    let mut distribution = vec![0u64; (MAX_RESPONSE - MIN_RESPONSE + 1) as usize];
+    for &response in responses {
+        distribution[(response - MIN_RESPONSE) as usize] += 1;
+    }
+    let total: u64 = responses.iter().sum();
+    let mean = total * SCALE as u64 / responses.len() as u64;
+    (mean as i64, distribution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_aggregates() {
+        // Sum 22 over 6 responses: mean 3.66666
+        let (mean, distribution) = aggregate(&[5, 4, 4, 3, 5, 1]);
+        assert_eq!(mean, 366666);
+        assert_eq!(distribution, vec![1, 0, 1, 2, 2]);
+    }
+
+    #[test]
+    fn test_unanimous_survey() {
+        let (mean, distribution) = aggregate(&[2; 10]);
+        assert_eq!(mean, 200000);
+        assert_eq!(distribution, vec![0, 10, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,107 @@
+// Time-weighted average price with zero-knowledge proof
+// This computes a TWAP over a private price series, revealing only the average and a hash of the series
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE_F64 as SCALE;
+
+// Bound the number of observations to keep cycle counts predictable
+const MAX_OBSERVATIONS: usize = 4096;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "prices": [100.0, 102.5, 101.0],   // price held over each period
+    //   "durations": [60, 120, 60]         // positive period lengths (e.g. seconds)
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, twap, series_hash) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (
+            Some(prices),
+            Some(durations)
+        ) = (
+            parsed.get("prices").and_then(|v| v.as_array()),
+            parsed.get("durations").and_then(|v| v.as_array())
+        ) {
+            // Convert prices to fixed point and durations to u64
+            let price_vec: Result<Vec<i64>, _> = prices
+                .iter()
+                .map(|v| v.as_f64().map(|p| (p * SCALE).round() as i64).ok_or("Invalid price"))
+                .collect();
+            let duration_vec: Result<Vec<u64>, _> = durations
+                .iter()
+                .map(|v| v.as_u64().ok_or("Invalid duration"))
+                .collect();
+            
+            match (price_vec, duration_vec) {
+                (Ok(p), Ok(d)) => {
+                    if p.len() != d.len() || p.is_empty() || p.len() > MAX_OBSERVATIONS {
+                        (-1, 0, Digest::ZERO) // Mismatched, empty or oversized series
+                    } else if d.contains(&0) {
+                        (-5, 0, Digest::ZERO) // Every period must have a positive duration
+                    } else {
+                        (p.len() as i64, time_weighted_average(&p, &d), hash_series(&p, &d))
+                    }
+                },
+                _ => (-2, 0, Digest::ZERO) // Invalid price or duration data
+            }
+        } else {
+            (-3, 0, Digest::ZERO) // Missing required fields
+        }
+    } else {
+        (-4, 0, Digest::ZERO) // JSON parse error
+    };
+    
+    // Commit the observation count (or error code), the fixed-point TWAP and the series hash
+    // Note: The individual prices and durations remain private beyond their hash!
+    env::commit(&status);
+    env::commit(&twap);
+    env::commit(&series_hash);
+}
+
+// Sum(price * duration) / Sum(duration) in fixed point, rounded half away from zero.
+// Accumulates in i128 so long series of large prices cannot overflow.
+fn time_weighted_average(prices: &[i64], durations: &[u64]) -> i64 {
+    let weighted: i128 = prices.iter().zip(durations.iter()).map(|(&p, &d)| p as i128 * d as i128).sum();
+    let total: i128 = durations.iter().map(|&d| d as i128).sum();
+    let half = total / 2;
+    let rounded = if weighted >= 0 { (weighted + half) / total } else { (weighted - half) / total };
+    rounded as i64
+}
+
+// SHA-256 over the little-endian (price, duration) pairs in input order
+fn hash_series(prices: &[i64], durations: &[u64]) -> Digest {
+    let mut bytes = Vec::with_capacity(prices.len() * 16);
+    for (price, duration) in prices.iter().zip(durations.iter()) {
+        bytes.extend_from_slice(&price.to_le_bytes());
+        bytes.extend_from_slice(&duration.to_le_bytes());
+    }
+    *Impl::hash_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hand_computed_twap() {
+        // (100.0 * 60 + 102.5 * 120 + 101.0 * 60) / 240 = 24360 / 240 = 101.5
+        let prices = [10_000_000, 10_250_000, 10_100_000];
+        assert_eq!(time_weighted_average(&prices, &[60, 120, 60]), 10_150_000);
+    }
+
+    #[test]
+    fn test_equal_durations_is_mean() {
+        assert_eq!(time_weighted_average(&[100_000, 200_000, 300_000], &[5, 5, 5]), 200_000);
+        assert_eq!(time_weighted_average(&[123_456], &[1]), 123_456);
+    }
+
+    #[test]
+    fn test_rounding() {
+        // (1 * 1 + 2 * 2) / 3 = 1.666.. rounds to 2
+        assert_eq!(time_weighted_average(&[1, 2], &[1, 2]), 2);
+        assert_eq!(time_weighted_average(&[-1, -2], &[1, 2]), -2);
+    }
+}
@@ -0,0 +1,143 @@
+// Historical value-at-risk check with zero-knowledge proof
+// This proves a private portfolio's VaR is within a public limit without revealing the positions
+use risc0_zkvm::guest::env;
+use serde_json::Value;
+
+// Fixed-point arithmetic scale factor shared with the host (5 decimal places)
+use fixed_point::SCALE;
+
+// Bound the problem size to keep cycle counts predictable
+const MAX_POSITIONS: usize = 256;
+const MAX_SCENARIOS: usize = 2048;
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "positions": [100000, 50000],               // position values
+    //   "scenarios": [[-0.02, 0.01], [0.03, -0.05]], // per-scenario returns for each position
+    //   "confidence_bps": 9500,                      // 95% confidence
+    //   "limit": 5000                                // public VaR limit
+    // }
+    let inputs_json: String = env::read();
+    
+    let (status, limit_fixed, confidence_bps) = if let Ok(parsed) = serde_json::from_str::<Value>(&inputs_json) {
+        if let (
+            Some(positions),
+            Some(scenarios),
+            Some(confidence_bps),
+            Some(limit)
+        ) = (
+            parsed.get("positions").and_then(|v| v.as_array()),
+            parsed.get("scenarios").and_then(|v| v.as_array()),
+            parsed.get("confidence_bps").and_then(|v| v.as_u64()),
+            parsed.get("limit").and_then(|v| v.as_f64())
+        ) {
+            let limit_fixed = to_fixed(limit);
+            let positions = parse_row(positions);
+            let scenarios: Option<Vec<Vec<i64>>> = scenarios
+                .iter()
+                .map(|s| s.as_array().and_then(|row| parse_row(row)))
+                .collect();
+            
+            match (positions, scenarios) {
+                (Some(positions), Some(scenarios)) => {
+                    let valid_shape = !positions.is_empty() && positions.len() <= MAX_POSITIONS
+                        && !scenarios.is_empty() && scenarios.len() <= MAX_SCENARIOS
+                        && scenarios.iter().all(|s| s.len() == positions.len());
+                    
+                    if valid_shape && confidence_bps > 0 && confidence_bps < 10000 {
+                        let var = historical_var(&positions, &scenarios, confidence_bps);
+                        (if var <= limit_fixed { 1 } else { 0 }, limit_fixed, confidence_bps)
+                    } else {
+                        (-1, limit_fixed, confidence_bps) // Mismatched dimensions or invalid confidence
+                    }
+                },
+                _ => (-2, limit_fixed, confidence_bps) // Invalid numeric data
+            }
+        } else {
+            (-3, 0, 0) // Missing required fields
+        }
+    } else {
+        (-4, 0, 0) // JSON parse error
+    };
+    
+    // Commit whether VaR is within the limit (1), breaches it (0) or the input was invalid (negative),
+    // along with the public limit (fixed-point) and confidence level it was checked against
+    // Note: The positions, scenarios and the VaR figure itself remain private!
+    env::commit(&status);
+    env::commit(&limit_fixed);
+    env::commit(&confidence_bps);
+}
+
+fn to_fixed(value: f64) -> i64 {
+    (value * SCALE as f64).round() as i64
+}
+
+fn parse_row(values: &[Value]) -> Option<Vec<i64>> {
+    values.iter().map(|v| v.as_f64().map(to_fixed)).collect()
+}
+
+// Historical VaR: the loss that at most (1 - confidence) of scenarios exceed.
+// Scenario P&L is sum(position * return); gains count as zero loss.
+fn historical_var(positions: &[i64], scenarios: &[Vec<i64>], confidence_bps: u64) -> i64 {
+    let mut losses: Vec<i64> = scenarios
+        .iter()
+        .map(|returns| {
+            let pnl: i128 = positions
+                .iter()
+                .zip(returns.iter())
+                .map(|(position, ret)| *position as i128 * *ret as i128)
+                .sum();
+            (-(pnl / SCALE as i128)) as i64
+        })
+        .collect();
+    
+    // Worst losses first
+    losses.sort_unstable_by(|a, b| b.cmp(a));
+    
+    let tail_count = (losses.len() as u64 * (10000 - confidence_bps) / 10000) as usize;
+    losses[tail_count.min(losses.len() - 1)].max(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_row(values: &[f64]) -> Vec<i64> {
+        values.iter().map(|v| to_fixed(*v)).collect()
+    }
+
+    fn scenarios() -> Vec<Vec<i64>> {
+        // Ten scenarios of returns for two positions
+        vec![
+            fixed_row(&[-0.10, -0.05]),
+            fixed_row(&[-0.04, -0.02]),
+            fixed_row(&[-0.02, 0.00]),
+            fixed_row(&[-0.01, 0.01]),
+            fixed_row(&[0.00, 0.00]),
+            fixed_row(&[0.01, 0.00]),
+            fixed_row(&[0.02, 0.01]),
+            fixed_row(&[0.03, 0.02]),
+            fixed_row(&[0.04, 0.02]),
+            fixed_row(&[0.05, 0.03]),
+        ]
+    }
+
+    #[test]
+    fn test_var_within_limit() {
+        let positions = fixed_row(&[1000.0, 1000.0]);
+        // At 90% the single worst scenario (-150) is excluded; the next worst loses 60
+        let var = historical_var(&positions, &scenarios(), 9000);
+        assert_eq!(var, to_fixed(60.0));
+        assert!(var <= to_fixed(100.0));
+    }
+
+    #[test]
+    fn test_var_breaches_limit() {
+        let positions = fixed_row(&[5000.0, 1000.0]);
+        // Next worst loss is 5000*0.04 + 1000*0.02 = 220
+        let var = historical_var(&positions, &scenarios(), 9000);
+        assert_eq!(var, to_fixed(220.0));
+        assert!(var > to_fixed(100.0));
+    }
+}
@@ -0,0 +1,98 @@
+// Ed25519 signature verification with zero-knowledge proof
+// This proves that a valid signature over a message exists under a public key, without revealing
+// the signature itself. Verification runs inside the zkVM, so the receipt attests to it rather than
+// the host's word. ed25519-compact is built without default features, which keeps it no_std.
+//
+// Journal layout (risc0-serde):
+//   status        i64, 1 = valid signature, 0 = invalid signature, negative on error
+//   message_hash  Digest, SHA-256 of the message bytes (zero on error)
+//   public_key    [u8; 32], the key the signature was checked against (zero on error)
+use ed25519_compact::{PublicKey, Signature};
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+
+fn main() {
+    // Read input from the host - expects:
+    // {
+    //   "public_key": "<64 hex chars>",    // public Ed25519 key
+    //   "message": "transfer 100 to bob",  // public message, hashed into the journal
+    //   "signature": "<128 hex chars>"     // private signature over the message bytes
+    // }
+    let inputs_json: String = env::read();
+
+    let (status, message_hash, public_key) = if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&inputs_json) {
+        if let (Some(public_key), Some(message), Some(signature)) = (
+            parsed.get("public_key").and_then(|v| v.as_str()),
+            parsed.get("message").and_then(|v| v.as_str()),
+            parsed.get("signature").and_then(|v| v.as_str())
+        ) {
+            match (decode_public_key(public_key), decode_signature(signature)) {
+                (Some(key_bytes), Some(signature)) => {
+                    let valid = verify(&key_bytes, message.as_bytes(), &signature);
+                    (valid as i64, *Impl::hash_bytes(message.as_bytes()), key_bytes)
+                }
+                (None, _) => (-3, Digest::ZERO, [0u8; 32]), // Public key is not 32 bytes of hex
+                (_, None) => (-4, Digest::ZERO, [0u8; 32]) // Signature is not 64 bytes of hex
+            }
+        } else {
+            (-1, Digest::ZERO, [0u8; 32]) // Missing required fields
+        }
+    } else {
+        (-2, Digest::ZERO, [0u8; 32]) // JSON parse error
+    };
+
+    // Commit the outcome, the message hash and the public key
+    // Note: The signature remains private!
+    env::commit(&status);
+    env::commit(&message_hash);
+    env::commit(&public_key);
+}
+
+fn decode_public_key(hex_key: &str) -> Option<[u8; 32]> {
+    hex::decode(hex_key).ok()?.try_into().ok()
+}
+
+fn decode_signature(hex_signature: &str) -> Option<Signature> {
+    let bytes: [u8; 64] = hex::decode(hex_signature).ok()?.try_into().ok()?;
+    Some(Signature::new(bytes))
+}
+
+// A key that is not a valid curve point fails verification like a bad signature does
+fn verify(public_key: &[u8; 32], message: &[u8], signature: &Signature) -> bool {
+    PublicKey::new(*public_key).verify(message, signature).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_compact::{KeyPair, Seed};
+
+    fn signed(message: &[u8]) -> (KeyPair, Signature) {
+        let keypair = KeyPair::from_seed(Seed::new([7u8; 32]));
+        let signature = keypair.sk.sign(message, None);
+        (keypair, signature)
+    }
+
+    #[test]
+    fn test_valid_signature_verifies() {
+        let (keypair, signature) = signed(b"transfer 100 to bob");
+        assert!(verify(&keypair.pk, b"transfer 100 to bob", &signature));
+    }
+
+    #[test]
+    fn test_tampered_message_or_other_key_fails() {
+        let (keypair, signature) = signed(b"transfer 100 to bob");
+        assert!(!verify(&keypair.pk, b"transfer 900 to bob", &signature));
+        let other = KeyPair::from_seed(Seed::new([8u8; 32]));
+        assert!(!verify(&other.pk, b"transfer 100 to bob", &signature));
+    }
+
+    #[test]
+    fn test_hex_lengths_are_checked() {
+        assert!(decode_public_key(&"ab".repeat(32)).is_some());
+        assert!(decode_public_key(&"ab".repeat(31)).is_none());
+        assert!(decode_public_key("not hex").is_none());
+        assert!(decode_signature(&"ab".repeat(64)).is_some());
+        assert!(decode_signature(&"ab".repeat(32)).is_none());
+    }
+}
@@ -1,3 +1,12 @@
+// Fixed-point addition with zero-knowledge proof
+// The sum is computed with `checked_add`; inputs whose sum does not fit in an i64 set the overflow
+// flag instead of committing a wrapped result that would still verify.
+//
+// Journal layout (risc0-serde, 28 bytes, all little-endian):
+//   bytes  0..8   a         i64, fixed-point
+//   bytes  8..16  b         i64, fixed-point
+//   bytes 16..24  result    i64, fixed-point a + b (0 unless flag is 0)
+//   bytes 24..28  flag      u32, 0 = ok, 1 = overflow
 #![no_main]
 #![no_std]
 
@@ -5,8 +14,9 @@ use risc0_zkvm::guest::env;
 
 risc0_zkvm::guest::entry!(main);
 
-// Fixed-point arithmetic scale factor (5 decimal places for better precision)
-const SCALE: i64 = 100000;
+// Journal flag values
+const OK: u32 = 0;
+const OVERFLOW: u32 = 1;
 
 fn main() {
     // Read two fixed-point numbers (scaled integers) from the host
@@ -14,10 +24,14 @@ fn main() {
     let b: i64 = env::read();
     
     // Perform addition (fixed-point addition is just regular addition)
-    let result = a + b;
+    let (result, flag) = match a.checked_add(b) {
+        Some(result) => (result, OK),
+        None => (0, OVERFLOW),
+    };
     
-    // Commit the original inputs and result to the journal for verification
+    // Commit the original inputs, the result and the overflow flag to the journal for verification
     env::commit(&a);
     env::commit(&b);
     env::commit(&result);
+    env::commit(&flag);
 }
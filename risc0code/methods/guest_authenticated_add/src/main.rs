@@ -3,7 +3,10 @@
 
 extern crate alloc;
 use alloc::string::String;
+use alloc::vec::Vec;
+use ed25519_compact::{KeyPair, Seed};
 use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Impl, Sha256};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -16,6 +19,10 @@ struct ComputationResult {
     // Metadata
     timestamp: u64,
     task_id: String,
+    
+    // Signature over the digest of everything above, made with the host's key inside the zkVM
+    public_key: [u8; 32],
+    signature: Vec<u8>,
 }
 
 risc0_zkvm::guest::entry!(main);
@@ -29,9 +36,20 @@ fn main() {
     let timestamp: u64 = env::read();
     let task_id: String = env::read();
     
+    // Read the signing key's 32-byte Ed25519 seed (kept private)
+    let seed: [u8; 32] = env::read();
+    
     // Perform computation
     let result = a + b;
     
+    // Sign the SHA-256 of the computation's serde encoding; ed25519-compact is built without
+    // default features, so it is no_std and signs deterministically without an RNG
+    let words = risc0_zkvm::serde::to_vec(&(a, b, result, timestamp, &task_id)).unwrap();
+    let message: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    let digest = Impl::hash_bytes(&message);
+    let keypair = KeyPair::from_seed(Seed::new(seed));
+    let signature = keypair.sk.sign(digest.as_bytes(), None);
+    
     // Create computation result
     let computation_result = ComputationResult {
         a,
@@ -39,9 +57,11 @@ fn main() {
         result,
         timestamp,
         task_id,
+        public_key: *keypair.pk,
+        signature: signature.to_vec(),
     };
     
     // Commit the computation result to the proof
+    // Note: The seed remains private; only the public key and the signature are revealed
     env::commit(&computation_result);
 }
-
@@ -0,0 +1,977 @@
+// Core operations built into this workspace: fixed-point and integer arithmetic, range proofs,
+// batches, authenticated addition and the ML guests. They are registered ahead of the JSON guests in
+// `Registry::builtin`, so the host and the verifier reach them the same way as any other operation.
+use crate::*;
+use crate::journal::{trace_fields, AuthenticatedAddJournal, BatchJournal, CheckedDecimalJournal, DivideJournal, FactorialJournal, Field, GcdJournal, JournalCodec, MlJournal, ModexpJournal, ModinverseJournal, PowerJournal, RangeJournal, SqrtJournal};
+use crate::operation::{write_input, DecodedJournal, JsonGuest, Operation, OperationError, Registry, ResultType};
+use crate::scale::{decimal_to_fixed_point, fixed_point_to_decimal, SCALE};
+use risc0_zkvm::{ExecutorEnvBuilder, Journal};
+use serde::de::DeserializeOwned;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Host-side modular exponentiation for verification
+pub fn modular_exponentiation_host(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    if modulus == 0 {
+        return 0;
+    }
+
+    if modulus == 1 {
+        return 0;
+    }
+
+    if exponent == 0 {
+        return 1;
+    }
+
+    base %= modulus;
+
+    if base == 0 {
+        return 0;
+    }
+
+    let mut result = 1u64;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = ((result as u128 * base as u128) % modulus as u128) as u64;
+        }
+
+        base = ((base as u128 * base as u128) % modulus as u128) as u64;
+        exponent >>= 1;
+    }
+
+    result
+}
+
+// Host-side modular inverse for verification; None when gcd(a, n) != 1 or n is 0
+pub fn modular_inverse_host(a: u64, n: u64) -> Option<u64> {
+    if n == 0 {
+        return None;
+    }
+    let (mut old_r, mut r) = ((a % n) as i128, n as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    (old_r == 1).then(|| old_s.rem_euclid(n as i128) as u64)
+}
+
+// Host-side Euclidean gcd for verification
+pub fn gcd_host(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// Host-side base^exponent for verification; None when it overflows u64, as the guest flags
+pub fn power_host(base: u64, exponent: u64) -> Option<u64> {
+    match base {
+        0 | 1 => Some(if exponent == 0 { 1 } else { base }),
+        _ => base.checked_pow(u32::try_from(exponent).ok()?),
+    }
+}
+
+// Host-side n! for verification; None past 20!, which is where the guest flags overflow
+pub fn factorial_host(n: u64) -> Option<u64> {
+    (2..=n).try_fold(1u64, |product, k| product.checked_mul(k))
+}
+
+// Whether no integer satisfies the range bounds, as the range guest flags: min > max, or an
+// exclusive bound that leaves nothing, like [5, 5)
+pub fn range_is_empty(min: u64, max: u64, min_exclusive: bool, max_exclusive: bool) -> bool {
+    match (min.checked_add(min_exclusive as u64), max.checked_sub(max_exclusive as u64)) {
+        (Some(lowest), Some(highest)) => lowest > highest,
+        _ => true,
+    }
+}
+
+// Error named by the divide guest's journal flag
+pub fn divide_error(flag: u32) -> &'static str {
+    match flag {
+        1 => "division by zero",
+        2 => "quotient overflows i64",
+        _ => "unknown divide error",
+    }
+}
+
+fn check_count(name: &str, kind: &str, args: &[String], count: usize) -> Result<(), OperationError> {
+    if args.len() != count {
+        return Err(format!("{} takes {} {} input(s), got {}", name, count, kind, args.len()).into());
+    }
+    Ok(())
+}
+
+fn decimals(args: &[String]) -> Result<Vec<f64>, OperationError> {
+    args.iter()
+        .map(|arg| arg.parse::<f64>().map_err(|_| format!("'{}' is not a number", arg).into()))
+        .collect()
+}
+
+fn integers(args: &[String]) -> Result<Vec<u64>, OperationError> {
+    args.iter()
+        .map(|arg| arg.parse::<u64>().map_err(|_| format!("'{}' is not a non-negative integer", arg).into()))
+        .collect()
+}
+
+fn decode<T: DeserializeOwned>(name: &str, journal: &Journal) -> Result<T, OperationError> {
+    journal.decode().map_err(|e| format!("Invalid {} journal: {}", name, e).into())
+}
+
+// add, subtract, multiply, divide and sqrt: decimal inputs, written as fixed-point i64s
+struct DecimalGuest {
+    name: &'static str,
+    description: &'static str,
+    symbol: &'static str,
+    usage: &'static str,
+    // Names of the inputs, in the order the guest reads them
+    inputs: &'static [&'static str],
+    elf: &'static [u8],
+    image_id: [u32; 8],
+    describe: fn(&[f64]) -> String,
+    // The host's result from the fixed-point inputs and the difference allowed from the guest's
+    expected: fn(&[i64]) -> Option<(i64, i64)>,
+    decode: fn(&Journal) -> Result<DecodedJournal, OperationError>,
+    // The journal's fields in commit order, for `--trace`
+    layout: &'static [(&'static str, Field)],
+}
+
+impl DecimalGuest {
+    fn values(&self, args: &[String]) -> Result<Vec<f64>, OperationError> {
+        check_count(self.name, "decimal", args, self.inputs.len())?;
+        decimals(args)
+    }
+}
+
+impl Operation for DecimalGuest {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    fn usage(&self) -> &'static str {
+        self.usage
+    }
+
+    fn arg_count(&self) -> usize {
+        self.inputs.len()
+    }
+
+    fn elf(&self) -> &'static [u8] {
+        self.elf
+    }
+
+    fn image_id(&self) -> [u32; 8] {
+        self.image_id
+    }
+
+    fn write_env(&self, builder: &mut ExecutorEnvBuilder<'_>, args: &[String], reads: &mut Vec<String>) -> Result<(), OperationError> {
+        for (name, value) in self.inputs.iter().zip(self.values(args)?) {
+            write_input(builder, reads, name, &decimal_to_fixed_point(value))?;
+        }
+        Ok(())
+    }
+
+    fn describe_inputs(&self, args: &[String]) -> String {
+        self.values(args).map_or_else(|_| args.join(" "), |values| (self.describe)(&values))
+    }
+
+    fn echo_inputs(&self, args: &[String]) -> serde_json::Value {
+        match self.values(args) {
+            Ok(values) => self.inputs.iter().map(|name| name.to_string()).zip(values.into_iter().map(serde_json::Value::from)).collect(),
+            Err(_) => serde_json::json!(args),
+        }
+    }
+
+    fn host_expected(&self, args: &[String]) -> Option<(i64, i64)> {
+        let fixed: Vec<i64> = self.values(args).ok()?.into_iter().map(decimal_to_fixed_point).collect();
+        (self.expected)(&fixed)
+    }
+
+    fn decode_journal(&self, journal: &Journal) -> Result<DecodedJournal, OperationError> {
+        (self.decode)(journal)
+    }
+
+    fn trace_journal(&self, codec: JournalCodec, bytes: &[u8]) -> Vec<String> {
+        trace_fields(self.name, codec, bytes, |journal, commits| journal.read_fields(self.layout, commits))
+    }
+}
+
+// modexp, modinverse, gcd, power and factorial: non-negative integer inputs, written as u64s
+struct IntegerGuest {
+    name: &'static str,
+    description: &'static str,
+    symbol: &'static str,
+    usage: &'static str,
+    inputs: &'static [&'static str],
+    elf: &'static [u8],
+    image_id: [u32; 8],
+    describe: fn(&[u64]) -> String,
+    // The host's result, or None where the guest flags an error instead of committing one
+    expected: fn(&[u64]) -> Option<u64>,
+    decode: fn(&Journal) -> Result<DecodedJournal, OperationError>,
+    layout: &'static [(&'static str, Field)],
+}
+
+impl IntegerGuest {
+    fn values(&self, args: &[String]) -> Result<Vec<u64>, OperationError> {
+        check_count(self.name, "integer", args, self.inputs.len())?;
+        integers(args)
+    }
+}
+
+impl Operation for IntegerGuest {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    fn usage(&self) -> &'static str {
+        self.usage
+    }
+
+    fn arg_count(&self) -> usize {
+        self.inputs.len()
+    }
+
+    fn elf(&self) -> &'static [u8] {
+        self.elf
+    }
+
+    fn image_id(&self) -> [u32; 8] {
+        self.image_id
+    }
+
+    fn result_type(&self) -> ResultType {
+        ResultType::Integer
+    }
+
+    fn write_env(&self, builder: &mut ExecutorEnvBuilder<'_>, args: &[String], reads: &mut Vec<String>) -> Result<(), OperationError> {
+        for (name, value) in self.inputs.iter().zip(self.values(args)?) {
+            write_input(builder, reads, name, &value)?;
+        }
+        Ok(())
+    }
+
+    fn describe_inputs(&self, args: &[String]) -> String {
+        self.values(args).map_or_else(|_| args.join(" "), |values| (self.describe)(&values))
+    }
+
+    fn echo_inputs(&self, args: &[String]) -> serde_json::Value {
+        match self.values(args) {
+            Ok(values) => self.inputs.iter().map(|name| name.to_string()).zip(values.into_iter().map(serde_json::Value::from)).collect(),
+            Err(_) => serde_json::json!(args),
+        }
+    }
+
+    fn host_expected(&self, args: &[String]) -> Option<(i64, i64)> {
+        (self.expected)(&self.values(args).ok()?).map(|result| (result as i64, 0))
+    }
+
+    fn decode_journal(&self, journal: &Journal) -> Result<DecodedJournal, OperationError> {
+        (self.decode)(journal)
+    }
+
+    fn trace_journal(&self, codec: JournalCodec, bytes: &[u8]) -> Vec<String> {
+        trace_fields(self.name, codec, bytes, |journal, commits| journal.read_fields(self.layout, commits))
+    }
+}
+
+// Prove that a private number lies between two public bounds
+struct Range;
+
+impl Range {
+    // [secret_number, min, max, min_exclusive, max_exclusive]; the flags default to inclusive
+    fn values(args: &[String]) -> Result<[u64; 5], OperationError> {
+        match integers(args)?[..] {
+            [secret, min, max] => Ok([secret, min, max, 0, 0]),
+            [secret, min, max, min_exclusive, max_exclusive] if min_exclusive <= 1 && max_exclusive <= 1 => Ok([secret, min, max, min_exclusive, max_exclusive]),
+            [_, _, _, _, _] => Err("range exclusivity flags must be 0 or 1".into()),
+            _ => Err(format!("range takes 3 integer input(s), or 5 with exclusivity flags, got {}", args.len()).into()),
+        }
+    }
+}
+
+impl Operation for Range {
+    fn name(&self) -> &'static str {
+        "range"
+    }
+
+    fn description(&self) -> &'static str {
+        "Prove a private number lies in [min, max]; --min-exclusive/--max-exclusive open a bound"
+    }
+
+    fn symbol(&self) -> &'static str {
+        "∈"
+    }
+
+    fn usage(&self) -> &'static str {
+        "<secret_number> <min> <max>"
+    }
+
+    fn arg_count(&self) -> usize {
+        3
+    }
+
+    fn elf(&self) -> &'static [u8] {
+        GUEST_RANGE_ELF
+    }
+
+    fn image_id(&self) -> [u32; 8] {
+        GUEST_RANGE_ID
+    }
+
+    fn result_type(&self) -> ResultType {
+        ResultType::Boolean
+    }
+
+    fn write_env(&self, builder: &mut ExecutorEnvBuilder<'_>, args: &[String], reads: &mut Vec<String>) -> Result<(), OperationError> {
+        let [secret, min, max, min_exclusive, max_exclusive] = Self::values(args)?;
+        // A range no value satisfies can never contain the secret, so reject it instead of proving
+        // a guaranteed "not in range". min == max is allowed and proves equality when both bounds
+        // are inclusive.
+        if min > max {
+            return Err(format!("range min ({}) must not exceed max ({})", min, max).into());
+        }
+        if range_is_empty(min, max, min_exclusive == 1, max_exclusive == 1) {
+            return Err(format!("no value lies between {} and {} with the requested exclusive bounds", min, max).into());
+        }
+
+        for (name, value) in [("secret_number", secret), ("min", min), ("max", max)] {
+            write_input(builder, reads, name, &value)?;
+        }
+        for (name, value) in [("min_exclusive", min_exclusive), ("max_exclusive", max_exclusive)] {
+            write_input(builder, reads, name, &(value as u32))?;
+        }
+        Ok(())
+    }
+
+    fn describe_inputs(&self, args: &[String]) -> String {
+        match Self::values(args) {
+            Ok([_, min, max, min_exclusive, max_exclusive]) => {
+                let open = if min_exclusive == 1 { '(' } else { '[' };
+                let close = if max_exclusive == 1 { ')' } else { ']' };
+                format!("secret ∈ {}{}, {}{}", open, min, max, close)
+            },
+            Err(_) => "secret ∈ ?".to_string(),
+        }
+    }
+
+    // The secret stays out of the output; only the bounds are echoed
+    fn echo_inputs(&self, args: &[String]) -> serde_json::Value {
+        match Self::values(args) {
+            Ok([_, min, max, min_exclusive, max_exclusive]) => serde_json::json!({ "min": min, "max": max, "min_exclusive": min_exclusive == 1, "max_exclusive": max_exclusive == 1 }),
+            Err(_) => serde_json::Value::Null,
+        }
+    }
+
+    fn decode_journal(&self, journal: &Journal) -> Result<DecodedJournal, OperationError> {
+        let range: RangeJournal = decode("range", journal)?;
+        // No secret lies in an empty range, so "not in range" would say nothing about it
+        let mut decoded = if range.invalid_range == 0 {
+            DecodedJournal::new(if range.in_range { 1.0 } else { 0.0 }, if range.in_range { 1 } else { 0 })
+                .line(format!("🔢 Range proof result: secret ∈ {} = {}", range.interval(), range.in_range))
+        } else {
+            DecodedJournal::error("invalid range: no value satisfies the bounds")
+                .line(format!("⚠️  Guest reported an empty range: no value lies in {}", range.interval()))
+        };
+        decoded.lines.push(format!("🔍 Details: above_min={}, below_max={}", range.above_min, range.below_max));
+        decoded.range = Some(range);
+        Ok(decoded)
+    }
+
+    fn trace_journal(&self, codec: JournalCodec, bytes: &[u8]) -> Vec<String> {
+        const LAYOUT: &[(&str, Field)] = &[
+            ("in_range", Field::Bool), ("above_min", Field::Bool), ("below_max", Field::Bool),
+            ("min", Field::U64), ("max", Field::U64),
+            ("invalid_range", Field::U32), ("min_exclusive", Field::U32), ("max_exclusive", Field::U32),
+        ];
+        trace_fields("range", codec, bytes, |journal, commits| journal.read_fields(LAYOUT, commits))
+    }
+}
+
+// add or multiply over many pairs in one proof
+struct Batch;
+
+impl Batch {
+    // The operation code the guest reads and the pairs, checked
+    fn values(args: &[String]) -> Result<(u32, Vec<(f64, f64)>), OperationError> {
+        let [operation, pairs] = args else {
+            return Err(format!("batch takes an operation and a JSON array of pairs, got {} input(s)", args.len()).into());
+        };
+        let code = match operation.as_str() {
+            "add" => BatchJournal::ADD,
+            "multiply" => BatchJournal::MULTIPLY,
+            other => return Err(format!("batch supports add and multiply, not {}", other).into()),
+        };
+        let pairs: Vec<(f64, f64)> = serde_json::from_str(pairs)
+            .map_err(|e| format!("pairs must be a JSON array of [a, b] numbers: {}", e))?;
+        if pairs.is_empty() {
+            return Err("batch takes at least one pair".into());
+        }
+        Ok((code, pairs))
+    }
+}
+
+impl Operation for Batch {
+    fn name(&self) -> &'static str {
+        "batch"
+    }
+
+    fn description(&self) -> &'static str {
+        "Add or multiply many pairs in one proof"
+    }
+
+    fn symbol(&self) -> &'static str {
+        "batch"
+    }
+
+    fn usage(&self) -> &'static str {
+        "<add|multiply> <pairs_json>"
+    }
+
+    fn arg_count(&self) -> usize {
+        2
+    }
+
+    fn elf(&self) -> &'static [u8] {
+        GUEST_BATCH_ELF
+    }
+
+    fn image_id(&self) -> [u32; 8] {
+        GUEST_BATCH_ID
+    }
+
+    fn write_env(&self, builder: &mut ExecutorEnvBuilder<'_>, args: &[String], reads: &mut Vec<String>) -> Result<(), OperationError> {
+        let (code, pairs) = Self::values(args)?;
+        write_input(builder, reads, "operation", &code)?;
+        let fixed: Vec<(i64, i64)> = pairs.iter().map(|&(a, b)| (decimal_to_fixed_point(a), decimal_to_fixed_point(b))).collect();
+        write_input(builder, reads, "pairs", &fixed)
+    }
+
+    fn describe_inputs(&self, args: &[String]) -> String {
+        match Self::values(args) {
+            Ok((_, pairs)) => format!("{} batch of {} pair(s)", args[0], pairs.len()),
+            Err(_) => format!("batch with inputs: {}", args.join(" ")),
+        }
+    }
+
+    fn echo_inputs(&self, args: &[String]) -> serde_json::Value {
+        match args {
+            [operation, pairs] => serde_json::json!({ "operation": operation, "pairs": serde_json::from_str::<serde_json::Value>(pairs).unwrap_or_else(|_| pairs.as_str().into()) }),
+            _ => serde_json::json!(args),
+        }
+    }
+
+    fn decode_journal(&self, journal: &Journal) -> Result<DecodedJournal, OperationError> {
+        let BatchJournal { operation, entries } = decode("batch", journal)?;
+        let (name, symbol) = if operation == BatchJournal::ADD { ("add", "+") } else { ("multiply", "*") };
+
+        // There is no single headline value; each pair's result is in `results`
+        let mut decoded = DecodedJournal::error("batch results are listed per pair")
+            .line(format!("🔢 Batch of {} {} pair(s):", entries.len(), name));
+        let mut results = Vec::with_capacity(entries.len());
+        for CheckedDecimalJournal { a, b, result, overflow } in entries {
+            let (a_decimal, b_decimal) = (fixed_point_to_decimal(a), fixed_point_to_decimal(b));
+            if overflow != 0 {
+                decoded.lines.push(format!("   ⚠️  {} {} {} overflows i64", a_decimal, symbol, b_decimal));
+                results.push(None);
+            } else {
+                let result_decimal = fixed_point_to_decimal(result);
+                decoded.lines.push(format!("   {} {} {} = {}", a_decimal, symbol, b_decimal, result_decimal));
+                results.push(Some(result_decimal));
+            }
+        }
+        decoded.overflow = Some(results.contains(&None));
+        decoded.results = Some(results);
+        Ok(decoded)
+    }
+
+    fn trace_journal(&self, codec: JournalCodec, bytes: &[u8]) -> Vec<String> {
+        trace_fields("batch", codec, bytes, |journal, commits| {
+            commits.push(format!("operation = {}", journal.read_u32()?));
+            let count = journal.read_u32()?;
+            commits.push(format!("entries = {}", count));
+            for i in 0..count {
+                let fields = ["a", "b", "result"].map(|name| format!("entries[{}].{}", i, name));
+                let overflow = format!("entries[{}].overflow", i);
+                journal.read_fields(&[(fields[0].as_str(), Field::I64), (fields[1].as_str(), Field::I64), (fields[2].as_str(), Field::I64), (overflow.as_str(), Field::U32)], commits)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+// Addition signed inside the guest. The host loads the named key and passes its seed as a fourth
+// argument, hex-encoded, after <a> <b> <key_id>.
+struct AuthenticatedAdd;
+
+impl Operation for AuthenticatedAdd {
+    fn name(&self) -> &'static str {
+        "authenticated_add"
+    }
+
+    fn description(&self) -> &'static str {
+        "Addition signed inside the guest with a named key"
+    }
+
+    fn symbol(&self) -> &'static str {
+        "+"
+    }
+
+    fn usage(&self) -> &'static str {
+        "<a> <b> <key_id>"
+    }
+
+    fn arg_count(&self) -> usize {
+        3
+    }
+
+    fn elf(&self) -> &'static [u8] {
+        GUEST_AUTHENTICATED_ADD_ELF
+    }
+
+    fn image_id(&self) -> [u32; 8] {
+        GUEST_AUTHENTICATED_ADD_ID
+    }
+
+    fn result_type(&self) -> ResultType {
+        ResultType::Integer
+    }
+
+    fn write_env(&self, builder: &mut ExecutorEnvBuilder<'_>, args: &[String], reads: &mut Vec<String>) -> Result<(), OperationError> {
+        let [a, b, key_id, seed] = args else {
+            return Err(format!("authenticated_add takes a, b, the key ID and its seed, got {} input(s)", args.len()).into());
+        };
+        let a: i64 = a.parse().map_err(|_| format!("'{}' is not an integer", a))?;
+        let b: i64 = b.parse().map_err(|_| format!("'{}' is not an integer", b))?;
+        let seed: [u8; 32] = hex::decode(seed).ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| format!("signing key '{}' seed must be 32 hex-encoded bytes", key_id))?;
+
+        // Generate a unique task ID based on timestamp and inputs
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let task_id = format!("auth_{}_{}_{}_{}", timestamp, a, b, key_id);
+
+        write_input(builder, reads, "a", &a)?;                  // Computation inputs
+        write_input(builder, reads, "b", &b)?;
+        write_input(builder, reads, "timestamp", &timestamp)?;  // Timestamp
+        write_input(builder, reads, "task_id", &task_id)?;      // Task ID
+
+        // The guest signs with the key_id seed; it stays out of the trace
+        reads.push(format!("signing_key = <{} seed>", key_id));
+        builder.write(&seed)?;
+        Ok(())
+    }
+
+    fn describe_inputs(&self, args: &[String]) -> String {
+        match args {
+            [a, b, key_id, ..] => format!("authenticated {} + {} (key: {})", a, b, key_id),
+            _ => format!("authenticated addition with inputs: {}", args.join(" ")),
+        }
+    }
+
+    // The seed never leaves the host
+    fn echo_inputs(&self, args: &[String]) -> serde_json::Value {
+        match args {
+            [a, b, key_id, ..] => serde_json::json!({ "a": a.parse::<i64>().ok(), "b": b.parse::<i64>().ok(), "key_id": key_id }),
+            _ => serde_json::Value::Null,
+        }
+    }
+
+    fn decode_journal(&self, journal: &Journal) -> Result<DecodedJournal, OperationError> {
+        let computation: AuthenticatedAddJournal = decode("authenticated_add", journal)?;
+        // The guest signed the computation; the receipt proves it did, but the signature still has to check out
+        if !computation.signature_is_valid() {
+            return Err("authenticated_add journal carries an invalid signature".into());
+        }
+
+        let mut decoded = DecodedJournal::new(computation.result as f64, computation.result)
+            .line(format!("🔢 Authenticated computation result: {} + {} = {}", computation.a, computation.b, computation.result))
+            .line(format!("🏷️  Task ID: {}", computation.task_id))
+            .line(format!("⏰ Timestamp: {}", computation.timestamp))
+            .line(format!("✍️  Signed by: {}", hex::encode(computation.public_key)));
+        decoded.authenticated = Some(computation);
+        Ok(decoded)
+    }
+}
+
+fn decode_checked(name: &str, symbol: &str, journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let CheckedDecimalJournal { a, b, result, overflow } = decode(name, journal)?;
+    let overflow = overflow != 0;
+    let (a_decimal, b_decimal) = (fixed_point_to_decimal(a), fixed_point_to_decimal(b));
+
+    let mut decoded = if overflow {
+        DecodedJournal::error("result overflows i64").line(format!("⚠️  Guest reported overflow: {} {} {}", a_decimal, symbol, b_decimal))
+    } else {
+        let result_decimal = fixed_point_to_decimal(result);
+        DecodedJournal::new(result_decimal, result).line(format!("🔢 Computation result: {} {} {} = {}", a_decimal, symbol, b_decimal, result_decimal))
+    };
+    decoded.overflow = Some(overflow);
+    Ok(decoded)
+}
+
+fn decode_divide(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let DivideJournal { a, b, quotient, remainder, flag } = decode("divide", journal)?;
+    let (a_decimal, b_decimal) = (fixed_point_to_decimal(a), fixed_point_to_decimal(b));
+
+    let mut decoded = if flag == 0 {
+        let quotient_decimal = fixed_point_to_decimal(quotient);
+        DecodedJournal::new(quotient_decimal, quotient).line(format!("🔢 Computation result: {} / {} = {} (remainder {})", a_decimal, b_decimal, quotient_decimal, remainder))
+    } else {
+        DecodedJournal::error(divide_error(flag)).line(format!("⚠️  Guest reported {}: {} / {}", divide_error(flag), a_decimal, b_decimal))
+    };
+    decoded.overflow = Some(flag == 2);
+    Ok(decoded)
+}
+
+fn decode_sqrt(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let SqrtJournal { n, sqrt } = decode("sqrt", journal)?;
+    let sqrt_decimal = fixed_point_to_decimal(sqrt);
+    Ok(DecodedJournal::new(sqrt_decimal, sqrt).line(format!("🔢 Computation result: sqrt({}) = {}", fixed_point_to_decimal(n), sqrt_decimal)))
+}
+
+fn decode_modexp(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let ModexpJournal { base, exponent, modulus, result } = decode("modexp", journal)?;
+    Ok(DecodedJournal::unsigned(result).line(format!("🔢 Computation result: {}^{} mod {} = {}", base, exponent, modulus, result)))
+}
+
+fn decode_modinverse(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let ModinverseJournal { a, n, inverse, flag } = decode("modinverse", journal)?;
+    let mut decoded = if flag == 0 {
+        DecodedJournal::unsigned(inverse).line(format!("🔢 Computation result: {}^-1 mod {} = {}", a, n, inverse))
+    } else {
+        DecodedJournal::error("not invertible").line(format!("⚠️  {} has no inverse mod {} (gcd({}, {}) != 1)", a, n, a, n))
+    };
+    decoded.invertible = Some(flag == 0);
+    Ok(decoded)
+}
+
+fn decode_gcd(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let gcd: GcdJournal = decode("gcd", journal)?;
+    // A guest that commits coefficients which do not satisfy the identity proves nothing useful
+    if !gcd.bezout_holds() {
+        return Err(format!("Invalid gcd journal: Bézout identity does not hold: {}*{} + {}*{} != {}", gcd.a, gcd.x, gcd.b, gcd.y, gcd.gcd).into());
+    }
+    let mut decoded = DecodedJournal::unsigned(gcd.gcd)
+        .line(format!("🔢 Computation result: gcd({}, {}) = {}", gcd.a, gcd.b, gcd.gcd))
+        .line(format!("🔍 Bézout: {}*({}) + {}*({}) = {}", gcd.a, gcd.x, gcd.b, gcd.y, gcd.gcd));
+    decoded.bezout = Some(gcd);
+    Ok(decoded)
+}
+
+fn decode_power(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let PowerJournal { base, exponent, result, overflow } = decode("power", journal)?;
+    let mut decoded = if overflow == 0 {
+        DecodedJournal::unsigned(result).line(format!("🔢 Computation result: {}^{} = {}", base, exponent, result))
+    } else {
+        DecodedJournal::error("result overflows u64").line(format!("⚠️  Guest reported overflow: {}^{}", base, exponent))
+    };
+    decoded.overflow = Some(overflow != 0);
+    Ok(decoded)
+}
+
+fn decode_factorial(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let FactorialJournal { n, result, overflow } = decode("factorial", journal)?;
+    let mut decoded = if overflow == 0 {
+        DecodedJournal::unsigned(result).line(format!("🔢 Computation result: {}! = {}", n, result))
+    } else {
+        DecodedJournal::error("result overflows u64").line(format!("⚠️  Guest reported overflow: {}! exceeds u64 (20! is the largest that fits)", n))
+    };
+    decoded.overflow = Some(overflow != 0);
+    Ok(decoded)
+}
+
+// ML guests commit one i64: a cluster index for k_means, a scaled decimal for the others
+fn decode_ml(operation: &str, journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let ml: MlJournal = decode(operation, journal)?;
+    let value = ml.value(operation);
+    let decoded = DecodedJournal::new(value, ml.result);
+    Ok(decoded.line(match operation {
+        "k_means" => format!("🔢 K-means clustering result: cluster {}", ml.result),
+        "logistic_regression" => format!("🔢 Logistic regression probability: {:.4} ({})", value, if value >= 0.5 { "positive" } else { "negative" }),
+        "linear_regression" => format!("🔢 Linear regression output: {}", value),
+        _ => format!("🔢 Neural network output: {}", value),
+    }))
+}
+
+const CHECKED_LAYOUT: &[(&str, Field)] = &[("a", Field::I64), ("b", Field::I64), ("result", Field::I64), ("overflow", Field::U32)];
+
+// Register every core operation, in the order `list-operations` shows them
+pub fn register(registry: &mut Registry) {
+    registry
+        .register(DecimalGuest {
+            name: "add", description: "Fixed-point addition, flagging i64 overflow", symbol: "+", usage: "<a> <b>", inputs: &["a", "b"],
+            elf: ADDITION_ELF, image_id: ADDITION_ID,
+            describe: |v| format!("{} + {}", v[0], v[1]),
+            expected: |v| Some((v[0].checked_add(v[1])?, 0)),
+            decode: |journal| decode_checked("add", "+", journal),
+            layout: CHECKED_LAYOUT,
+        })
+        .register(DecimalGuest {
+            name: "subtract", description: "Fixed-point subtraction", symbol: "-", usage: "<a> <b>", inputs: &["a", "b"],
+            elf: SUBTRACT_GUEST_ELF, image_id: SUBTRACT_GUEST_ID,
+            describe: |v| format!("{} - {}", v[0], v[1]),
+            expected: |v| Some((v[0].checked_sub(v[1])?, 0)),
+            decode: |journal| decode_checked("subtract", "-", journal),
+            layout: CHECKED_LAYOUT,
+        })
+        .register(DecimalGuest {
+            name: "multiply", description: "Fixed-point multiplication, flagging i64 overflow", symbol: "*", usage: "<a> <b>", inputs: &["a", "b"],
+            elf: MULTIPLY_GUEST_ELF, image_id: MULTIPLY_GUEST_ID,
+            describe: |v| format!("{} * {}", v[0], v[1]),
+            expected: |v| Some((v[0].checked_mul(v[1])? / SCALE, 0)),
+            decode: |journal| decode_checked("multiply", "*", journal),
+            layout: CHECKED_LAYOUT,
+        })
+        .register(DecimalGuest {
+            name: "divide", description: "Fixed-point division with remainder, flagging division by zero", symbol: "/", usage: "<a> <b>", inputs: &["a", "b"],
+            elf: GUEST_DIVIDE_ELF, image_id: GUEST_DIVIDE_ID,
+            describe: |v| format!("{} / {}", v[0], v[1]),
+            // The guest flags a zero divisor or an out-of-range quotient instead of dividing; there is nothing to compare then
+            expected: |v| match v[1] {
+                0 => None,
+                b => Some((i64::try_from((v[0] as i128 * SCALE as i128) / b as i128).ok()?, 0)),
+            },
+            decode: decode_divide,
+            layout: &[("a", Field::I64), ("b", Field::I64), ("quotient", Field::I64), ("remainder", Field::I64), ("flag", Field::U32)],
+        })
+        .register(DecimalGuest {
+            name: "sqrt", description: "Fixed-point square root", symbol: "sqrt", usage: "<n>", inputs: &["n"],
+            elf: SQRT_GUEST_ELF, image_id: SQRT_GUEST_ID,
+            describe: |v| format!("sqrt({})", v[0]),
+            // The host rounds f64::sqrt while the guest searches for the floor, so allow one unit
+            expected: |v| Some((if v[0] >= 0 { decimal_to_fixed_point(fixed_point_to_decimal(v[0]).sqrt()) } else { 0 }, 1)),
+            decode: decode_sqrt,
+            layout: &[("n", Field::I64), ("sqrt", Field::I64)],
+        })
+        .register(IntegerGuest {
+            name: "modexp", description: "Modular exponentiation", symbol: "^", usage: "<base> <exponent> <modulus>", inputs: &["base", "exponent", "modulus"],
+            elf: MODEXP_GUEST_ELF, image_id: MODEXP_GUEST_ID,
+            describe: |v| format!("{}^{} mod {}", v[0], v[1], v[2]),
+            expected: |v| Some(modular_exponentiation_host(v[0], v[1], v[2])),
+            decode: decode_modexp,
+            layout: &[("base", Field::U64), ("exponent", Field::U64), ("modulus", Field::U64), ("result", Field::U64)],
+        })
+        .register(IntegerGuest {
+            name: "modinverse", description: "Modular inverse, flagging inputs that are not coprime", symbol: "^-1", usage: "<a> <n>", inputs: &["a", "n"],
+            elf: GUEST_MODINVERSE_ELF, image_id: GUEST_MODINVERSE_ID,
+            describe: |v| format!("{}^-1 mod {}", v[0], v[1]),
+            // The guest flags a missing inverse instead of committing one; there is nothing to compare then
+            expected: |v| modular_inverse_host(v[0], v[1]),
+            decode: decode_modinverse,
+            layout: &[("a", Field::U64), ("n", Field::U64), ("inverse", Field::U64), ("flag", Field::U32)],
+        })
+        .register(IntegerGuest {
+            name: "gcd", description: "Greatest common divisor with Bézout coefficients", symbol: "gcd", usage: "<a> <b>", inputs: &["a", "b"],
+            elf: GUEST_GCD_ELF, image_id: GUEST_GCD_ID,
+            describe: |v| format!("gcd({}, {})", v[0], v[1]),
+            expected: |v| Some(gcd_host(v[0], v[1])),
+            decode: decode_gcd,
+            layout: &[("a", Field::U64), ("b", Field::U64), ("gcd", Field::U64), ("x", Field::I64), ("y", Field::I64)],
+        })
+        .register(IntegerGuest {
+            name: "power", description: "Exponentiation, flagging u64 overflow", symbol: "^", usage: "<base> <exponent>", inputs: &["base", "exponent"],
+            elf: GUEST_POWER_ELF, image_id: GUEST_POWER_ID,
+            describe: |v| format!("{}^{}", v[0], v[1]),
+            // The guest flags an overflowing power instead of committing a result
+            expected: |v| power_host(v[0], v[1]),
+            decode: decode_power,
+            layout: &[("base", Field::U64), ("exponent", Field::U64), ("result", Field::U64), ("overflow", Field::U32)],
+        })
+        .register(IntegerGuest {
+            name: "factorial", description: "Factorial, flagging u64 overflow past 20!", symbol: "!", usage: "<n>", inputs: &["n"],
+            elf: GUEST_FACTORIAL_ELF, image_id: GUEST_FACTORIAL_ID,
+            describe: |v| format!("{}!", v[0]),
+            expected: |v| factorial_host(v[0]),
+            decode: decode_factorial,
+            layout: &[("n", Field::U64), ("result", Field::U64), ("overflow", Field::U32)],
+        })
+        .register(Range)
+        .register(Batch)
+        .register(AuthenticatedAdd)
+        .register(JsonGuest { name: "k_means", description: "K-means clustering", symbol: "k-means", elf: GUEST_K_MEANS_ELF, image_id: GUEST_K_MEANS_ID, decode: |journal| decode_ml("k_means", journal) })
+        .register(JsonGuest { name: "linear_regression", description: "Linear regression prediction", symbol: "linear_reg", elf: GUEST_LINEAR_REGRESSION_ELF, image_id: GUEST_LINEAR_REGRESSION_ID, decode: |journal| decode_ml("linear_regression", journal) })
+        .register(JsonGuest { name: "neural_network", description: "Neural network inference", symbol: "neural_net", elf: GUEST_NEURAL_NETWORK_ELF, image_id: GUEST_NEURAL_NETWORK_ID, decode: |journal| decode_ml("neural_network", journal) })
+        .register(JsonGuest { name: "logistic_regression", description: "Logistic regression classification", symbol: "logistic_reg", elf: GUEST_LOGISTIC_REGRESSION_ELF, image_id: GUEST_LOGISTIC_REGRESSION_ID, decode: |journal| decode_ml("logistic_regression", journal) });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use risc0_zkvm::ExecutorEnv;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn journal_of<T: serde::Serialize>(value: &T) -> Journal {
+        let words = risc0_zkvm::serde::to_vec(value).unwrap();
+        Journal::new(words.iter().flat_map(|w| w.to_le_bytes()).collect())
+    }
+
+    fn op(name: &str) -> &'static dyn Operation {
+        Registry::builtin().get(name).unwrap()
+    }
+
+    fn write(name: &str, args: &[&str]) -> Result<Vec<String>, OperationError> {
+        let mut reads = Vec::new();
+        op(name).write_env(&mut ExecutorEnv::builder(), &to_args(args), &mut reads)?;
+        Ok(reads)
+    }
+
+    #[test]
+    fn test_host_expected() {
+        let expected = |name: &str, args: &[&str]| op(name).host_expected(&to_args(args));
+        assert_eq!(expected("divide", &["7", "2"]), Some((350000, 0)));
+        assert_eq!(expected("divide", &["7", "0"]), None);
+        assert_eq!(expected("divide", &["90000000000000", "0.00001"]), None);
+        assert_eq!(expected("sqrt", &["2"]), Some((141421, 1)));
+        assert_eq!(expected("modexp", &["4", "13", "497"]), Some((445, 0)));
+        assert_eq!(expected("modinverse", &["17", "3120"]), Some((2753, 0)));
+        assert_eq!(expected("modinverse", &["6", "9"]), None);
+        assert_eq!(expected("gcd", &["240", "46"]), Some((2, 0)));
+        assert_eq!(expected("power", &["3", "4"]), Some((81, 0)));
+        assert_eq!(expected("power", &["2", "64"]), None);
+        assert_eq!(expected("factorial", &["0"]), Some((1, 0)));
+        assert_eq!(expected("factorial", &["20"]), Some((2432902008176640000, 0)));
+        assert_eq!(expected("factorial", &["21"]), None);
+        assert_eq!(expected("range", &["5", "1", "10"]), None);
+        // Overflowing products and differences have no host value to compare against
+        assert_eq!(expected("multiply", &["99999999", "99999999"]), None);
+        assert_eq!(expected("subtract", &["1.25", "3.5"]), Some((-225000, 0)));
+        assert_eq!(expected("subtract", &["-90000000000000", "90000000000000"]), None);
+    }
+
+    #[test]
+    fn test_inputs_are_checked_for_kind_and_count() {
+        assert_eq!(write("divide", &["7", "2"]).unwrap(), vec!["a = 700000", "b = 200000"]);
+        assert_eq!(op("divide").echo_inputs(&to_args(&["7", "2.5"])), serde_json::json!({ "a": 7.0, "b": 2.5 }));
+        assert_eq!(op("modexp").describe_inputs(&to_args(&["4", "13", "497"])), "4^13 mod 497");
+        assert!(write("add", &["1"]).is_err());
+        assert!(write("add", &["1", "x"]).is_err());
+        assert!(write("modexp", &["2", "-3", "5"]).is_err());
+        assert!(write("k_means", &["not json"]).is_err());
+    }
+
+    #[test]
+    fn test_range_inputs() {
+        assert_eq!(write("range", &["5", "1", "10"]).unwrap(), vec!["secret_number = 5", "min = 1", "max = 10", "min_exclusive = 0", "max_exclusive = 0"]);
+        assert!(write("range", &["5", "1", "10", "0", "1"]).is_ok());
+        assert!(write("range", &["5", "1", "10", "2", "0"]).is_err());
+        assert!(write("range", &["5", "1", "10", "1"]).is_err());
+
+        // A range no value satisfies is rejected before proving
+        assert!(write("range", &["5", "10", "3"]).unwrap_err().to_string().contains("min (10) must not exceed max (3)"));
+        assert!(write("range", &["5", "5", "5", "0", "1"]).is_err());
+
+        let range = op("range");
+        assert_eq!(range.describe_inputs(&to_args(&["5", "1", "10", "1", "0"])), "secret ∈ (1, 10]");
+        let echoed = range.echo_inputs(&to_args(&["5", "1", "10", "1", "0"]));
+        assert_eq!(echoed, serde_json::json!({ "min": 1, "max": 10, "min_exclusive": true, "max_exclusive": false }));
+    }
+
+    #[test]
+    fn test_range_is_empty_honours_exclusive_bounds() {
+        assert!(!range_is_empty(5, 5, false, false));
+        assert!(range_is_empty(5, 5, false, true));
+        assert!(range_is_empty(5, 6, true, true));
+        assert!(!range_is_empty(5, 7, true, true));
+        assert!(range_is_empty(10, 3, false, false));
+        assert!(range_is_empty(u64::MAX, u64::MAX, true, false));
+        assert!(range_is_empty(0, 0, false, true));
+    }
+
+    #[test]
+    fn test_batch_inputs() {
+        assert_eq!(write("batch", &["multiply", "[[2.5, 4], [1, -3]]"]).unwrap(), vec!["operation = 1", "pairs = [(250000, 400000), (100000, -300000)]"]);
+        assert_eq!(op("batch").describe_inputs(&to_args(&["multiply", "[[2.5, 4], [1, -3]]"])), "multiply batch of 2 pair(s)");
+
+        assert!(write("batch", &["divide", "[[1, 2]]"]).is_err());
+        assert!(write("batch", &["add", "[]"]).is_err());
+        assert!(write("batch", &["add", "[[1, 2, 3]]"]).is_err());
+        assert!(write("batch", &["add"]).is_err());
+    }
+
+    #[test]
+    fn test_authenticated_add_keeps_seed_out_of_reads() {
+        let seed = hex::encode([7u8; 32]);
+        let reads = write("authenticated_add", &["3", "4", "alice", &seed]).unwrap();
+        assert_eq!(&reads[..2], ["a = 3", "b = 4"]);
+        assert!(reads[3].starts_with("task_id = \"auth_") && reads[3].ends_with("_3_4_alice\""));
+        assert_eq!(reads[4], "signing_key = <alice seed>");
+        assert!(reads.iter().all(|read| !read.contains(&seed)));
+
+        // The key must have been loaded by the host
+        assert!(write("authenticated_add", &["3", "4", "alice"]).is_err());
+        assert!(write("authenticated_add", &["3", "4", "alice", "abcd"]).is_err());
+        assert_eq!(op("authenticated_add").echo_inputs(&to_args(&["3", "4", "alice", &seed])), serde_json::json!({ "a": 3, "b": 4, "key_id": "alice" }));
+    }
+
+    #[test]
+    fn test_decode_batch_results_in_input_order() {
+        let entries = vec![(150000i64, 225000i64, 375000i64, 0u32), (i64::MAX, 1, 0, 1)];
+        let decoded = op("batch").decode_journal(&journal_of(&(BatchJournal::ADD, entries))).unwrap();
+        assert_eq!(decoded.results, Some(vec![Some(3.75), None]));
+        assert_eq!(decoded.overflow, Some(true));
+        assert!(decoded.note.is_some());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_bezout_coefficients() {
+        let gcd = GcdJournal { a: 240, b: 46, gcd: 2, x: -9, y: 47 };
+        let decoded = op("gcd").decode_journal(&journal_of(&gcd)).unwrap();
+        assert_eq!(decoded.bezout, Some(gcd.clone()));
+        assert!(op("gcd").decode_journal(&journal_of(&GcdJournal { y: 48, ..gcd })).is_err());
+    }
+
+    #[test]
+    fn test_result_types_use_natural_json() {
+        assert_eq!(op("add").result_type().to_json(3.75, 375000, None), serde_json::json!(3.75));
+        assert_eq!(op("modexp").result_type().to_json(445.0, 445, None), serde_json::json!(445));
+        assert_eq!(op("range").result_type().to_json(1.0, 1, None), serde_json::json!(true));
+        assert_eq!(op("range").result_type().name(), "boolean");
+        assert_eq!(ResultType::Integer.to_json(-7.0, -7, None), serde_json::json!(-7));
+
+        // 3^40 fits in u64 but not i64; the decoded integer carries it exactly
+        let power = 3u64.pow(40);
+        let decoded = op("power").decode_journal(&journal_of(&PowerJournal { base: 3, exponent: 40, result: power, overflow: 0 })).unwrap();
+        assert_eq!(decoded.integer, Some(power));
+        assert_eq!(op("power").result_type().to_json(decoded.value, decoded.fixed, decoded.integer), serde_json::json!(power));
+    }
+
+    #[test]
+    fn test_trace_lists_addition_values() {
+        let bytes = journal_of(&(150000i64, 225000i64, 375000i64, 0u32)).bytes;
+        assert_eq!(op("add").trace_journal(JournalCodec::Risc0Serde, &bytes), vec!["a = 150000", "b = 225000", "result = 375000", "overflow = 0"]);
+
+        // A short journal is reported rather than misparsed
+        let commits = op("add").trace_journal(JournalCodec::Risc0Serde, &bytes[..12]);
+        assert_eq!(commits[0], "a = 150000");
+        assert!(commits[1].starts_with("<journal does not match the add layout"));
+    }
+
+    #[test]
+    fn test_trace_lists_batch_entries() {
+        let bytes = journal_of(&(BatchJournal::ADD, vec![(150000i64, 225000i64, 375000i64, 0u32)])).bytes;
+        assert_eq!(op("batch").trace_journal(JournalCodec::Risc0Serde, &bytes), vec![
+            "operation = 0", "entries = 1", "entries[0].a = 150000", "entries[0].b = 225000", "entries[0].result = 375000", "entries[0].overflow = 0",
+        ]);
+    }
+}
@@ -0,0 +1,518 @@
+// Journal encodings shared by the host and the verifier.
+// Guests built from this repo commit with `env::commit`, which uses RISC Zero's word-oriented serde
+// codec; guests that write bytes with `env::commit_slice` produce raw little-endian values instead.
+// The two agree for 32/64-bit integers but not for bools or anything variable-length, so decoding
+// goes through a `JournalReader` for the guest's declared codec.
+//
+// The built-in guests' journals also have typed layouts below, decoded with `Journal::decode`.
+// RISC Zero's serde codec encodes a struct as its fields in order, so a guest that commits the
+// fields one by one produces the same bytes as committing the struct.
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::operation::OperationError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JournalCodec {
+    // `env::commit`: every value padded to 32-bit words, bools as a 0/1 word
+    #[default]
+    Risc0Serde,
+    // `env::commit_slice`: packed little-endian bytes, bools as a single 0/1 byte
+    RawLe,
+}
+
+impl JournalCodec {
+    pub fn reader(self, bytes: &[u8]) -> JournalReader<'_> {
+        JournalReader { codec: self, bytes, offset: 0 }
+    }
+}
+
+impl FromStr for JournalCodec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "risc0-serde" => Ok(JournalCodec::Risc0Serde),
+            "raw-le" => Ok(JournalCodec::RawLe),
+            other => Err(format!("journal codec must be 'risc0-serde' or 'raw-le', got '{}'", other)),
+        }
+    }
+}
+
+impl fmt::Display for JournalCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            JournalCodec::Risc0Serde => "risc0-serde",
+            JournalCodec::RawLe => "raw-le",
+        })
+    }
+}
+
+// Sequential reader over a journal's bytes in a given codec
+pub struct JournalReader<'a> {
+    codec: JournalCodec,
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl JournalReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], OperationError> {
+        let end = self.offset + N;
+        let chunk = self.bytes.get(self.offset..end).ok_or_else(|| {
+            format!("journal too short: needed {} bytes at offset {}, have {}", N, self.offset, self.bytes.len())
+        })?;
+        self.offset = end;
+        Ok(chunk.try_into().unwrap())
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, OperationError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, OperationError> {
+        Ok(u64::from_le_bytes(self.take()?))
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, OperationError> {
+        Ok(i64::from_le_bytes(self.take()?))
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, OperationError> {
+        let value = match self.codec {
+            JournalCodec::Risc0Serde => self.read_u32()?,
+            JournalCodec::RawLe => self.take::<1>()?[0] as u32,
+        };
+        match value {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => Err(format!("invalid bool {} in journal", other).into()),
+        }
+    }
+
+    // Bytes not consumed yet
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    // Read named fields in commit order, listing each as "name = value"
+    pub fn read_fields(&mut self, fields: &[(&str, Field)], commits: &mut Vec<String>) -> Result<(), OperationError> {
+        for (name, field) in fields {
+            let value = match field {
+                Field::I64 => self.read_i64()?.to_string(),
+                Field::U64 => self.read_u64()?.to_string(),
+                Field::U32 => self.read_u32()?.to_string(),
+                Field::Bool => self.read_bool()?.to_string(),
+            };
+            commits.push(format!("{} = {}", name, value));
+        }
+        Ok(())
+    }
+}
+
+// Type of one committed journal field, for reading a layout with `JournalReader::read_fields`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    I64,
+    U64,
+    U32,
+    Bool,
+}
+
+// Split a journal into its committed values for `--trace`: `layout` reads the fields it knows, and
+// anything left is listed as raw 32-bit words
+pub fn trace_fields(operation: &str, codec: JournalCodec, bytes: &[u8], layout: impl FnOnce(&mut JournalReader<'_>, &mut Vec<String>) -> Result<(), OperationError>) -> Vec<String> {
+    let mut journal = codec.reader(bytes);
+    let mut commits = Vec::new();
+    if let Err(e) = layout(&mut journal, &mut commits) {
+        commits.push(format!("<journal does not match the {} layout: {}>", operation, e));
+    }
+    while journal.remaining() >= 4 {
+        commits.push(format!("word = {:#010x}", journal.read_u32().unwrap()));
+    }
+    if journal.remaining() > 0 {
+        commits.push(format!("<{} trailing bytes>", journal.remaining()));
+    }
+    commits
+}
+
+// Tagged result envelope for dynamic and precompiled guests whose result is not a single i64.
+// The guest commits it with `env::commit_slice`, so it reads the same under either codec:
+//   bytes  0..4   magic    b"R0RE"
+//   bytes  4..8   tag      u32, how the payload is encoded (RESULT_TAG_*)
+//   bytes  8..12  length   u32, payload length in bytes
+//   bytes 12..    payload  exactly `length` bytes, nothing after it
+// A journal that does not start with the magic is read the legacy way, as a leading i64.
+pub const RESULT_ENVELOPE_MAGIC: [u8; 4] = *b"R0RE";
+// Payload is one little-endian i64
+pub const RESULT_TAG_I64: u32 = 0;
+// Payload is UTF-8 JSON (e.g. `serde_json::to_vec` of a struct or vector)
+pub const RESULT_TAG_JSON: u32 = 1;
+
+pub fn encode_result_envelope(tag: u32, payload: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(12 + payload.len());
+    bytes.extend_from_slice(&RESULT_ENVELOPE_MAGIC);
+    bytes.extend_from_slice(&tag.to_le_bytes());
+    bytes.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+// Decode a dynamic guest's result as JSON: the envelope's payload, or the legacy leading i64 in `codec`
+pub fn decode_dynamic_result(codec: JournalCodec, bytes: &[u8]) -> Result<serde_json::Value, OperationError> {
+    if !bytes.starts_with(&RESULT_ENVELOPE_MAGIC) {
+        return Ok(codec.reader(bytes).read_i64()?.into());
+    }
+
+    let mut header = JournalCodec::RawLe.reader(&bytes[RESULT_ENVELOPE_MAGIC.len()..]);
+    let tag = header.read_u32()?;
+    let length = header.read_u32()? as usize;
+    let payload = &bytes[12..];
+    if payload.len() != length {
+        return Err(format!("result envelope declares {} payload bytes but carries {}", length, payload.len()).into());
+    }
+    match tag {
+        RESULT_TAG_I64 => {
+            let value: [u8; 8] = payload.try_into().map_err(|_| format!("i64 result payload must be 8 bytes, got {}", length))?;
+            Ok(i64::from_le_bytes(value).into())
+        },
+        RESULT_TAG_JSON => Ok(serde_json::from_slice(payload).map_err(|e| format!("invalid JSON result payload: {}", e))?),
+        other => Err(format!("unknown result envelope tag {}", other).into()),
+    }
+}
+
+// add, subtract and multiply: fixed-point inputs and result, then the overflow flag (0 = ok, 1 = overflow)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckedDecimalJournal {
+    pub a: i64,
+    pub b: i64,
+    pub result: i64,
+    pub overflow: u32,
+}
+
+// batch: the operation code, then one add/multiply layout per input pair, in input order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchJournal {
+    pub operation: u32,
+    pub entries: Vec<CheckedDecimalJournal>,
+}
+
+impl BatchJournal {
+    // Operation codes the batch guest reads
+    pub const ADD: u32 = 0;
+    pub const MULTIPLY: u32 = 1;
+}
+
+// divide: fixed-point inputs, quotient and remainder, then the flag
+// (0 = ok, 1 = division by zero, 2 = quotient overflows i64)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DivideJournal {
+    pub a: i64,
+    pub b: i64,
+    pub quotient: i64,
+    pub remainder: i64,
+    pub flag: u32,
+}
+
+// sqrt: fixed-point input and its square root
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SqrtJournal {
+    pub n: i64,
+    pub sqrt: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModexpJournal {
+    pub base: u64,
+    pub exponent: u64,
+    pub modulus: u64,
+    pub result: u64,
+}
+
+// modinverse: the value, the modulus, the inverse, then the flag (0 = ok, 1 = not invertible)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModinverseJournal {
+    pub a: u64,
+    pub n: u64,
+    pub inverse: u64,
+    pub flag: u32,
+}
+
+// gcd: the inputs, their gcd and Bézout coefficients with a*x + b*y = gcd
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GcdJournal {
+    pub a: u64,
+    pub b: u64,
+    pub gcd: u64,
+    pub x: i64,
+    pub y: i64,
+}
+
+impl GcdJournal {
+    // Re-check the committed values without trusting the guest: a*x + b*y = gcd (exact in i128)
+    // and gcd divides both inputs
+    pub fn bezout_holds(&self) -> bool {
+        let identity = self.a as i128 * self.x as i128 + self.b as i128 * self.y as i128 == self.gcd as i128;
+        // is_multiple_of(0) holds only for 0, which is what gcd(0, 0) = 0 needs
+        identity && self.a.is_multiple_of(self.gcd) && self.b.is_multiple_of(self.gcd)
+    }
+}
+
+// power: the base, the exponent, base^exponent, then the flag (0 = ok, 1 = overflow, result is 0)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PowerJournal {
+    pub base: u64,
+    pub exponent: u64,
+    pub result: u64,
+    pub overflow: u32,
+}
+
+// factorial: n, n!, then the flag (0 = ok, 1 = overflow past 20!, result is 0)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactorialJournal {
+    pub n: u64,
+    pub result: u64,
+    pub overflow: u32,
+}
+
+// range: the secret itself stays private, only the outcome and the bounds are committed, then the
+// flag (0 = ok, 1 = no value satisfies the bounds) and whether each bound was exclusive (1) or
+// inclusive (0)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RangeJournal {
+    pub in_range: bool,
+    pub above_min: bool,
+    pub below_max: bool,
+    pub min: u64,
+    pub max: u64,
+    pub invalid_range: u32,
+    pub min_exclusive: u32,
+    pub max_exclusive: u32,
+}
+
+impl RangeJournal {
+    // The proven interval in bracket notation, e.g. "[18, 65)"
+    pub fn interval(&self) -> String {
+        let open = if self.min_exclusive != 0 { '(' } else { '[' };
+        let close = if self.max_exclusive != 0 { ')' } else { ']' };
+        format!("{}{}, {}{}", open, self.min, self.max, close)
+    }
+}
+
+// authenticated_add: the guest commits this struct directly. It signs `signed_digest()` inside the
+// zkVM with the key the host writes in, so the receipt binds the result to that key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticatedAddJournal {
+    pub a: i64,
+    pub b: i64,
+    pub result: i64,
+    pub timestamp: u64,
+    pub task_id: String,
+    pub public_key: [u8; 32],
+    // 64-byte Ed25519 signature; a Vec because serde only implements arrays up to 32
+    pub signature: Vec<u8>,
+}
+
+impl AuthenticatedAddJournal {
+    // SHA-256 of the risc0-serde encoding of (a, b, result, timestamp, task_id), the message the
+    // guest signs
+    pub fn signed_digest(&self) -> Digest {
+        let words = risc0_zkvm::serde::to_vec(&(self.a, self.b, self.result, self.timestamp, &self.task_id))
+            .expect("serializing integers and a string cannot fail");
+        let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+        *Impl::hash_bytes(&bytes)
+    }
+
+    // Re-check the committed signature against the committed public key
+    pub fn signature_is_valid(&self) -> bool {
+        let Ok(signature) = ed25519_compact::Signature::from_slice(&self.signature) else {
+            return false;
+        };
+        ed25519_compact::PublicKey::new(self.public_key)
+            .verify(self.signed_digest().as_bytes(), &signature)
+            .is_ok()
+    }
+}
+
+// k_anonymity's quasi-identifier columns and data_completeness's required fields, committed as a
+// digest: SHA-256 over each name's length (u32 little-endian) and UTF-8 bytes, in input order.
+// Verifiers hash the names they expect and compare.
+pub fn name_list_digest<S: AsRef<str>>(names: &[S]) -> Digest {
+    let mut bytes = Vec::new();
+    for name in names {
+        bytes.extend_from_slice(&(name.as_ref().len() as u32).to_le_bytes());
+        bytes.extend_from_slice(name.as_ref().as_bytes());
+    }
+    *Impl::hash_bytes(&bytes)
+}
+
+// k_means, linear_regression, neural_network and logistic_regression: one scaled i64, or a
+// negative status code when the inputs were rejected
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MlJournal {
+    pub result: i64,
+}
+
+impl MlJournal {
+    // Factor the guest multiplied its result by, the same `fixed-point` constant the guest imports;
+    // k_means commits a cluster index as-is
+    pub fn scale(operation: &str) -> f64 {
+        match operation {
+            "linear_regression" | "neural_network" => fixed_point::REGRESSION_SCALE as f64,
+            "logistic_regression" => fixed_point::PROBABILITY_SCALE as f64,
+            _ => 1.0,
+        }
+    }
+
+    pub fn value(&self, operation: &str) -> f64 {
+        self.result as f64 / Self::scale(operation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_bytes(words: Vec<u32>) -> Vec<u8> {
+        words.iter().flat_map(|w| w.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_risc0_serde_codec() {
+        // What a guest committing (true, -5i64, 7u64) via `env::commit` produces
+        let bytes = to_bytes(risc0_zkvm::serde::to_vec(&(true, -5i64, 7u64)).unwrap());
+        
+        let mut reader = JournalCodec::Risc0Serde.reader(&bytes);
+        assert!(reader.read_bool().unwrap());
+        assert_eq!(reader.read_i64().unwrap(), -5);
+        assert_eq!(reader.read_u64().unwrap(), 7);
+        assert_eq!(reader.remaining(), 0);
+        assert!(reader.read_u32().is_err());
+    }
+
+    #[test]
+    fn test_raw_le_codec() {
+        // The same values written packed with `env::commit_slice`
+        let mut bytes = vec![1u8];
+        bytes.extend((-5i64).to_le_bytes());
+        bytes.extend(7u64.to_le_bytes());
+        
+        let mut reader = JournalCodec::RawLe.reader(&bytes);
+        assert!(reader.read_bool().unwrap());
+        assert_eq!(reader.read_i64().unwrap(), -5);
+        assert_eq!(reader.read_u64().unwrap(), 7);
+        assert_eq!(reader.remaining(), 0);
+        
+        // Reading packed bytes as risc0-serde misaligns after the bool
+        let mut reader = JournalCodec::Risc0Serde.reader(&bytes);
+        assert!(reader.read_bool().is_err());
+    }
+
+    #[test]
+    fn test_typed_journals_match_field_by_field_commits() {
+        use risc0_zkvm::Journal;
+        
+        // What guest-modexp commits with four separate `env::commit` calls
+        let bytes = to_bytes(risc0_zkvm::serde::to_vec(&(4u64, 13u64, 497u64, 445u64)).unwrap());
+        let journal = Journal::new(bytes.clone());
+        let decoded: ModexpJournal = journal.decode().unwrap();
+        assert_eq!(decoded, ModexpJournal { base: 4, exponent: 13, modulus: 497, result: 445 });
+        assert_eq!(to_bytes(risc0_zkvm::serde::to_vec(&decoded).unwrap()), bytes);
+        
+        let range = RangeJournal { in_range: true, above_min: true, below_max: true, min: 18, max: 65, invalid_range: 0, min_exclusive: 0, max_exclusive: 1 };
+        let bytes = to_bytes(risc0_zkvm::serde::to_vec(&(true, true, true, 18u64, 65u64, 0u32, 0u32, 1u32)).unwrap());
+        assert_eq!(Journal::new(bytes).decode::<RangeJournal>().unwrap(), range);
+        assert_eq!(range.interval(), "[18, 65)");
+        
+        // guest-batch commits its entries as a Vec of (a, b, result, flag) tuples
+        let bytes = to_bytes(risc0_zkvm::serde::to_vec(&(BatchJournal::MULTIPLY, vec![(250000i64, 400000i64, 1000000i64, 0u32), (1, 2, 0, 1)])).unwrap());
+        let batch: BatchJournal = Journal::new(bytes).decode().unwrap();
+        assert_eq!(batch.operation, BatchJournal::MULTIPLY);
+        assert_eq!(batch.entries[0], CheckedDecimalJournal { a: 250000, b: 400000, result: 1000000, overflow: 0 });
+        assert_eq!(batch.entries[1].overflow, 1);
+        
+        // A journal shorter than the layout fails to decode instead of reading past the end
+        assert!(Journal::new(vec![0u8; 8]).decode::<DivideJournal>().is_err());
+    }
+
+    #[test]
+    fn test_gcd_journal_checks_bezout_identity() {
+        let journal = GcdJournal { a: 240, b: 46, gcd: 2, x: -9, y: 47 };
+        assert!(journal.bezout_holds());
+        assert!(!GcdJournal { y: 48, ..journal.clone() }.bezout_holds());
+        // 240*1 + 46*(-5) = 10 satisfies the identity, but 10 does not divide 46
+        assert!(!GcdJournal { gcd: 10, x: 1, y: -5, ..journal }.bezout_holds());
+        assert!(GcdJournal { a: 0, b: 0, gcd: 0, x: 1, y: 0 }.bezout_holds());
+    }
+
+    #[test]
+    fn test_name_list_digest_matches_guests() {
+        // The k_anonymity and data_completeness guests' hash_names tests use the same vectors
+        assert_eq!(name_list_digest(&["zip", "age_band"]).to_string(), "041cb8067c72b349d8cee1919526125beec5beb4801cc18110456159a1de2ea5");
+        assert_eq!(name_list_digest(&["id", "email"]).to_string(), "a6bd3eebba1f52619b10b331777f9d3418e9bf1a87ad4a2e73355e263c5e5330");
+        assert_eq!(name_list_digest(&["zip".to_string(), "age_band".to_string()]), name_list_digest(&["zip", "age_band"]));
+    }
+
+    #[test]
+    fn test_authenticated_add_signature_covers_computation() {
+        let keypair = ed25519_compact::KeyPair::from_seed(ed25519_compact::Seed::new([7u8; 32]));
+        let mut journal = AuthenticatedAddJournal {
+            a: 3, b: 4, result: 7, timestamp: 1700000000, task_id: "auth_1700000000_3_4_default".to_string(),
+            public_key: *keypair.pk, signature: Vec::new(),
+        };
+        journal.signature = keypair.sk.sign(journal.signed_digest().as_bytes(), None).to_vec();
+        assert!(journal.signature_is_valid());
+        
+        // Changing the result, or claiming another key, breaks the signature
+        assert!(!AuthenticatedAddJournal { result: 8, ..journal.clone() }.signature_is_valid());
+        assert!(!AuthenticatedAddJournal { public_key: [9u8; 32], ..journal.clone() }.signature_is_valid());
+        assert!(!AuthenticatedAddJournal { signature: vec![0u8; 63], ..journal }.signature_is_valid());
+    }
+    
+    #[test]
+    fn test_ml_journal_unscales_per_operation() {
+        let journal = MlJournal { result: 12500 };
+        assert_eq!(journal.value("linear_regression"), 12.5);
+        assert_eq!(journal.value("logistic_regression"), 1.25);
+        assert_eq!(journal.value("k_means"), 12500.0);
+        
+        // The guests scale their results by the same fixed-point constants
+        assert_eq!(MlJournal::scale("neural_network"), fixed_point::REGRESSION_SCALE as f64);
+        assert_eq!(MlJournal::scale("logistic_regression"), fixed_point::PROBABILITY_SCALE as f64);
+    }
+
+    #[test]
+    fn test_codec_names_round_trip() {
+        for codec in [JournalCodec::Risc0Serde, JournalCodec::RawLe] {
+            assert_eq!(codec.to_string().parse::<JournalCodec>().unwrap(), codec);
+        }
+        assert!("bincode".parse::<JournalCodec>().is_err());
+    }
+
+    #[test]
+    fn test_dynamic_result_envelope_decodes_to_json() {
+        let envelope = encode_result_envelope(RESULT_TAG_JSON, br#"{"label": 1, "scores": [0.25, 0.75]}"#);
+        for codec in [JournalCodec::Risc0Serde, JournalCodec::RawLe] {
+            assert_eq!(decode_dynamic_result(codec, &envelope).unwrap(), serde_json::json!({"label": 1, "scores": [0.25, 0.75]}));
+        }
+        let envelope = encode_result_envelope(RESULT_TAG_I64, &(-7i64).to_le_bytes());
+        assert_eq!(decode_dynamic_result(JournalCodec::Risc0Serde, &envelope).unwrap(), serde_json::json!(-7));
+    }
+
+    #[test]
+    fn test_dynamic_result_without_envelope_is_leading_i64() {
+        let bytes = to_bytes(risc0_zkvm::serde::to_vec(&(42i64, 1u32)).unwrap());
+        assert_eq!(decode_dynamic_result(JournalCodec::Risc0Serde, &bytes).unwrap(), serde_json::json!(42));
+    }
+
+    #[test]
+    fn test_malformed_envelopes_are_rejected() {
+        let mut truncated = encode_result_envelope(RESULT_TAG_JSON, b"[1, 2]");
+        truncated.pop();
+        assert!(decode_dynamic_result(JournalCodec::RawLe, &truncated).is_err());
+        assert!(decode_dynamic_result(JournalCodec::RawLe, &encode_result_envelope(9, b"")).is_err());
+        assert!(decode_dynamic_result(JournalCodec::RawLe, &encode_result_envelope(RESULT_TAG_I64, &[1, 2])).is_err());
+        assert!(decode_dynamic_result(JournalCodec::RawLe, b"R0RE\x01").is_err());
+    }
+}
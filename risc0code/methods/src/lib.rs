@@ -1 +1,7 @@
 include!(concat!(env!("OUT_DIR"), "/methods.rs"));
+
+pub mod builtin;
+pub mod journal;
+pub mod operation;
+pub mod proof_file;
+pub mod scale;
@@ -0,0 +1,1010 @@
+// Registry of built-in operations shared by the host and the verifier.
+// Adding an operation means implementing `Operation` (or describing a JSON guest with `JsonGuest`)
+// and registering it once in `Registry::builtin`; CLI dispatch, input writing, filename detection
+// and journal decoding all go through the registry.
+use crate::*;
+use crate::journal::{AuthenticatedAddJournal, GcdJournal, JournalCodec, RangeJournal};
+use risc0_zkvm::sha::Digest;
+use risc0_zkvm::{ExecutorEnvBuilder, Journal};
+use serde::Serialize;
+use std::fmt;
+use std::sync::OnceLock;
+
+pub type OperationError = Box<dyn std::error::Error>;
+
+// Fixed-point arithmetic scale factor, as a divisor for decoding
+const SCALE: f64 = crate::scale::SCALE as f64;
+
+// Headline value decoded from a journal, plus human-readable detail lines for display
+#[derive(Debug, Default)]
+pub struct DecodedJournal {
+    pub value: f64,
+    pub fixed: i64,
+    // Only for integer operations: the committed u64 exactly; `fixed` wraps negative past i64::MAX
+    pub integer: Option<u64>,
+    // Why there is no result: an empty journal, or an error the guest reported instead of a value
+    pub note: Option<&'static str>,
+    // Whether the guest's overflow flag was set; None for operations without one
+    pub overflow: Option<bool>,
+    // Only for batch: one result per input pair, in input order; None where that pair overflowed
+    pub results: Option<Vec<Option<f64>>>,
+    // Only for modinverse: whether gcd(a, n) == 1, so that an inverse exists
+    pub invertible: Option<bool>,
+    // Only for gcd: every committed value, after re-checking the Bézout identity
+    pub bezout: Option<GcdJournal>,
+    // Only for range: the outcome, both one-sided checks, the bounds, the empty-range flag and
+    // which bounds were exclusive
+    pub range: Option<RangeJournal>,
+    // Only for authenticated_add: the signed computation, after re-checking the signature
+    pub authenticated: Option<AuthenticatedAddJournal>,
+    pub lines: Vec<String>,
+}
+
+impl DecodedJournal {
+    pub fn new(value: f64, fixed: i64) -> Self {
+        DecodedJournal { value, fixed, ..Default::default() }
+    }
+
+    // Status-coded journals report the status itself as the headline value
+    pub fn status(status: i64) -> Self {
+        Self::new(status as f64, status)
+    }
+
+    // An integer operation's u64 result, kept exact in `integer`
+    pub fn unsigned(value: u64) -> Self {
+        DecodedJournal { integer: Some(value), ..Self::new(value as f64, value as i64) }
+    }
+
+    // The guest reported an error instead of a result
+    pub fn error(note: &'static str) -> Self {
+        DecodedJournal { note: Some(note), ..Default::default() }
+    }
+
+    // Side-effect-only guests may halt without committing anything; the proof is still verifiable
+    pub fn empty() -> Self {
+        Self::error("no output committed").line("🔢 No output committed (empty journal)".to_string())
+    }
+
+    pub fn line(mut self, line: String) -> Self {
+        self.lines.push(line);
+        self
+    }
+}
+
+// The natural JSON type of an operation's headline result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultType {
+    Decimal,
+    Integer,
+    Boolean,
+}
+
+impl ResultType {
+    // Reported as `result_type` in the host's JSON output
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResultType::Decimal => "decimal",
+            ResultType::Integer => "integer",
+            ResultType::Boolean => "boolean",
+        }
+    }
+
+    // The decoded value as JSON of this type. Integer operations that commit a u64 pass it as
+    // `integer`, since values past i64::MAX do not survive `fixed`.
+    pub fn to_json(&self, value: f64, fixed: i64, integer: Option<u64>) -> serde_json::Value {
+        match self {
+            ResultType::Decimal => serde_json::json!(value),
+            ResultType::Integer => integer.map_or_else(|| serde_json::json!(fixed), |integer| serde_json::json!(integer)),
+            ResultType::Boolean => serde_json::Value::Bool(fixed != 0),
+        }
+    }
+}
+
+// Write one guest input, recording it in `reads` (the host lists them for `--trace`)
+pub fn write_input<T: Serialize + fmt::Debug>(builder: &mut ExecutorEnvBuilder<'_>, reads: &mut Vec<String>, name: &str, value: &T) -> Result<(), OperationError> {
+    reads.push(format!("{} = {:?}", name, value));
+    builder.write(value)?;
+    Ok(())
+}
+
+pub trait Operation: Send + Sync {
+    // CLI name, also embedded in saved proof filenames
+    fn name(&self) -> &'static str;
+
+    // Short human-readable description
+    fn description(&self) -> &'static str;
+
+    // Symbol shown in progress output
+    fn symbol(&self) -> &'static str;
+
+    // Positional CLI arguments after the operation name
+    fn usage(&self) -> &'static str {
+        "<json_inputs>"
+    }
+
+    fn arg_count(&self) -> usize {
+        1
+    }
+
+    fn elf(&self) -> &'static [u8];
+
+    fn image_id(&self) -> [u32; 8];
+
+    // How the headline result is typed
+    fn result_type(&self) -> ResultType {
+        ResultType::Decimal
+    }
+
+    // Check `args` and write them to the executor environment in the order the guest reads them,
+    // recording each input in `reads`. `args` holds the `arg_count()` CLI values, followed by
+    // anything the host supplies itself (range's bound flags, authenticated_add's key seed).
+    fn write_env(&self, builder: &mut ExecutorEnvBuilder<'_>, args: &[String], reads: &mut Vec<String>) -> Result<(), OperationError>;
+
+    // Human-readable form of the computation being proven
+    fn describe_inputs(&self, args: &[String]) -> String {
+        format!("{} with inputs: {}", self.description(), args.join(" "))
+    }
+
+    // `args` as the `inputs` value of the host's JSON output: a single JSON argument verbatim,
+    // anything else as an array of the raw arguments
+    fn echo_inputs(&self, args: &[String]) -> serde_json::Value {
+        match args {
+            [json] => serde_json::from_str(json).unwrap_or_else(|_| serde_json::json!(args)),
+            _ => serde_json::json!(args),
+        }
+    }
+
+    // The host's own computation of the result for `--compare-host-compute`, as the value the guest
+    // commits and the difference allowed from it. None when the host does not compute it.
+    fn host_expected(&self, _args: &[String]) -> Option<(i64, i64)> {
+        None
+    }
+
+    fn decode_journal(&self, journal: &Journal) -> Result<DecodedJournal, OperationError>;
+
+    // How the guest encodes its journal; guests using `env::commit` need not override this
+    fn journal_codec(&self) -> JournalCodec {
+        JournalCodec::Risc0Serde
+    }
+
+    // The journal's committed values for `--trace`; by default the decoder's detail lines
+    fn trace_journal(&self, _codec: JournalCodec, bytes: &[u8]) -> Vec<String> {
+        match self.decode_journal(&Journal::new(bytes.to_vec())) {
+            Ok(decoded) => decoded.lines,
+            Err(e) => vec![format!("<{}>", e)],
+        }
+    }
+}
+
+// A guest that reads one JSON string and whose journal is decoded by `decode`
+pub struct JsonGuest {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub symbol: &'static str,
+    pub elf: &'static [u8],
+    pub image_id: [u32; 8],
+    pub decode: fn(&Journal) -> Result<DecodedJournal, OperationError>,
+}
+
+impl Operation for JsonGuest {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    fn elf(&self) -> &'static [u8] {
+        self.elf
+    }
+
+    fn image_id(&self) -> [u32; 8] {
+        self.image_id
+    }
+
+    fn write_env(&self, builder: &mut ExecutorEnvBuilder<'_>, args: &[String], reads: &mut Vec<String>) -> Result<(), OperationError> {
+        let [inputs_json] = args else {
+            return Err(format!("{} takes 1 JSON input, got {}", self.name, args.len()).into());
+        };
+
+        // Parse inputs JSON to validate it's valid JSON
+        let _inputs: serde_json::Value = serde_json::from_str(inputs_json)
+            .map_err(|e| format!("Invalid JSON inputs: {}", e))?;
+
+        write_input(builder, reads, "inputs_json", inputs_json)    // Write JSON string directly
+    }
+
+    fn decode_journal(&self, journal: &Journal) -> Result<DecodedJournal, OperationError> {
+        (self.decode)(journal)
+    }
+}
+
+#[derive(Default)]
+pub struct Registry {
+    operations: Vec<Box<dyn Operation>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Register an operation; names must be unique
+    pub fn register(&mut self, operation: impl Operation + 'static) -> &mut Self {
+        assert!(self.get(operation.name()).is_none(), "operation '{}' registered twice", operation.name());
+        self.operations.push(Box::new(operation));
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Operation> {
+        self.operations.iter().find(|op| op.name() == name).map(|op| op.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Operation> {
+        self.operations.iter().map(|op| op.as_ref())
+    }
+
+    // Detect the operation a saved proof belongs to from its filename (e.g. proof_twap_1700000000.bin).
+    // The longest matching name wins so that overlapping names resolve to the most specific operation.
+    pub fn detect_from_filename(&self, filename: &str) -> Option<&dyn Operation> {
+        self.iter()
+            .filter(|op| filename.contains(op.name()))
+            .max_by_key(|op| op.name().len())
+    }
+
+    // Every operation built into this workspace: the core operations from `builtin`, then the JSON
+    // guests. Built on first use and shared for the life of the process.
+    pub fn builtin() -> &'static Registry {
+        static BUILTIN: OnceLock<Registry> = OnceLock::new();
+        BUILTIN.get_or_init(|| {
+            let mut registry = Self::new();
+            crate::builtin::register(&mut registry);
+            Self::register_json_guests(&mut registry);
+            registry
+        })
+    }
+
+    fn register_json_guests(registry: &mut Registry) {
+        registry
+            .register(JsonGuest { name: "scoring_rubric", description: "Scoring rubric", symbol: "rubric", elf: GUEST_SCORING_RUBRIC_ELF, image_id: GUEST_SCORING_RUBRIC_ID, decode: decode_scoring_rubric })
+            .register(JsonGuest { name: "interval_tree", description: "Interval tree lookup", symbol: "interval", elf: GUEST_INTERVAL_TREE_ELF, image_id: GUEST_INTERVAL_TREE_ID, decode: decode_interval_tree })
+            .register(JsonGuest { name: "escrow_release", description: "Escrow release", symbol: "escrow", elf: GUEST_ESCROW_RELEASE_ELF, image_id: GUEST_ESCROW_RELEASE_ID, decode: decode_escrow_release })
+            .register(JsonGuest { name: "data_completeness", description: "Data completeness check", symbol: "completeness", elf: GUEST_DATA_COMPLETENESS_ELF, image_id: GUEST_DATA_COMPLETENESS_ID, decode: decode_data_completeness })
+            .register(JsonGuest { name: "k_anonymity", description: "K-anonymity check", symbol: "k-anon", elf: GUEST_K_ANONYMITY_ELF, image_id: GUEST_K_ANONYMITY_ID, decode: decode_k_anonymity })
+            .register(JsonGuest { name: "loan_amortization", description: "Loan amortization", symbol: "amortize", elf: GUEST_LOAN_AMORTIZATION_ELF, image_id: GUEST_LOAN_AMORTIZATION_ID, decode: decode_loan_amortization })
+            .register(JsonGuest { name: "deck_shuffle", description: "Deck shuffle", symbol: "shuffle", elf: GUEST_DECK_SHUFFLE_ELF, image_id: GUEST_DECK_SHUFFLE_ID, decode: decode_deck_shuffle })
+            .register(JsonGuest { name: "levenshtein", description: "Levenshtein distance", symbol: "edit-distance", elf: GUEST_LEVENSHTEIN_ELF, image_id: GUEST_LEVENSHTEIN_ID, decode: decode_levenshtein })
+            .register(JsonGuest { name: "convolution", description: "2D convolution", symbol: "conv2d", elf: GUEST_CONVOLUTION_ELF, image_id: GUEST_CONVOLUTION_ID, decode: decode_convolution })
+            .register(JsonGuest { name: "value_at_risk", description: "Value-at-risk check", symbol: "VaR", elf: GUEST_VALUE_AT_RISK_ELF, image_id: GUEST_VALUE_AT_RISK_ID, decode: decode_value_at_risk })
+            .register(JsonGuest { name: "histogram_equalization", description: "Histogram equalization", symbol: "equalize", elf: GUEST_HISTOGRAM_EQUALIZATION_ELF, image_id: GUEST_HISTOGRAM_EQUALIZATION_ID, decode: decode_histogram_equalization })
+            .register(JsonGuest { name: "ensemble_vote", description: "Ensemble majority vote", symbol: "majority", elf: GUEST_ENSEMBLE_VOTE_ELF, image_id: GUEST_ENSEMBLE_VOTE_ID, decode: decode_ensemble_vote })
+            .register(JsonGuest { name: "recommendation_score", description: "Recommendation score", symbol: "recommend", elf: GUEST_RECOMMENDATION_SCORE_ELF, image_id: GUEST_RECOMMENDATION_SCORE_ID, decode: decode_recommendation_score })
+            .register(JsonGuest { name: "twap", description: "Time-weighted average price", symbol: "twap", elf: GUEST_TWAP_ELF, image_id: GUEST_TWAP_ID, decode: decode_twap })
+            .register(JsonGuest { name: "polynomial_root", description: "Polynomial root check", symbol: "root", elf: GUEST_POLYNOMIAL_ROOT_ELF, image_id: GUEST_POLYNOMIAL_ROOT_ID, decode: decode_polynomial_root })
+            .register(JsonGuest { name: "quadratic_voting", description: "Quadratic voting tally", symbol: "qv", elf: GUEST_QUADRATIC_VOTING_ELF, image_id: GUEST_QUADRATIC_VOTING_ID, decode: decode_quadratic_voting })
+            .register(JsonGuest { name: "calculator", description: "Tagged calculator", symbol: "calc", elf: GUEST_CALCULATOR_ELF, image_id: GUEST_CALCULATOR_ID, decode: decode_calculator })
+            .register(JsonGuest { name: "redaction", description: "Redaction check", symbol: "redact", elf: GUEST_REDACTION_ELF, image_id: GUEST_REDACTION_ID, decode: decode_redaction })
+            .register(JsonGuest { name: "permission_bitmask", description: "Permission bitmask check", symbol: "perm", elf: GUEST_PERMISSION_BITMASK_ELF, image_id: GUEST_PERMISSION_BITMASK_ID, decode: decode_permission_bitmask })
+            .register(JsonGuest { name: "random_forest", description: "Random forest inference", symbol: "forest", elf: GUEST_RANDOM_FOREST_ELF, image_id: GUEST_RANDOM_FOREST_ID, decode: decode_random_forest })
+            .register(JsonGuest { name: "inventory_reorder", description: "Inventory reorder decision", symbol: "reorder", elf: GUEST_INVENTORY_REORDER_ELF, image_id: GUEST_INVENTORY_REORDER_ID, decode: decode_inventory_reorder })
+            .register(JsonGuest { name: "calibration_tolerance", description: "Calibration tolerance check", symbol: "cal", elf: GUEST_CALIBRATION_TOLERANCE_ELF, image_id: GUEST_CALIBRATION_TOLERANCE_ID, decode: decode_calibration_tolerance })
+            .register(JsonGuest { name: "state_transition", description: "State transition", symbol: "state", elf: GUEST_STATE_TRANSITION_ELF, image_id: GUEST_STATE_TRANSITION_ID, decode: decode_state_transition })
+            .register(JsonGuest { name: "ab_test", description: "A/B test significance", symbol: "ab", elf: GUEST_AB_TEST_ELF, image_id: GUEST_AB_TEST_ID, decode: decode_ab_test })
+            .register(JsonGuest { name: "debt_to_income", description: "Debt-to-income check", symbol: "dti", elf: GUEST_DEBT_TO_INCOME_ELF, image_id: GUEST_DEBT_TO_INCOME_ID, decode: decode_debt_to_income })
+            .register(JsonGuest { name: "survey_aggregate", description: "Survey aggregate", symbol: "survey", elf: GUEST_SURVEY_AGGREGATE_ELF, image_id: GUEST_SURVEY_AGGREGATE_ID, decode: decode_survey_aggregate })
+            .register(JsonGuest { name: "minimum_spanning_tree", description: "Minimum spanning tree weight", symbol: "mst", elf: GUEST_MINIMUM_SPANNING_TREE_ELF, image_id: GUEST_MINIMUM_SPANNING_TREE_ID, decode: decode_minimum_spanning_tree })
+            .register(JsonGuest { name: "dividend_yield", description: "Dividend yield screen", symbol: "yield", elf: GUEST_DIVIDEND_YIELD_ELF, image_id: GUEST_DIVIDEND_YIELD_ID, decode: decode_dividend_yield })
+            .register(JsonGuest { name: "shipping_cost", description: "Shipping cost lookup", symbol: "ship", elf: GUEST_SHIPPING_COST_ELF, image_id: GUEST_SHIPPING_COST_ID, decode: decode_shipping_cost })
+            .register(JsonGuest { name: "eligibility_scorecard", description: "Multi-factor eligibility scorecard", symbol: "score", elf: GUEST_ELIGIBILITY_SCORECARD_ELF, image_id: GUEST_ELIGIBILITY_SCORECARD_ID, decode: decode_eligibility_scorecard })
+            .register(JsonGuest { name: "geometric_mean", description: "Geometric mean", symbol: "geomean", elf: GUEST_GEOMETRIC_MEAN_ELF, image_id: GUEST_GEOMETRIC_MEAN_ID, decode: decode_geometric_mean })
+            .register(JsonGuest { name: "scheduling_priority", description: "Weighted scheduling priority", symbol: "schedule", elf: GUEST_SCHEDULING_PRIORITY_ELF, image_id: GUEST_SCHEDULING_PRIORITY_ID, decode: decode_scheduling_priority })
+            .register(JsonGuest { name: "record_dedup", description: "Record deduplication check", symbol: "dedup", elf: GUEST_RECORD_DEDUP_ELF, image_id: GUEST_RECORD_DEDUP_ID, decode: decode_record_dedup })
+            .register(JsonGuest { name: "energy_billing", description: "Tiered energy billing", symbol: "bill", elf: GUEST_ENERGY_BILLING_ELF, image_id: GUEST_ENERGY_BILLING_ID, decode: decode_energy_billing })
+            .register(JsonGuest { name: "fraud_risk", description: "Fraud-risk gate", symbol: "risk", elf: GUEST_FRAUD_RISK_ELF, image_id: GUEST_FRAUD_RISK_ID, decode: decode_fraud_risk })
+            .register(JsonGuest { name: "pedersen_commitment", description: "Pedersen-style commitment opening", symbol: "commit", elf: GUEST_PEDERSEN_COMMITMENT_ELF, image_id: GUEST_PEDERSEN_COMMITMENT_ID, decode: decode_pedersen_commitment })
+            .register(JsonGuest { name: "verify_signature", description: "Ed25519 signature verification", symbol: "ed25519", elf: GUEST_VERIFY_SIGNATURE_ELF, image_id: GUEST_VERIFY_SIGNATURE_ID, decode: decode_verify_signature })
+            .register(JsonGuest { name: "decision_tree", description: "Decision tree classification", symbol: "tree", elf: GUEST_DECISION_TREE_ELF, image_id: GUEST_DECISION_TREE_ID, decode: decode_decision_tree })
+            .register(JsonGuest { name: "matrix_multiply", description: "Matrix multiplication", symbol: "matmul", elf: GUEST_MATRIX_MULTIPLY_ELF, image_id: GUEST_MATRIX_MULTIPLY_ID, decode: decode_matrix_multiply });
+    }
+}
+
+// Rubric guest commits the total score (i64, negative on error), the pass flag (bool) and the
+// threshold it was judged against (i64)
+fn decode_scoring_rubric(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (total_score, passed, threshold): (i64, bool, i64) = journal.decode()
+        .map_err(|e| format!("Failed to decode rubric result from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(total_score).line(if total_score < 0 {
+        format!("⚠️  Rubric evaluation failed with error code {}", total_score)
+    } else {
+        format!("🔢 Rubric result: score = {} ({} at threshold {})", total_score, if passed { "PASS" } else { "FAIL" }, threshold)
+    }))
+}
+
+// Interval guest commits the match count (i64), matching indices (Vec<u32>) and the interval hash
+fn decode_interval_tree(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (match_count, matches, intervals_hash): (i64, Vec<u32>, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode interval result from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(match_count);
+    Ok(if match_count < 0 {
+        decoded.line(format!("⚠️  Interval lookup failed with error code {}", match_count))
+    } else {
+        decoded
+            .line(format!("🔢 Interval lookup result: {} match(es) at indices {:?}", match_count, matches))
+            .line(format!("🔒 Intervals hash: {}", intervals_hash))
+    })
+}
+
+// Escrow guest commits only the release amount (i64, negative on error)
+fn decode_escrow_release(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let release_amount: i64 = journal.decode()
+        .map_err(|e| format!("Failed to decode escrow result from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(release_amount).line(if release_amount < 0 {
+        format!("⚠️  Escrow evaluation failed with error code {}", release_amount)
+    } else {
+        format!("🔢 Escrow release amount: {}", release_amount)
+    }))
+}
+
+// Completeness guest commits 1 (complete), 0 (missing values) or a negative error code (i64) and the
+// required fields' `name_list_digest`, which verifiers must check against the fields they expect
+fn decode_data_completeness(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, required_fields_hash): (i64, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode completeness result from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(status);
+    Ok(match status {
+        1 | 0 => decoded
+            .line(if status == 1 {
+                "🔢 Data completeness: every record has all required fields".to_string()
+            } else {
+                "🔢 Data completeness: missing or null values found".to_string()
+            })
+            .line(format!("🔒 Required fields hash: {}", required_fields_hash)),
+        code => decoded.line(format!("⚠️  Completeness check failed with error code {}", code)),
+    })
+}
+
+// K-anonymity guest commits 1 (satisfied), 0 (violated) or a negative error code (i64), k (u64) and
+// the quasi-identifier columns' `name_list_digest`; verifiers must check both against the claim they expect
+fn decode_k_anonymity(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, k, quasi_identifiers_hash): (i64, u64, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode k-anonymity result from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(status);
+    Ok(match status {
+        1 | 0 => decoded
+            .line(if status == 1 {
+                format!("🔢 K-anonymity: every quasi-identifier combination appears at least {} times", k)
+            } else {
+                format!("🔢 K-anonymity: at least one combination appears fewer than {} times", k)
+            })
+            .line(format!("🔒 Quasi-identifier columns hash: {}", quasi_identifiers_hash)),
+        code => decoded.line(format!("⚠️  K-anonymity check failed with error code {}", code)),
+    })
+}
+
+// Amortization guest commits the monthly payment and total paid (i64 cents, negative payment on error)
+fn decode_loan_amortization(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (monthly_payment, total_paid): (i64, i64) = journal.decode()
+        .map_err(|e| format!("Failed to decode amortization result from journal: {}", e))?;
+
+    Ok(if monthly_payment < 0 {
+        DecodedJournal::status(monthly_payment)
+            .line(format!("⚠️  Amortization failed with error code {}", monthly_payment))
+    } else {
+        DecodedJournal::new(total_paid as f64 / 100.0, total_paid)
+            .line(format!("🔢 Amortization result: monthly payment = {:.2}, total paid = {:.2}", monthly_payment as f64 / 100.0, total_paid as f64 / 100.0))
+    })
+}
+
+// Shuffle guest commits the deck size (i64), seed hash, permutation hash and optional permutation
+fn decode_deck_shuffle(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (deck_size, seed_hash, permutation_hash, permutation): (i64, Digest, Digest, Vec<u32>) = journal.decode()
+        .map_err(|e| format!("Failed to decode shuffle result from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(deck_size);
+    if deck_size < 0 {
+        return Ok(decoded.line(format!("⚠️  Shuffle failed with error code {}", deck_size)));
+    }
+    let decoded = decoded
+        .line(format!("🔢 Shuffled {} cards", deck_size))
+        .line(format!("🔒 Seed hash: {}", seed_hash))
+        .line(format!("🔒 Permutation hash: {}", permutation_hash));
+    Ok(if permutation.is_empty() {
+        decoded
+    } else {
+        decoded.line(format!("🃏 Permutation: {:?}", permutation))
+    })
+}
+
+// Levenshtein guest commits only the edit distance (i64, negative on error)
+fn decode_levenshtein(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let distance: i64 = journal.decode()
+        .map_err(|e| format!("Failed to decode edit distance from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(distance).line(if distance < 0 {
+        format!("⚠️  Edit distance failed with error code {}", distance)
+    } else {
+        format!("🔢 Edit distance: {}", distance)
+    }))
+}
+
+// Convolution guest commits rows (i64, negative on error), cols (i64), fixed-point output (Vec<i64>) and its hash
+fn decode_convolution(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (rows, cols, output, output_hash): (i64, i64, Vec<i64>, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode convolution result from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(rows);
+    if rows < 0 {
+        return Ok(decoded.line(format!("⚠️  Convolution failed with error code {}", rows)));
+    }
+    let decoded = decoded.line(format!("🔢 Convolution output: {}x{} (hash {})", rows, cols, output_hash));
+    Ok(if output.is_empty() {
+        decoded
+    } else {
+        let decimals: Vec<f64> = output.iter().map(|v| *v as f64 / SCALE).collect();
+        decoded.line(format!("🔢 Output values (row-major): {:?}", decimals))
+    })
+}
+
+// VaR guest commits status (i64: 1 within limit, 0 breach, negative on error), limit (fixed-point i64), confidence (u64 bps)
+fn decode_value_at_risk(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, limit_fixed, confidence_bps): (i64, i64, u64) = journal.decode()
+        .map_err(|e| format!("Failed to decode VaR result from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(status).line(match status {
+        1 | 0 => format!("🔢 VaR at {}% confidence {} the limit of {}",
+                         confidence_bps as f64 / 100.0, if status == 1 { "is within" } else { "BREACHES" }, limit_fixed as f64 / SCALE),
+        code => format!("⚠️  VaR check failed with error code {}", code),
+    }))
+}
+
+// Equalization guest commits the level count (i64, negative on error), lookup table (Vec<u32>) and histogram hash
+fn decode_histogram_equalization(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (levels, mapping, histogram_hash): (i64, Vec<u32>, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode equalization result from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(levels);
+    Ok(if levels < 0 {
+        decoded.line(format!("⚠️  Histogram equalization failed with error code {}", levels))
+    } else {
+        decoded
+            .line(format!("🔢 Equalization mapping over {} levels: {:?}", levels, mapping))
+            .line(format!("🔒 Histogram hash: {}", histogram_hash))
+    })
+}
+
+// Ensemble guest commits the sample count (i64, negative on error) and final predictions (Vec<i64>)
+fn decode_ensemble_vote(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (sample_count, final_predictions): (i64, Vec<i64>) = journal.decode()
+        .map_err(|e| format!("Failed to decode ensemble result from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(sample_count).line(if sample_count < 0 {
+        format!("⚠️  Ensemble vote failed with error code {}", sample_count)
+    } else {
+        format!("🔢 Ensemble predictions for {} samples: {:?}", sample_count, final_predictions)
+    }))
+}
+
+// Recommendation guest commits the dimension (i64, negative on error) and the fixed-point score (i64)
+fn decode_recommendation_score(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, score_fixed): (i64, i64) = journal.decode()
+        .map_err(|e| format!("Failed to decode recommendation score from journal: {}", e))?;
+
+    Ok(if status < 0 {
+        DecodedJournal::status(status)
+            .line(format!("⚠️  Recommendation scoring failed with error code {}", status))
+    } else {
+        let score = score_fixed as f64 / SCALE;
+        DecodedJournal::new(score, score_fixed)
+            .line(format!("🔢 Recommendation score over {} features: {}", status, score))
+    })
+}
+
+// TWAP guest commits the observation count (i64, negative on error), the fixed-point TWAP (i64) and the series hash
+fn decode_twap(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, twap_fixed, series_hash): (i64, i64, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode TWAP from journal: {}", e))?;
+
+    Ok(if status < 0 {
+        DecodedJournal::status(status)
+            .line(format!("⚠️  TWAP computation failed with error code {}", status))
+    } else {
+        let twap = twap_fixed as f64 / SCALE;
+        DecodedJournal::new(twap, twap_fixed)
+            .line(format!("🔢 TWAP over {} observations: {}", status, twap))
+            .line(format!("🔒 Price series hash: {}", series_hash))
+    })
+}
+
+// Root guest commits 1 (root), 0 (not a root) or a negative error code, then the coefficients hash
+fn decode_polynomial_root(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, coeffs_hash): (i64, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode polynomial root result from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(status)
+        .line(match status {
+            1 => "🔢 Polynomial root: the private candidate solves the equation".to_string(),
+            0 => "🔢 Polynomial root: the private candidate is not a solution".to_string(),
+            code => format!("⚠️  Polynomial root check failed with error code {}", code),
+        })
+        .line(format!("🔒 Coefficients hash: {}", coeffs_hash)))
+}
+
+// Quadratic voting guest commits the voter count (i64, negative on error) and fixed-point voting power per option (Vec<i64>)
+fn decode_quadratic_voting(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (voters, totals): (i64, Vec<i64>) = journal.decode()
+        .map_err(|e| format!("Failed to decode quadratic voting tally from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(voters);
+    Ok(if voters < 0 {
+        decoded.line(format!("⚠️  Quadratic voting tally failed with error code {}", voters))
+    } else {
+        let powers: Vec<f64> = totals.iter().map(|v| *v as f64 / SCALE).collect();
+        decoded.line(format!("🔢 Voting power per option from {} voters: {:?}", voters, powers))
+    })
+}
+
+// Calculator guest commits the status (i64, 1 on success, negative on error), the op tag (String) and the fixed-point result (i64)
+fn decode_calculator(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, op, result_fixed): (i64, String, i64) = journal.decode()
+        .map_err(|e| format!("Failed to decode calculator result from journal: {}", e))?;
+
+    Ok(if status < 0 {
+        DecodedJournal::status(status)
+            .line(format!("⚠️  Calculator '{}' failed with error code {}", op, status))
+    } else {
+        let result = result_fixed as f64 / SCALE;
+        DecodedJournal::new(result, result_fixed)
+            .line(format!("🔢 Calculator {} = {}", op, result))
+    })
+}
+
+// Redaction guest commits 1 (correct), 0 (incorrect) or a negative error code, the redacted document hash and the removed content hash
+fn decode_redaction(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, redacted_hash, removed_hash): (i64, Digest, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode redaction result from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(status).line(match status {
+        1 => "🔢 Redaction: only the listed fields were removed or masked".to_string(),
+        0 => "🔢 Redaction: the redacted document does not match the original".to_string(),
+        code => format!("⚠️  Redaction check failed with error code {}", code),
+    });
+    Ok(if status < 0 {
+        decoded
+    } else {
+        decoded
+            .line(format!("🔒 Redacted document hash: {}", redacted_hash))
+            .line(format!("🔒 Removed content hash: {}", removed_hash))
+    })
+}
+
+// Permission bitmask guest commits 1 (all required bits present), 0 (missing) or a negative error code (i64), and the required mask (u64)
+fn decode_permission_bitmask(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, required): (i64, u64) = journal.decode()
+        .map_err(|e| format!("Failed to decode permission check from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(status).line(match status {
+        1 => format!("🔢 Permissions: all required bits {:#b} are granted", required),
+        0 => format!("🔢 Permissions: at least one required bit of {:#b} is missing", required),
+        code => format!("⚠️  Permission check failed with error code {}", code),
+    }))
+}
+
+// Random forest guest commits the tree count or a negative error code (i64), whether leaves were averaged (bool),
+// the fixed-point prediction (i64) and how many trees agree with it (u64)
+fn decode_random_forest(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, averaged, prediction_fixed, agreeing): (i64, bool, i64, u64) = journal.decode()
+        .map_err(|e| format!("Failed to decode random forest prediction from journal: {}", e))?;
+
+    Ok(if status < 0 {
+        DecodedJournal::status(status)
+            .line(format!("⚠️  Random forest inference failed with error code {}", status))
+    } else {
+        let prediction = prediction_fixed as f64 / SCALE;
+        let decoded = DecodedJournal::new(prediction, prediction_fixed);
+        if averaged {
+            decoded.line(format!("🔢 Random forest average prediction over {} trees: {}", status, prediction))
+        } else {
+            decoded.line(format!("🔢 Random forest majority prediction: {} ({} of {} trees agree)", prediction, agreeing, status))
+        }
+    })
+}
+
+// Inventory reorder guest commits 1 (reorder), 0 (no reorder) or a negative error code (i64) and the reorder quantity (u64)
+fn decode_inventory_reorder(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, quantity): (i64, u64) = journal.decode()
+        .map_err(|e| format!("Failed to decode reorder decision from journal: {}", e))?;
+
+    Ok(match status {
+        1 => DecodedJournal::new(quantity as f64, quantity as i64)
+            .line(format!("🔢 Reorder {} units", quantity)),
+        0 => DecodedJournal::new(0.0, 0).line("🔢 Stock is above the reorder point; no order needed".to_string()),
+        code => DecodedJournal::status(code).line(format!("⚠️  Reorder decision failed with error code {}", code)),
+    })
+}
+
+// Calibration guest commits 1 (within tolerance), 0 (outside) or a negative error code (i64),
+// the fixed-point reference (i64) and the tolerance in basis points (u64)
+fn decode_calibration_tolerance(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, reference_fixed, tolerance_bps): (i64, i64, u64) = journal.decode()
+        .map_err(|e| format!("Failed to decode calibration check from journal: {}", e))?;
+
+    let reference = reference_fixed as f64 / SCALE;
+    Ok(DecodedJournal::status(status).line(match status {
+        1 => format!("🔢 Calibration: measurement within {} bps of reference {}", tolerance_bps, reference),
+        0 => format!("🔢 Calibration: measurement outside {} bps of reference {}", tolerance_bps, reference),
+        code => format!("⚠️  Calibration check failed with error code {}", code),
+    }))
+}
+
+// State transition guest commits the number of updates applied or a negative error code (i64),
+// the prior state hash and the new state hash (Digest)
+fn decode_state_transition(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, prev_state, new_state): (i64, Digest, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode state transition from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(status);
+    Ok(if status < 0 {
+        decoded.line(format!("⚠️  State transition from {} failed with error code {}", prev_state, status))
+    } else {
+        decoded
+            .line(format!("🔢 Applied {} updates", status))
+            .line(format!("🔒 Previous state: {}", prev_state))
+            .line(format!("🔒 New state: {}", new_state))
+    })
+}
+
+// A/B test guest commits 1 (significant), 0 (not significant) or a negative error code (i64) and the confidence level in percent (u64)
+fn decode_ab_test(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, confidence): (i64, u64) = journal.decode()
+        .map_err(|e| format!("Failed to decode A/B test result from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(status).line(match status {
+        1 => format!("🔢 A/B test: difference is significant at {}% confidence", confidence),
+        0 => format!("🔢 A/B test: difference is not significant at {}% confidence", confidence),
+        code => format!("⚠️  A/B test failed with error code {}", code),
+    }))
+}
+
+// Debt-to-income guest commits 1 (passes), 0 (fails) or a negative error code (i64) and the limit in basis points (u64)
+fn decode_debt_to_income(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, max_ratio_bps): (i64, u64) = journal.decode()
+        .map_err(|e| format!("Failed to decode debt-to-income check from journal: {}", e))?;
+
+    let limit = max_ratio_bps as f64 / 100.0;
+    Ok(DecodedJournal::status(status).line(match status {
+        1 => format!("🔢 Debt-to-income ratio is within {}%", limit),
+        0 => format!("🔢 Debt-to-income ratio exceeds {}%", limit),
+        -5 => "⚠️  Debt-to-income check failed: income is zero".to_string(),
+        code => format!("⚠️  Debt-to-income check failed with error code {}", code),
+    }))
+}
+
+// Survey guest commits the response count or a negative error code (i64), the fixed-point mean (i64)
+// and the count per Likert point 1–5 (Vec<u64>)
+fn decode_survey_aggregate(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, mean_fixed, distribution): (i64, i64, Vec<u64>) = journal.decode()
+        .map_err(|e| format!("Failed to decode survey aggregate from journal: {}", e))?;
+
+    Ok(if status < 0 {
+        DecodedJournal::status(status)
+            .line(format!("⚠️  Survey aggregation failed with error code {}", status))
+    } else {
+        let mean = mean_fixed as f64 / SCALE;
+        DecodedJournal::new(mean, mean_fixed)
+            .line(format!("🔢 Mean response over {} respondents: {}", status, mean))
+            .line(format!("📊 Responses per point 1–5: {:?}", distribution))
+    })
+}
+
+// MST guest commits the number of tree edges or a negative error code (i64), the fixed-point total weight (i64)
+// and the edge list hash (Digest)
+fn decode_minimum_spanning_tree(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, weight_fixed, edges_hash): (i64, i64, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode MST weight from journal: {}", e))?;
+
+    Ok(if status < 0 {
+        DecodedJournal::status(status)
+            .line(format!("⚠️  MST computation failed with error code {}", status))
+    } else {
+        let weight = weight_fixed as f64 / SCALE;
+        DecodedJournal::new(weight, weight_fixed)
+            .line(format!("🔢 MST weight over {} edges: {}", status, weight))
+            .line(format!("🔒 Edge list hash: {}", edges_hash))
+    })
+}
+
+// Dividend yield guest commits 1 (exceeds target), 0 (does not) or a negative error code (i64) and the target in basis points (u64)
+fn decode_dividend_yield(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, target_yield_bps): (i64, u64) = journal.decode()
+        .map_err(|e| format!("Failed to decode dividend yield screen from journal: {}", e))?;
+
+    let target = target_yield_bps as f64 / 100.0;
+    Ok(DecodedJournal::status(status).line(match status {
+        1 => format!("🔢 Dividend yield exceeds the {}% target", target),
+        0 => format!("🔢 Dividend yield does not exceed the {}% target", target),
+        -5 => "⚠️  Dividend yield screen failed: price is zero".to_string(),
+        code => format!("⚠️  Dividend yield screen failed with error code {}", code),
+    }))
+}
+
+// Shipping cost guest commits the status (i64, 1 on success, negative on error), the fixed-point cost (i64)
+// and the rate table hash (Digest)
+fn decode_shipping_cost(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, cost_fixed, table_hash): (i64, i64, Digest) = journal.decode()
+        .map_err(|e| format!("Failed to decode shipping cost from journal: {}", e))?;
+
+    let decoded = if status < 0 {
+        DecodedJournal::status(status).line(match status {
+            -5 => "⚠️  Shipping cost failed: weight exceeds the zone's largest bracket".to_string(),
+            -6 => "⚠️  Shipping cost failed: zone not in the rate table".to_string(),
+            code => format!("⚠️  Shipping cost failed with error code {}", code),
+        })
+    } else {
+        let cost = cost_fixed as f64 / SCALE;
+        DecodedJournal::new(cost, cost_fixed).line(format!("🔢 Shipping cost: {}", cost))
+    };
+    Ok(decoded.line(format!("🔒 Rate table hash: {}", table_hash)))
+}
+
+// Eligibility scorecard guest commits pass (1), fail (0) or a negative error code (i64) and the fixed-point threshold (i64)
+fn decode_eligibility_scorecard(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, threshold_fixed): (i64, i64) = journal.decode()
+        .map_err(|e| format!("Failed to decode eligibility scorecard from journal: {}", e))?;
+
+    let threshold = threshold_fixed as f64 / SCALE;
+    Ok(DecodedJournal::status(status).line(match status {
+        1 => format!("🔢 Applicant meets the scorecard threshold of {}", threshold),
+        0 => format!("🔢 Applicant falls below the scorecard threshold of {}", threshold),
+        -5 => "⚠️  Eligibility scorecard failed: a factor's max does not exceed its min".to_string(),
+        code => format!("⚠️  Eligibility scorecard failed with error code {}", code),
+    }))
+}
+
+// Geometric mean guest commits the value count or a negative error code (i64) and the fixed-point mean (i64)
+fn decode_geometric_mean(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, mean_fixed): (i64, i64) = journal.decode()
+        .map_err(|e| format!("Failed to decode geometric mean from journal: {}", e))?;
+
+    Ok(match status {
+        -5 => DecodedJournal::status(status)
+            .line("⚠️  Geometric mean failed: values must be strictly positive".to_string()),
+        code if code < 0 => DecodedJournal::status(status)
+            .line(format!("⚠️  Geometric mean failed with error code {}", code)),
+        count => {
+            let mean = mean_fixed as f64 / SCALE;
+            DecodedJournal::new(mean, mean_fixed)
+                .line(format!("🔢 Geometric mean of {} values: {}", count, mean))
+        }
+    })
+}
+
+// Scheduling guest commits the task count or a negative error code (i64) and the task ids in priority order (Vec<u64>)
+fn decode_scheduling_priority(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, order): (i64, Vec<u64>) = journal.decode()
+        .map_err(|e| format!("Failed to decode scheduling priority from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(status).line(match status {
+        -5 => "⚠️  Scheduling failed: task ids must be unique".to_string(),
+        code if code < 0 => format!("⚠️  Scheduling failed with error code {}", code),
+        count => format!("🔢 Priority order of {} tasks: {:?}", count, order),
+    }))
+}
+
+// Record dedup guest commits duplicate (1), distinct (0) or a negative error code (i64) and the fixed-point threshold (i64)
+fn decode_record_dedup(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, threshold_fixed): (i64, i64) = journal.decode()
+        .map_err(|e| format!("Failed to decode record dedup check from journal: {}", e))?;
+
+    let threshold = threshold_fixed as f64 / SCALE;
+    Ok(DecodedJournal::status(status).line(match status {
+        1 => format!("🔢 Records are duplicates (similarity ≥ {})", threshold),
+        0 => format!("🔢 Records are distinct (similarity < {})", threshold),
+        -5 => "⚠️  Dedup check failed: feature vectors differ in length".to_string(),
+        -6 => "⚠️  Dedup check failed: a feature vector is all zeros".to_string(),
+        code => format!("⚠️  Dedup check failed with error code {}", code),
+    }))
+}
+
+// Energy billing guest commits the status (i64, 1 on success, negative on error) and the fixed-point bill total (i64)
+fn decode_energy_billing(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, total_fixed): (i64, i64) = journal.decode()
+        .map_err(|e| format!("Failed to decode energy bill from journal: {}", e))?;
+
+    Ok(match status {
+        -5 => DecodedJournal::status(status)
+            .line("⚠️  Energy billing failed: tier thresholds must strictly increase".to_string()),
+        -6 => DecodedJournal::status(status)
+            .line("⚠️  Energy billing failed: consumption exceeds the last tier".to_string()),
+        code if code < 0 => DecodedJournal::status(status)
+            .line(format!("⚠️  Energy billing failed with error code {}", code)),
+        _ => {
+            let total = total_fixed as f64 / SCALE;
+            DecodedJournal::new(total, total_fixed).line(format!("🔢 Tiered bill total: {}", total))
+        }
+    })
+}
+
+// Fraud-risk guest commits the tier (i64: 0 low, 1 medium, 2 high, negative on error) and the medium/high thresholds (u64 bps)
+fn decode_fraud_risk(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, medium_bps, high_bps): (i64, u64, u64) = journal.decode()
+        .map_err(|e| format!("Failed to decode fraud-risk tier from journal: {}", e))?;
+
+    let thresholds = format!("medium ≥ {}%, high ≥ {}%", medium_bps as f64 / 100.0, high_bps as f64 / 100.0);
+    Ok(DecodedJournal::status(status).line(match status {
+        0 => format!("🔢 Transaction is LOW risk ({})", thresholds),
+        1 => format!("🔢 Transaction is MEDIUM risk ({})", thresholds),
+        2 => format!("🔢 Transaction is HIGH risk ({})", thresholds),
+        -5 => "⚠️  Fraud-risk gate failed: thresholds must satisfy medium ≤ high ≤ 100%".to_string(),
+        code => format!("⚠️  Fraud-risk gate failed with error code {}", code),
+    }))
+}
+
+// Pedersen commitment guest commits the status (i64, 1 on success, negative on error), the commitment (u64)
+// and, when a range was requested, (min, max, in_range) as Option<(u64, u64, bool)>
+fn decode_pedersen_commitment(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, commitment, range): (i64, u64, Option<(u64, u64, bool)>) = journal.decode()
+        .map_err(|e| format!("Failed to decode Pedersen commitment from journal: {}", e))?;
+
+    if status < 0 {
+        return Ok(DecodedJournal::status(status).line(match status {
+            -5 => "⚠️  Commitment failed: range min exceeds max".to_string(),
+            code => format!("⚠️  Commitment failed with error code {}", code),
+        }));
+    }
+    let decoded = DecodedJournal::new(commitment as f64, commitment as i64)
+        .line(format!("🔒 Commitment: {:#018x}", commitment));
+    Ok(match range {
+        Some((min, max, in_range)) => decoded.line(format!("🔢 Committed value in [{}, {}]: {}", min, max, in_range)),
+        None => decoded,
+    })
+}
+
+// Signature guest commits 1 (valid), 0 (invalid) or a negative error code, the message hash and the public key
+fn decode_verify_signature(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (status, message_hash, public_key): (i64, Digest, [u8; 32]) = journal.decode()
+        .map_err(|e| format!("Failed to decode signature result from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(status);
+    Ok(match status {
+        1 | 0 => decoded
+            .line(format!("🔢 Ed25519 signature: {}", if status == 1 { "VALID" } else { "INVALID" }))
+            .line(format!("🔒 Message hash: {}", message_hash))
+            .line(format!("🔑 Public key: {}", public_key.iter().map(|b| format!("{:02x}", b)).collect::<String>())),
+        -3 => decoded.line("⚠️  Signature check failed: public key is not 32 bytes of hex".to_string()),
+        -4 => decoded.line("⚠️  Signature check failed: signature is not 64 bytes of hex".to_string()),
+        code => decoded.line(format!("⚠️  Signature check failed with error code {}", code)),
+    })
+}
+
+// Decision tree guest commits only the predicted class (i64, negative on error)
+fn decode_decision_tree(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let class: i64 = journal.decode()
+        .map_err(|e| format!("Failed to decode decision tree prediction from journal: {}", e))?;
+
+    Ok(DecodedJournal::status(class).line(match class {
+        -5 => "⚠️  Decision tree failed: child index out of range or cycle".to_string(),
+        -6 => "⚠️  Decision tree failed: feature vector shorter than the tree needs".to_string(),
+        code if code < 0 => format!("⚠️  Decision tree failed with error code {}", code),
+        class => format!("🔢 Decision tree predicted class: {}", class),
+    }))
+}
+
+// Matrix guest commits rows (i64, negative on error), cols (i64) and the row-major fixed-point product (Vec<i64>)
+fn decode_matrix_multiply(journal: &Journal) -> Result<DecodedJournal, OperationError> {
+    let (rows, cols, product): (i64, i64, Vec<i64>) = journal.decode()
+        .map_err(|e| format!("Failed to decode matrix product from journal: {}", e))?;
+
+    let decoded = DecodedJournal::status(rows);
+    if rows < 0 {
+        return Ok(decoded.line(match rows {
+            -1 => "⚠️  Matrix multiplication failed: A.cols != B.rows".to_string(),
+            -5 => "⚠️  Matrix multiplication failed: a product entry overflows".to_string(),
+            code => format!("⚠️  Matrix multiplication failed with error code {}", code),
+        }));
+    }
+    if cols <= 0 {
+        return Err(format!("Matrix product has {} columns, expected at least one", cols).into());
+    }
+    if rows.checked_mul(cols) != Some(product.len() as i64) {
+        return Err(format!("Matrix product has {} entries, expected {}x{}", product.len(), rows, cols).into());
+    }
+    let reshaped: Vec<Vec<f64>> = product
+        .chunks(cols as usize)
+        .map(|row| row.iter().map(|v| *v as f64 / SCALE).collect())
+        .collect();
+    Ok(decoded
+        .line(format!("🔢 Matrix product: {}x{}", rows, cols))
+        .line(format!("🔢 Product rows: {:?}", reshaped)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_names_are_unique_and_detectable() {
+        let registry = Registry::builtin();
+        for op in registry.iter() {
+            let filename = format!("proof_{}_1700000000.bin", op.name());
+            assert_eq!(registry.detect_from_filename(&filename).map(|found| found.name()), Some(op.name()));
+        }
+        assert_eq!(registry.detect_from_filename("proof_authenticated_add_1700000000.bin").map(|op| op.name()), Some("authenticated_add"));
+        assert!(registry.detect_from_filename("proof_dynamic_1700000000.bin").is_none());
+        
+        // Built once and shared
+        assert!(std::ptr::eq(registry, Registry::builtin()));
+    }
+
+    #[test]
+    fn test_json_guests_echo_and_check_their_inputs() {
+        let op = Registry::builtin().get("twap").unwrap();
+        assert_eq!(op.echo_inputs(&["{\"prices\": [1]}".to_string()]), serde_json::json!({"prices": [1]}));
+        assert_eq!(op.describe_inputs(&["{}".to_string()]), "Time-weighted average price with inputs: {}");
+        
+        let mut reads = Vec::new();
+        assert!(op.write_env(&mut risc0_zkvm::ExecutorEnv::builder(), &["not json".to_string()], &mut reads).is_err());
+        assert!(op.write_env(&mut risc0_zkvm::ExecutorEnv::builder(), &[], &mut reads).is_err());
+        op.write_env(&mut risc0_zkvm::ExecutorEnv::builder(), &["{}".to_string()], &mut reads).unwrap();
+        assert_eq!(reads, vec!["inputs_json = \"{}\""]);
+    }
+
+    #[test]
+    fn test_decode_status_journal() {
+        let fields_hash = crate::journal::name_list_digest(&["id", "email"]);
+        let journal = Journal::new(risc0_zkvm::serde::to_vec(&(1i64, fields_hash)).unwrap().iter().flat_map(|w| w.to_le_bytes()).collect());
+        let decoded = Registry::builtin().get("data_completeness").unwrap().decode_journal(&journal).unwrap();
+        assert_eq!((decoded.value, decoded.fixed), (1.0, 1));
+        assert_eq!(decoded.lines, vec![
+            "🔢 Data completeness: every record has all required fields".to_string(),
+            format!("🔒 Required fields hash: {}", fields_hash),
+        ]);
+    }
+
+    #[test]
+    fn test_decode_k_anonymity_reports_k_and_columns() {
+        let columns_hash = crate::journal::name_list_digest(&["zip", "age_band"]);
+        let words = risc0_zkvm::serde::to_vec(&(1i64, 3u64, columns_hash)).unwrap();
+        let journal = Journal::new(words.iter().flat_map(|w| w.to_le_bytes()).collect());
+        let decoded = Registry::builtin().get("k_anonymity").unwrap().decode_journal(&journal).unwrap();
+        assert_eq!(decoded.value, 1.0);
+        assert_eq!(decoded.lines, vec![
+            "🔢 K-anonymity: every quasi-identifier combination appears at least 3 times".to_string(),
+            format!("🔒 Quasi-identifier columns hash: {}", columns_hash),
+        ]);
+    }
+
+    #[test]
+    fn test_decode_scoring_rubric_reports_threshold() {
+        let words = risc0_zkvm::serde::to_vec(&(7i64, true, 5i64)).unwrap();
+        let journal = Journal::new(words.iter().flat_map(|w| w.to_le_bytes()).collect());
+        let decoded = Registry::builtin().get("scoring_rubric").unwrap().decode_journal(&journal).unwrap();
+        assert_eq!(decoded.value, 7.0);
+        assert_eq!(decoded.lines, vec!["🔢 Rubric result: score = 7 (PASS at threshold 5)"]);
+    }
+
+    #[test]
+    fn test_decode_matrix_product_reshapes_rows() {
+        let words = risc0_zkvm::serde::to_vec(&(2i64, 2i64, vec![5800000i64, 6400000, 13900000, 15400000])).unwrap();
+        let journal = Journal::new(words.iter().flat_map(|w| w.to_le_bytes()).collect());
+        let decoded = Registry::builtin().get("matrix_multiply").unwrap().decode_journal(&journal).unwrap();
+        assert_eq!(decoded.value, 2.0);
+        assert_eq!(decoded.lines[1], "🔢 Product rows: [[58.0, 64.0], [139.0, 154.0]]");
+
+        // Malformed shapes are rejected instead of panicking in `chunks` or overflowing `rows * cols`
+        let matrix = Registry::builtin().get("matrix_multiply").unwrap();
+        for shape in [(1i64, 0i64, vec![]), (2, -1, vec![]), (i64::MAX, 2, vec![0i64])] {
+            let words = risc0_zkvm::serde::to_vec(&shape).unwrap();
+            assert!(matrix.decode_journal(&Journal::new(words.iter().flat_map(|w| w.to_le_bytes()).collect())).is_err());
+        }
+    }
+}
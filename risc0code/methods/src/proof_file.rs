@@ -0,0 +1,159 @@
+// Saved proof file formats, shared so the host that writes them and the verifier that reads them
+// cannot drift apart. Each format starts with an 8-byte magic followed by its bincode encoding.
+use risc0_zkvm::sha::Digest;
+use risc0_zkvm::{InnerReceipt, Receipt};
+use serde::{Deserialize, Serialize};
+
+use crate::operation::OperationError;
+
+// Magic prefix identifying a minimal proof envelope
+pub const MINIMAL_ENVELOPE_MAGIC: &[u8; 8] = b"R0MINENV";
+
+// Minimal proof envelope written by `host --envelope minimal`: only what is needed to verify
+// (seal-bearing inner receipt, journal, and the image ID the host proved with)
+#[derive(Serialize, Deserialize)]
+pub struct MinimalEnvelope {
+    pub image_id: [u32; 8],
+    pub journal: Vec<u8>,
+    pub inner: InnerReceipt,
+}
+
+impl MinimalEnvelope {
+    // Composite receipts carry per-segment seals and cannot be minimized
+    pub fn new(receipt: &Receipt, image_id: [u32; 8]) -> Result<Self, OperationError> {
+        if let InnerReceipt::Composite(_) = receipt.inner {
+            return Err("Composite receipts cannot be stored in a minimal envelope; prove a succinct receipt instead".into());
+        }
+        Ok(MinimalEnvelope { image_id, journal: receipt.journal.bytes.clone(), inner: receipt.inner.clone() })
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, OperationError> {
+        let mut bytes = MINIMAL_ENVELOPE_MAGIC.to_vec();
+        bytes.extend(bincode::serialize(self)?);
+        Ok(bytes)
+    }
+}
+
+// Raw seal of a succinct receipt as little-endian words, for verifiers that take the seal alone.
+// Composite and dev-mode (fake) receipts have no single seal.
+pub fn succinct_seal_bytes(receipt: &Receipt) -> Result<Vec<u8>, OperationError> {
+    let succinct = receipt.inner.succinct()?;
+    Ok(succinct.seal.iter().flat_map(|word| word.to_le_bytes()).collect())
+}
+
+// A saved proof decoded from either a full receipt or a minimal envelope
+pub struct SavedProof {
+    pub receipt: Receipt,
+    // The image ID a minimal envelope recorded; None for a full receipt
+    pub envelope_image_id: Option<[u32; 8]>,
+}
+
+impl SavedProof {
+    pub fn decode(bytes: &[u8]) -> Result<Self, OperationError> {
+        if let Some(envelope_bytes) = bytes.strip_prefix(MINIMAL_ENVELOPE_MAGIC.as_slice()) {
+            let envelope: MinimalEnvelope = bincode::deserialize(envelope_bytes)?;
+            return Ok(SavedProof { receipt: Receipt::new(envelope.inner, envelope.journal), envelope_image_id: Some(envelope.image_id) });
+        }
+        Ok(SavedProof { receipt: bincode::deserialize(bytes)?, envelope_image_id: None })
+    }
+
+    // An envelope names the guest it was written for; reject one that disagrees with the image ID
+    // the receipt is verified against, so a relabelled envelope cannot pass for another guest's proof
+    pub fn check_image_id(&self, image_id: impl Into<Digest>) -> Result<(), OperationError> {
+        let image_id = image_id.into();
+        match self.envelope_image_id.map(Digest::from) {
+            Some(recorded) if recorded != image_id => Err(format!(
+                "minimal envelope records image ID {} but the proof is verified against {}", recorded, image_id
+            ).into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+// Sidecar saved next to dynamic/precompiled proofs as `<proof>.meta.json`, recording the image ID
+// the verifier cannot derive from a built-in guest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProofMeta {
+    pub image_id: String,
+    pub operation: String,
+    pub elf_len: usize,
+}
+
+// Magic prefix identifying a proof bundle
+pub const BUNDLE_MAGIC: &[u8; 8] = b"R0BUNDLE";
+
+// Manifest entry describing one bundled proof; the digest covers the stored proof bytes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleEntry {
+    pub filename: String,
+    pub operation: String,
+    pub image_id: Digest,
+    pub digest: Digest,
+}
+
+// Several proof files packed together with a manifest, in the order they were given
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProofBundle {
+    pub manifest: Vec<BundleEntry>,
+    pub proofs: Vec<Vec<u8>>,
+}
+
+impl ProofBundle {
+    pub fn encode(&self) -> Result<Vec<u8>, OperationError> {
+        let mut bytes = BUNDLE_MAGIC.to_vec();
+        bytes.extend(bincode::serialize(self)?);
+        Ok(bytes)
+    }
+
+    // Decode a bundle file, rejecting one whose manifest and proofs do not pair up
+    pub fn decode(bytes: &[u8]) -> Result<Self, OperationError> {
+        let bundle_bytes = bytes.strip_prefix(BUNDLE_MAGIC.as_slice()).ok_or("File is not a proof bundle")?;
+        let bundle: ProofBundle = bincode::deserialize(bundle_bytes)?;
+        if bundle.manifest.len() != bundle.proofs.len() {
+            return Err("Bundle manifest and proof count disagree".into());
+        }
+        Ok(bundle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let entry = BundleEntry {
+            filename: "proof_add_1700000000.bin".to_string(),
+            operation: "add".to_string(),
+            image_id: Digest::from([1u32; 8]),
+            digest: Digest::from([2u32; 8]),
+        };
+        let bundle = ProofBundle { manifest: vec![entry], proofs: vec![vec![1, 2, 3]] };
+        assert_eq!(ProofBundle::decode(&bundle.encode().unwrap()).unwrap(), bundle);
+
+        let unpaired = ProofBundle { manifest: bundle.manifest.clone(), proofs: Vec::new() };
+        assert!(ProofBundle::decode(&unpaired.encode().unwrap()).is_err());
+        assert!(ProofBundle::decode(b"not a bundle").is_err());
+    }
+
+    #[test]
+    fn test_minimal_envelope_records_its_image_id() {
+        use risc0_zkvm::{FakeReceipt, ReceiptClaim};
+
+        let journal = vec![7u8, 0, 0, 0];
+        let claim = ReceiptClaim::ok([1u32; 8], journal.clone());
+        let receipt = Receipt::new(InnerReceipt::Fake(FakeReceipt::new(claim)), journal.clone());
+
+        let bytes = MinimalEnvelope::new(&receipt, [1u32; 8]).unwrap().encode().unwrap();
+        assert!(bytes.starts_with(MINIMAL_ENVELOPE_MAGIC));
+        let proof = SavedProof::decode(&bytes).unwrap();
+        assert_eq!(proof.receipt.journal.bytes, journal);
+        assert_eq!(proof.envelope_image_id, Some([1u32; 8]));
+        assert!(proof.check_image_id([1u32; 8]).is_ok());
+        assert!(proof.check_image_id([2u32; 8]).is_err());
+
+        // Full receipts carry no separate image ID to check
+        let proof = SavedProof::decode(&bincode::serialize(&receipt).unwrap()).unwrap();
+        assert!(proof.check_image_id([2u32; 8]).is_ok());
+    }
+}
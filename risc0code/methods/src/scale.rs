@@ -0,0 +1,21 @@
+// Fixed-point scale agreement between the host and the guests.
+// The host encodes decimals as value * SCALE before writing them to a guest, and decodes committed
+// values by dividing by SCALE again; a guest compiled with a different scale would still prove and
+// verify, it would just compute the wrong number. Guests therefore import the constant from the
+// `fixed-point` crate rather than declaring their own, and the host re-exports the same one here.
+// The ML guests' coarser result scales (`REGRESSION_SCALE`, `PROBABILITY_SCALE`) live there too, and
+// `journal::MlJournal` decodes with them.
+
+// The fixed-point scale shared by the host, the verifier's decoders and every decimal guest
+// (5 decimal places)
+pub use fixed_point::SCALE;
+
+// Convert decimal number to fixed-point representation
+pub fn decimal_to_fixed_point(decimal: f64) -> i64 {
+    (decimal * SCALE as f64).round() as i64
+}
+
+// Convert fixed-point representation back to decimal
+pub fn fixed_point_to_decimal(fixed: i64) -> f64 {
+    fixed as f64 / SCALE as f64
+}
@@ -0,0 +1,424 @@
+// Verification and journal decoding for saved RISC Zero proofs.
+// The `verify` binary is a thin CLI over these functions; every check takes a `VerifierContext`,
+// so callers choose dev mode explicitly instead of through RISC0_DEV_MODE.
+use methods::journal::{decode_dynamic_result, JournalCodec};
+use methods::operation::{Registry, ResultType};
+use methods::proof_file::{self, BundleEntry, ProofBundle, SavedProof};
+use risc0_zkvm::{InnerReceipt, Journal, Receipt, VerifierContext};
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::time::{Duration, Instant};
+
+// Parse a hex image ID (the little-endian bytes of its eight words, as printed by the host)
+pub fn parse_image_id(hex_id: &str) -> Result<[u32; 8], String> {
+    let bytes = hex::decode(hex_id.trim_start_matches("0x")).map_err(|e| format!("invalid hex: {}", e))?;
+    let bytes: [u8; 32] = bytes.try_into().map_err(|b: Vec<u8>| format!("expected 32 bytes, got {}", b.len()))?;
+    let mut words = [0u32; 8];
+    for (word, chunk) in words.iter_mut().zip(bytes.as_chunks::<4>().0) {
+        *word = u32::from_le_bytes(*chunk);
+    }
+    Ok(words)
+}
+
+// The operation a saved proof was generated for, from its filename (e.g. proof_multiply_1700000000.bin)
+pub fn detect_operation(registry: &Registry, filename: &str) -> String {
+    if let Some(op) = registry.detect_from_filename(filename) {
+        op.name().to_string()
+    } else if filename.contains("precompiled") {
+        "precompiled".to_string()
+    } else if filename.contains("dynamic") {
+        "dynamic".to_string()
+    } else {
+        "add".to_string() // default
+    }
+}
+
+// Built-in guests all commit with `env::commit`; only dynamic guests may use another journal codec
+pub fn journal_codec(registry: &Registry, operation: &str, flag: Option<JournalCodec>) -> Result<JournalCodec, String> {
+    match (operation, flag) {
+        ("precompiled" | "dynamic", flag) => Ok(flag.unwrap_or_default()),
+        (_, flag) => {
+            let builtin = registry.get(operation).map_or(JournalCodec::Risc0Serde, |op| op.journal_codec());
+            if flag.is_some_and(|codec| codec != builtin) {
+                return Err(format!("{} journals are always {}; --journal-codec only applies to precompiled proofs", operation, builtin));
+            }
+            Ok(builtin)
+        }
+    }
+}
+
+// A proof's committed result, as the verifier reports and compares it
+pub struct DecodedResult {
+    // Human-readable description of the journal, one line each
+    pub lines: Vec<String>,
+    // The value --expected compares against; wide enough for both the i64 and the u64 results
+    pub value: i128,
+    // Set by operations whose result is a decimal, for --expected-decimal
+    pub decimal: Option<f64>,
+    // No committed value to compare: an empty journal, a batch, or an error the guest reported instead
+    pub no_result: bool,
+}
+
+impl DecodedResult {
+    // The result the --json report carries: a decimal, or the integer exactly as committed
+    pub fn json_value(&self) -> Option<serde_json::Value> {
+        if self.no_result {
+            return None;
+        }
+        Some(match self.decimal {
+            Some(decimal) => decimal.into(),
+            None => i64::try_from(self.value).map(Into::into)
+                .or_else(|_| u64::try_from(self.value).map(Into::into))
+                .unwrap_or_else(|_| (self.value as f64).into()),
+        })
+    }
+}
+
+// Decode the result of a proof of `operation` from its journal
+pub fn decode_result(registry: &Registry, operation: &str, codec: JournalCodec, journal: &Journal) -> Result<DecodedResult, Box<dyn std::error::Error>> {
+    // Side-effect-only guests may halt without committing anything; the proof is still verifiable
+    if journal.bytes.is_empty() {
+        return Ok(DecodedResult { lines: vec!["➡️  No output committed (empty journal)".to_string()], value: 0, decimal: None, no_result: true });
+    }
+    
+    match operation {
+        "precompiled" | "dynamic" => {
+            // Dynamic guests commit a tagged result envelope, or a leading i64 in the codec chosen with
+            // --journal-codec; either way the result is decoded to JSON
+            let value = decode_dynamic_result(codec, &journal.bytes)
+                .map_err(|e| format!("Invalid {} journal for {} operation: {}", codec, operation, e))?;
+            let lines = vec![format!("➡️  Computation result: {}", value)];
+            let integer = value.as_i64().map(i128::from).or(value.as_u64().map(i128::from));
+            Ok(match (integer, value.as_f64()) {
+                (Some(integer), _) => DecodedResult { lines, value: integer, decimal: None, no_result: false },
+                (None, Some(number)) => DecodedResult { lines, value: number as i128, decimal: Some(number), no_result: false },
+                // A structured result has no single value to compare with --expected
+                (None, None) => DecodedResult { lines, value: 0, decimal: None, no_result: true },
+            })
+        },
+        _ => {
+            let op = registry.get(operation).ok_or("Unknown operation")?;
+            let decoded = op.decode_journal(journal)?;
+            // Batches, guest-reported errors and overflows carry no single value to compare
+            let no_result = decoded.note.is_some();
+            Ok(DecodedResult {
+                value: decoded.integer.map_or(decoded.value as i128, i128::from),
+                decimal: (op.result_type() == ResultType::Decimal && !no_result).then_some(decoded.value),
+                lines: decoded.lines,
+                no_result,
+            })
+        }
+    }
+}
+
+// Verification latency statistics across repeated runs
+pub struct VerifyBenchmark {
+    pub iterations: usize,
+    pub min: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+// Verify the same (already deserialized) receipt `iterations` times
+pub fn benchmark_verification(receipt: &Receipt, ctx: &VerifierContext, image_id: [u32; 8], iterations: usize) -> Result<VerifyBenchmark, Box<dyn std::error::Error>> {
+    if iterations == 0 {
+        return Err("Benchmark requires at least one iteration".into());
+    }
+    
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        receipt.verify_with_context(ctx, image_id)?;
+        samples.push(start.elapsed());
+    }
+    samples.sort();
+    
+    let total: Duration = samples.iter().sum();
+    // Nearest-rank 95th percentile
+    let p95_index = (iterations * 95).div_ceil(100).max(1) - 1;
+    Ok(VerifyBenchmark {
+        iterations,
+        min: samples[0],
+        mean: total / iterations as u32,
+        p95: samples[p95_index],
+    })
+}
+
+// Extract the raw seal bytes from receipts that can be checked by an on-chain verifier
+pub fn extract_seal(receipt: &Receipt) -> Result<(Vec<u8>, &'static str), Box<dyn std::error::Error>> {
+    match &receipt.inner {
+        InnerReceipt::Groth16(groth16) => Ok((groth16.seal.clone(), "groth16")),
+        // Succinct seals are u32 words, exported the same way `host --seal-only` writes them
+        InnerReceipt::Succinct(_) => Ok((proof_file::succinct_seal_bytes(receipt)?, "succinct")),
+        _ => Err("Receipt is not in an on-chain compatible form (expected a succinct or Groth16 receipt)".into()),
+    }
+}
+
+// Operation name -> hex image IDs of earlier guest builds whose proofs should still verify
+pub type Allowlist = BTreeMap<String, BTreeSet<String>>;
+
+// A missing allowlist file is an empty allowlist
+pub fn load_allowlist(path: &str) -> Result<Allowlist, Box<dyn std::error::Error>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(serde_json::from_str(&contents).map_err(|e| format!("Invalid allowlist {}: {}", path, e))?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Allowlist::new()),
+        Err(e) => Err(format!("Failed to read allowlist {}: {}", path, e).into()),
+    }
+}
+
+// Record the current image ID of every operation, returning how many IDs were new
+pub fn update_allowlist(registry: &Registry, path: &str) -> Result<usize, Box<dyn std::error::Error>> {
+    let mut allowlist = load_allowlist(path)?;
+    
+    let mut added = 0;
+    for op in registry.iter() {
+        if allowlist.entry(op.name().to_string()).or_default().insert(Digest::from(op.image_id()).to_string()) {
+            added += 1;
+        }
+    }
+    fs::write(path, serde_json::to_string_pretty(&allowlist)?)?;
+    Ok(added)
+}
+
+// Verify against the current image ID, then against any allowlisted historical ID.
+// Returns the historical ID that matched, or None when the current ID did.
+pub fn verify_with_allowlist(receipt: &Receipt, ctx: &VerifierContext, image_id: [u32; 8], historical: Option<&BTreeSet<String>>) -> Result<Option<Digest>, Box<dyn std::error::Error>> {
+    let current_error = match receipt.verify_with_context(ctx, image_id) {
+        Ok(()) => return Ok(None),
+        Err(e) => e,
+    };
+    for hex_id in historical.into_iter().flatten() {
+        let bytes = hex::decode(hex_id).map_err(|e| format!("Invalid allowlisted image ID {}: {}", hex_id, e))?;
+        let digest = Digest::try_from(bytes.as_slice()).map_err(|_| format!("Allowlisted image ID {} is not 32 bytes", hex_id))?;
+        if receipt.verify_with_context(ctx, digest).is_ok() {
+            return Ok(Some(digest));
+        }
+    }
+    Err(current_error.into())
+}
+
+// Check one bundle member: intact bytes, expected image ID and a valid receipt.
+// The manifest's image ID is read from each receipt's own claim when the bundle is built, so it is
+// never trusted: built-in operations are checked against their known image ID, and precompiled or
+// dynamic members only verify against one of the caller's `guest_image_ids`.
+fn verify_bundle_member(registry: &Registry, ctx: &VerifierContext, entry: &BundleEntry, proof_bytes: &[u8], guest_image_ids: &[Digest]) -> Result<(), Box<dyn std::error::Error>> {
+    if *Impl::hash_bytes(proof_bytes) != entry.digest {
+        return Err("proof bytes do not match the manifest digest (corrupted member)".into());
+    }
+    
+    let image_id = match (entry.operation.as_str(), registry.get(&entry.operation)) {
+        ("precompiled" | "dynamic", _) if guest_image_ids.contains(&entry.image_id) => entry.image_id,
+        ("precompiled" | "dynamic", _) => {
+            return Err(format!("image ID {} is not an expected guest image ID; pass --image-id <hex> to verify this {} proof", entry.image_id, entry.operation).into());
+        },
+        (_, Some(op)) if Digest::from(op.image_id()) != entry.image_id => {
+            return Err(format!("manifest image ID {} does not match {}", entry.image_id, entry.operation).into());
+        },
+        (_, Some(op)) => Digest::from(op.image_id()),
+        (_, None) => return Err(format!("unknown operation {}", entry.operation).into()),
+    };
+    
+    let proof = SavedProof::decode(proof_bytes)?;
+    proof.check_image_id(image_id)?;
+    proof.receipt.verify_with_context(ctx, image_id)?;
+    Ok(())
+}
+
+// A bundle member's filename and whether it verified
+pub type MemberOutcome = (String, Result<(), String>);
+
+// Verify every member of a bundle, returning each member's outcome in manifest order.
+// `guest_image_ids` are the image IDs precompiled and dynamic members may have.
+pub fn verify_bundle(registry: &Registry, ctx: &VerifierContext, bytes: &[u8], guest_image_ids: &[Digest]) -> Result<Vec<MemberOutcome>, Box<dyn std::error::Error>> {
+    let bundle = ProofBundle::decode(bytes)?;
+    
+    Ok(bundle.manifest.iter().zip(bundle.proofs.iter())
+        .map(|(entry, proof)| (entry.filename.clone(), verify_bundle_member(registry, ctx, entry, proof, guest_image_ids).map_err(|e| e.to_string())))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use methods::{ADDITION_ID, MULTIPLY_GUEST_ID, SQRT_GUEST_ID};
+    use risc0_zkvm::{FakeReceipt, ReceiptClaim};
+
+    // Fake receipts only verify in dev mode
+    fn dev_mode() -> VerifierContext {
+        VerifierContext::default().with_dev_mode(true)
+    }
+
+    fn fake_receipt(image_id: [u32; 8], journal: Vec<u8>) -> Receipt {
+        let claim = ReceiptClaim::ok(image_id, journal.clone());
+        Receipt::new(InnerReceipt::Fake(FakeReceipt::new(claim)), journal)
+    }
+
+    #[test]
+    fn test_extract_seal_rejects_non_succinct_receipt() {
+        assert!(extract_seal(&fake_receipt(ADDITION_ID, vec![1u8, 0, 0, 0])).is_err());
+    }
+
+    #[test]
+    fn test_parse_image_id_round_trips_host_hex() {
+        let hex_id = hex::encode(ADDITION_ID.iter().flat_map(|w| w.to_le_bytes()).collect::<Vec<u8>>());
+        assert_eq!(parse_image_id(&hex_id).unwrap(), ADDITION_ID);
+        assert_eq!(parse_image_id(&format!("0x{}", hex_id)).unwrap(), ADDITION_ID);
+        assert_eq!(Digest::from(ADDITION_ID).to_string(), hex_id);
+        assert!(parse_image_id("abcd").is_err());
+        assert!(parse_image_id("zz").is_err());
+    }
+
+    #[test]
+    fn test_detect_operation_and_journal_codec() {
+        let registry = Registry::builtin();
+        assert_eq!(detect_operation(registry, "proof_multiply_1700000000.bin"), "multiply");
+        assert_eq!(detect_operation(registry, "proof_precompiled_1700000000.bin"), "precompiled");
+        assert_eq!(detect_operation(registry, "receipt.bin"), "add");
+        
+        assert_eq!(journal_codec(registry, "precompiled", Some(JournalCodec::RawLe)), Ok(JournalCodec::RawLe));
+        assert_eq!(journal_codec(registry, "add", None), Ok(JournalCodec::Risc0Serde));
+        assert!(journal_codec(registry, "add", Some(JournalCodec::RawLe)).is_err());
+    }
+
+    #[test]
+    fn test_decode_result_of_empty_and_dynamic_journals() {
+        let registry = Registry::builtin();
+        let empty = decode_result(registry, "add", JournalCodec::Risc0Serde, &Journal::new(Vec::new())).unwrap();
+        assert!(empty.no_result);
+        assert_eq!(empty.json_value(), None);
+        
+        let dynamic = decode_result(registry, "dynamic", JournalCodec::RawLe, &Journal::new(42i64.to_le_bytes().to_vec())).unwrap();
+        assert_eq!((dynamic.value, dynamic.decimal, dynamic.no_result), (42, None, false));
+        assert_eq!(dynamic.lines, vec!["➡️  Computation result: 42"]);
+        
+        assert!(decode_result(registry, "unknown", JournalCodec::Risc0Serde, &Journal::new(vec![1])).is_err());
+    }
+
+    #[test]
+    fn test_decode_result_keeps_u64_results_exact() {
+        let registry = Registry::builtin();
+        
+        // 20!, the largest factorial that fits in a u64, is far past i32::MAX
+        let journal = Journal::new(risc0_zkvm::serde::to_vec(&(20u64, 2432902008176640000u64, 0u32)).unwrap().iter().flat_map(|w| w.to_le_bytes()).collect());
+        let decoded = decode_result(registry, "factorial", JournalCodec::Risc0Serde, &journal).unwrap();
+        assert_eq!(decoded.value, 2432902008176640000);
+        assert_eq!(decoded.json_value(), Some(serde_json::json!(2432902008176640000u64)));
+        
+        // A u64 past i64::MAX stays exact too
+        let journal = Journal::new(risc0_zkvm::serde::to_vec(&(2u64, 63u64, u64::MAX, u64::MAX - 1)).unwrap().iter().flat_map(|w| w.to_le_bytes()).collect());
+        let decoded = decode_result(registry, "modexp", JournalCodec::Risc0Serde, &journal).unwrap();
+        assert_eq!(decoded.value, (u64::MAX - 1) as i128);
+        assert_eq!(decoded.json_value(), Some(serde_json::json!(u64::MAX - 1)));
+    }
+
+    #[test]
+    fn test_benchmark_verification_produces_stats() {
+        let receipt = fake_receipt(ADDITION_ID, vec![0u8; 24]);
+        
+        let stats = benchmark_verification(&receipt, &dev_mode(), ADDITION_ID, 5).unwrap();
+        assert_eq!(stats.iterations, 5);
+        assert!(stats.min <= stats.mean);
+        assert!(stats.min <= stats.p95);
+        
+        assert!(benchmark_verification(&receipt, &dev_mode(), ADDITION_ID, 0).is_err());
+        // Outside dev mode a fake receipt never verifies
+        assert!(benchmark_verification(&receipt, &VerifierContext::default().with_dev_mode(false), ADDITION_ID, 1).is_err());
+    }
+
+    #[test]
+    fn test_allowlisted_historical_image_id_verifies() {
+        let registry = Registry::builtin();
+        let path = std::env::temp_dir().join(format!("image_id_allowlist_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        
+        // A proof from an earlier build of the add guest, whose image ID is no longer ADDITION_ID
+        let old_id = [7u32; 8];
+        let receipt = fake_receipt(old_id, vec![1u8; 24]);
+        assert!(verify_with_allowlist(&receipt, &dev_mode(), ADDITION_ID, None).is_err());
+        
+        // Recording the current IDs is idempotent and covers every registered operation
+        let added = update_allowlist(registry, path).unwrap();
+        assert_eq!(added, registry.iter().count());
+        assert_eq!(update_allowlist(registry, path).unwrap(), 0);
+        
+        let mut allowlist = load_allowlist(path).unwrap();
+        let _ = fs::remove_file(path);
+        assert!(allowlist["add"].contains(&Digest::from(ADDITION_ID).to_string()));
+        allowlist.get_mut("add").unwrap().insert(Digest::from(old_id).to_string());
+        
+        let matched = verify_with_allowlist(&receipt, &dev_mode(), ADDITION_ID, allowlist.get("add")).unwrap();
+        assert_eq!(matched, Some(Digest::from(old_id)));
+        // Historical IDs are per operation
+        assert!(verify_with_allowlist(&receipt, &dev_mode(), MULTIPLY_GUEST_ID, allowlist.get("multiply")).is_err());
+    }
+
+    #[test]
+    fn test_verify_bundle_detects_corrupted_member() {
+        let registry = Registry::builtin();
+        
+        let mut manifest = Vec::new();
+        let mut proofs = Vec::new();
+        for (i, (operation, image_id)) in [("add", ADDITION_ID), ("multiply", MULTIPLY_GUEST_ID), ("sqrt", SQRT_GUEST_ID)].into_iter().enumerate() {
+            let bytes = bincode::serialize(&fake_receipt(image_id, vec![i as u8; 24])).unwrap();
+            manifest.push(BundleEntry {
+                filename: format!("proof_{}_{}.bin", operation, 1700000000 + i),
+                operation: operation.to_string(),
+                image_id: Digest::from(image_id),
+                digest: *Impl::hash_bytes(&bytes),
+            });
+            proofs.push(bytes);
+        }
+        let mut bundle = ProofBundle { manifest, proofs };
+        let outcomes = verify_bundle(registry, &dev_mode(), &bundle.encode().unwrap(), &[]).unwrap();
+        assert_eq!(outcomes.len(), 3);
+        assert!(outcomes.iter().all(|(_, outcome)| outcome.is_ok()));
+        
+        // Flip a byte in the middle member only
+        let last = bundle.proofs[1].len() - 1;
+        bundle.proofs[1][last] ^= 0xff;
+        let outcomes = verify_bundle(registry, &dev_mode(), &bundle.encode().unwrap(), &[]).unwrap();
+        assert!(outcomes[0].1.is_ok() && outcomes[2].1.is_ok());
+        assert!(outcomes[1].1.as_ref().unwrap_err().contains("corrupted"));
+        
+        assert!(verify_bundle(registry, &dev_mode(), b"not a bundle", &[]).is_err());
+    }
+
+    #[test]
+    fn test_verify_bundle_needs_expected_guest_image_id() {
+        let registry = Registry::builtin();
+        
+        // A dynamic guest's manifest entry names the image ID its own receipt claims
+        let guest_id = [5u32; 8];
+        let bytes = bincode::serialize(&fake_receipt(guest_id, 42i64.to_le_bytes().to_vec())).unwrap();
+        let entry = BundleEntry {
+            filename: "proof_dynamic_1700000000.bin".to_string(),
+            operation: "dynamic".to_string(),
+            image_id: Digest::from(guest_id),
+            digest: *Impl::hash_bytes(&bytes),
+        };
+        let bundle = ProofBundle { manifest: vec![entry], proofs: vec![bytes] }.encode().unwrap();
+        
+        // Without an expected image ID the member is not reported as verified
+        let outcomes = verify_bundle(registry, &dev_mode(), &bundle, &[]).unwrap();
+        assert!(outcomes[0].1.as_ref().unwrap_err().contains("--image-id"));
+        let outcomes = verify_bundle(registry, &dev_mode(), &bundle, &[Digest::from(ADDITION_ID)]).unwrap();
+        assert!(outcomes[0].1.is_err());
+        
+        let outcomes = verify_bundle(registry, &dev_mode(), &bundle, &[Digest::from(guest_id)]).unwrap();
+        assert!(outcomes[0].1.is_ok());
+    }
+
+    #[test]
+    #[ignore = "generates a real succinct proof"]
+    fn test_export_seal_from_succinct_receipt() {
+        use risc0_zkvm::{default_prover, ExecutorEnv, ProverOpts};
+        use methods::ADDITION_ELF;
+        
+        let env = ExecutorEnv::builder().write(&150000i64).unwrap().write(&250000i64).unwrap().build().unwrap();
+        let receipt = default_prover().prove_with_opts(env, ADDITION_ELF, &ProverOpts::succinct()).unwrap().receipt;
+        let (seal_bytes, seal_kind) = extract_seal(&receipt).unwrap();
+        assert_eq!(seal_kind, "succinct");
+        assert_eq!(seal_bytes.len(), receipt.inner.succinct().unwrap().seal.len() * 4);
+    }
+}
@@ -681,8 +681,8 @@ export class BasicOperations {
 
       const result = ProjectUtils.parseJsonFromOutput(execResult.stdout);
       
-      // Convert scaled result back to probability (divide by 10000)
-      const probability = result.result / 10000.0;
+      // The host already divides out the guest's fixed-point scale, so this is the probability
+      const probability = result.result;
       const classification = probability >= 0.5 ? 'positive' : 'negative';
       
       return {
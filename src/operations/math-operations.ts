@@ -224,7 +224,7 @@ export class MathOperations {
   }
 
   async performZkVmRange(args: any): Promise<ToolResponse> {
-    const { secretNumber, minValue, maxValue, forceRebuild = false } = args;
+    const { secretNumber, minValue, maxValue, minExclusive = false, maxExclusive = false, forceRebuild = false } = args;
 
     if (typeof secretNumber !== 'number' || typeof minValue !== 'number' || typeof maxValue !== 'number' || 
         secretNumber < 0 || minValue < 0 || maxValue < 0 || 
@@ -236,8 +236,13 @@ export class MathOperations {
       );
     }
 
+    if (typeof minExclusive !== 'boolean' || typeof maxExclusive !== 'boolean') {
+      throw new McpError(ErrorCode.InvalidParams, 'minExclusive and maxExclusive must be booleans');
+    }
+    const interval = `${minExclusive ? '(' : '['}${minValue}, ${maxValue}${maxExclusive ? ')' : ']'}`;
+
     try {
-      console.error(`[API] Starting zkVM range proof: secret ∈ [${minValue}, ${maxValue}] (production mode)`);
+      console.error(`[API] Starting zkVM range proof: secret ∈ ${interval} (production mode)`);
       
       // Ensure the RISC Zero project exists and is built
       await ProjectUtils.ensureProjectExists(this.projectPath, forceRebuild);
@@ -262,8 +267,9 @@ export class MathOperations {
       console.error(`[API] Starting binary execution...`);
       const startTime = Date.now();
 
-      // Simple command without session parameters: range secretNumber minValue maxValue
-      const command = `${hostBinary} range ${secretNumber} ${minValue} ${maxValue}`;
+      // Simple command without session parameters: [--min-exclusive] [--max-exclusive] range secretNumber minValue maxValue
+      const flags = `${minExclusive ? '--min-exclusive ' : ''}${maxExclusive ? '--max-exclusive ' : ''}`;
+      const command = `${hostBinary} ${flags}range ${secretNumber} ${minValue} ${maxValue}`;
       
       let result: ZkVmResult;
       
@@ -296,7 +302,9 @@ export class MathOperations {
       }
       
       // Calculate expected result for validation
-      const expectedResult = secretNumber >= minValue && secretNumber <= maxValue;
+      const aboveMin = minExclusive ? secretNumber > minValue : secretNumber >= minValue;
+      const belowMax = maxExclusive ? secretNumber < maxValue : secretNumber <= maxValue;
+      const expectedResult = aboveMin && belowMax;
       
       return {
         content: [
@@ -305,10 +313,10 @@ export class MathOperations {
             text: JSON.stringify({
               computation: {
                 operation: 'range',
-                inputs: { minValue, maxValue },
-                result: result.result === 1,
+                inputs: { minValue, maxValue, minExclusive, maxExclusive },
+                result: result.result === true,
                 expected: expectedResult,
-                correct: (result.result === 1) === expectedResult
+                correct: (result.result === true) === expectedResult
               },
               zkProof: {
                 mode: 'Production (real ZK proof)',
@@ -103,11 +103,21 @@ export const toolDefinitions = [
         },
         minValue: {
           type: 'number',
-          description: 'Minimum value of the range (inclusive)',
+          description: 'Minimum value of the range (inclusive unless minExclusive is set)',
         },
         maxValue: {
           type: 'number',
-          description: 'Maximum value of the range (inclusive)',
+          description: 'Maximum value of the range (inclusive unless maxExclusive is set)',
+        },
+        minExclusive: {
+          type: 'boolean',
+          description: 'Prove secret > minValue instead of secret >= minValue',
+          default: false
+        },
+        maxExclusive: {
+          type: 'boolean',
+          description: 'Prove secret < maxValue instead of secret <= maxValue',
+          default: false
         },
         forceRebuild: {
           type: 'boolean',
@@ -2,6 +2,7 @@ import { McpError, ErrorCode, CallToolResult } from '@modelcontextprotocol/sdk/t
 
 export interface ZkVmResult {
   result?: any;
+  result_type?: 'decimal' | 'integer' | 'boolean' | null;
   image_id?: string;
   verification_status?: string;
   proof_file_path?: string;